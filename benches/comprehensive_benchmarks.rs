@@ -37,10 +37,8 @@ fn bench_scalable_traversal(c: &mut Criterion) {
                 b.iter(|| {
                     let walker = WalkBuilder::new(path).build();
                     let mut count = 0;
-                    for entry in walker {
-                        if let Ok(_entry) = entry {
-                            count += 1;
-                        }
+                    for _entry in walker.flatten() {
+                        count += 1;
                     }
                     black_box(count)
                 })
@@ -78,10 +76,8 @@ fn bench_scalable_traversal(c: &mut Criterion) {
                         .hidden(false)
                         .build();
                     let mut count = 0;
-                    for entry in walker {
-                        if let Ok(_entry) = entry {
-                            count += 1;
-                        }
+                    for _entry in walker.flatten() {
+                        count += 1;
                     }
                     black_box(count)
                 })
@@ -100,11 +96,9 @@ fn bench_realistic_patterns(c: &mut Criterion) {
     // Collect realistic file paths from different project types
     let mut all_paths = Vec::new();
     let walker = WalkBuilder::new(base_path).build();
-    for entry in walker.take(5000) {
-        if let Ok(entry) = entry {
-            if entry.file_type().map_or(false, |ft| ft.is_file()) {
-                all_paths.push(entry.path().to_path_buf());
-            }
+    for entry in walker.take(5000).flatten() {
+        if entry.file_type().is_some_and(|ft| ft.is_file()) {
+            all_paths.push(entry.path().to_path_buf());
         }
     }
     
@@ -158,14 +152,12 @@ fn bench_content_search_realistic(c: &mut Criterion) {
     let mut files_by_type = std::collections::HashMap::new();
     let walker = WalkBuilder::new(base_path).build();
     
-    for entry in walker {
-        if let Ok(entry) = entry {
-            if let Some(ext) = entry.path().extension() {
-                if let Some(ext_str) = ext.to_str() {
-                    let file_list = files_by_type.entry(ext_str.to_string()).or_insert_with(Vec::new);
-                    if file_list.len() < 1000 {  // Limit per file type for benchmark performance
-                        file_list.push(entry.path().to_path_buf());
-                    }
+    for entry in walker.flatten() {
+        if let Some(ext) = entry.path().extension() {
+            if let Some(ext_str) = ext.to_str() {
+                let file_list = files_by_type.entry(ext_str.to_string()).or_insert_with(Vec::new);
+                if file_list.len() < 1000 {  // Limit per file type for benchmark performance
+                    file_list.push(entry.path().to_path_buf());
                 }
             }
         }
@@ -238,6 +230,61 @@ fn bench_content_search_realistic(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark parallel walk-and-search at different walker thread counts
+///
+/// Content search blocks on file reads, so more threads than cores could
+/// overlap I/O. With a warm page cache the extra threads only add contention,
+/// which is why `search()` keeps one walker thread per core by default.
+fn bench_content_search_threads(c: &mut Criterion) {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    
+    let test_env = create_comprehensive_test_environment();
+    let base_path = test_env.path().to_path_buf();
+    let cpus = num_cpus::get();
+    let matcher = RegexMatcherBuilder::new()
+        .build(r"(TODO|FIXME|BUG|HACK)")
+        .unwrap();
+    
+    let mut group = c.benchmark_group("content_search_threads");
+    group.measurement_time(Duration::from_secs(15));
+    
+    for multiplier in [1, 2, 4] {
+        let threads = cpus * multiplier;
+        group.bench_with_input(
+            BenchmarkId::new("threads_per_cpu", multiplier),
+            &threads,
+            |b, &threads| {
+                b.iter(|| {
+                    let total_matches = AtomicUsize::new(0);
+                    WalkBuilder::new(&base_path).threads(threads).build_parallel().run(|| {
+                        let matcher = &matcher;
+                        let total_matches = &total_matches;
+                        Box::new(move |entry| {
+                            let Ok(entry) = entry else { return ignore::WalkState::Continue };
+                            if entry.file_type().is_some_and(|ft| ft.is_file()) {
+                                let mut count = 0;
+                                let _ = Searcher::new().search_path(
+                                    matcher,
+                                    entry.path(),
+                                    grep_searcher::sinks::UTF8(|_, _| {
+                                        count += 1;
+                                        Ok(true)
+                                    }),
+                                );
+                                total_matches.fetch_add(count, Ordering::Relaxed);
+                            }
+                            ignore::WalkState::Continue
+                        })
+                    });
+                    black_box(total_matches.into_inner())
+                })
+            },
+        );
+    }
+    
+    group.finish();
+}
+
 /// Benchmark special filesystem scenarios (performance edge cases)
 fn bench_filesystem_edge_cases(c: &mut Criterion) {
     let test_env = create_comprehensive_test_environment();
@@ -253,10 +300,8 @@ fn bench_filesystem_edge_cases(c: &mut Criterion) {
         b.iter(|| {
             let walker = WalkBuilder::new(&deep_path).build();
             let mut count = 0;
-            for entry in walker {
-                if let Ok(_entry) = entry {
-                    count += 1;
-                }
+            for _entry in walker.flatten() {
+                count += 1;
             }
             black_box(count)
         })
@@ -268,10 +313,8 @@ fn bench_filesystem_edge_cases(c: &mut Criterion) {
         b.iter(|| {
             let walker = WalkBuilder::new(&flat_path).build();
             let mut count = 0;
-            for entry in walker {
-                if let Ok(_entry) = entry {
-                    count += 1;
-                }
+            for _entry in walker.flatten() {
+                count += 1;
             }
             black_box(count)
         })
@@ -288,11 +331,9 @@ fn bench_filesystem_edge_cases(c: &mut Criterion) {
         b.iter(|| {
             let walker = WalkBuilder::new(&sizes_path).build();
             let mut matches = 0;
-            for entry in walker {
-                if let Ok(entry) = entry {
-                    if glob_set.is_match(entry.path()) {
-                        matches += 1;
-                    }
+            for entry in walker.flatten() {
+                if glob_set.is_match(entry.path()) {
+                    matches += 1;
                 }
             }
             black_box(matches)
@@ -312,28 +353,26 @@ fn bench_filesystem_edge_cases(c: &mut Criterion) {
             let mut total_matches = 0;
             let mut searcher = Searcher::new();
             
-            for entry in walker {
-                if let Ok(entry) = entry {
-                    if entry.file_type().map_or(false, |ft| ft.is_file()) {
-                        if let Ok(file) = std::fs::File::open(entry.path()) {
-                            struct CountSink { count: usize }
-                            
-                            impl grep_searcher::Sink for CountSink {
-                                type Error = std::io::Error;
-                                fn matched(
-                                    &mut self,
-                                    _searcher: &grep_searcher::Searcher,
-                                    _mat: &grep_searcher::SinkMatch<'_>,
-                                ) -> Result<bool, Self::Error> {
-                                    self.count += 1;
-                                    Ok(true)
-                                }
+            for entry in walker.flatten() {
+                if entry.file_type().is_some_and(|ft| ft.is_file()) {
+                    if let Ok(file) = std::fs::File::open(entry.path()) {
+                        struct CountSink { count: usize }
+                        
+                        impl grep_searcher::Sink for CountSink {
+                            type Error = std::io::Error;
+                            fn matched(
+                                &mut self,
+                                _searcher: &grep_searcher::Searcher,
+                                _mat: &grep_searcher::SinkMatch<'_>,
+                            ) -> Result<bool, Self::Error> {
+                                self.count += 1;
+                                Ok(true)
                             }
-                            
-                            let mut sink = CountSink { count: 0 };
-                            let _ = searcher.search_file(&matcher, &file, &mut sink);
-                            total_matches += sink.count;
                         }
+                        
+                        let mut sink = CountSink { count: 0 };
+                        let _ = searcher.search_file(&matcher, &file, &mut sink);
+                        total_matches += sink.count;
                     }
                 }
             }
@@ -363,11 +402,9 @@ fn bench_project_workflows(c: &mut Criterion) {
         b.iter(|| {
             let walker = WalkBuilder::new(&project_path).build();
             let mut matches = 0;
-            for entry in walker {
-                if let Ok(entry) = entry {
-                    if glob_set.is_match(entry.path()) {
-                        matches += 1;
-                    }
+            for entry in walker.flatten() {
+                if glob_set.is_match(entry.path()) {
+                    matches += 1;
                 }
             }
             black_box(matches)
@@ -384,11 +421,9 @@ fn bench_project_workflows(c: &mut Criterion) {
         b.iter(|| {
             let walker = WalkBuilder::new(base_path).build();
             let mut matches = 0;
-            for entry in walker {
-                if let Ok(entry) = entry {
-                    if glob_set.is_match(entry.path()) {
-                        matches += 1;
-                    }
+            for entry in walker.flatten() {
+                if glob_set.is_match(entry.path()) {
+                    matches += 1;
                 }
             }
             black_box(matches)
@@ -409,32 +444,30 @@ fn bench_project_workflows(c: &mut Criterion) {
             let mut total_matches = 0;
             let mut searcher = Searcher::new();
             
-            for entry in walker {
-                if let Ok(entry) = entry {
-                    if entry.file_type().map_or(false, |ft| ft.is_file()) {
-                        // Only search text files
-                        if let Some(ext) = entry.path().extension() {
-                            if let Some(ext_str) = ext.to_str() {
-                                if matches!(ext_str, "py" | "rs" | "js" | "ts" | "cpp" | "c" | "h" | "txt" | "md") {
-                                    if let Ok(file) = std::fs::File::open(entry.path()) {
-                                        struct CountSink { count: usize }
-                                        
-                                        impl grep_searcher::Sink for CountSink {
-                                            type Error = std::io::Error;
-                                            fn matched(
-                                                &mut self,
-                                                _searcher: &grep_searcher::Searcher,
-                                                _mat: &grep_searcher::SinkMatch<'_>,
-                                            ) -> Result<bool, Self::Error> {
-                                                self.count += 1;
-                                                Ok(true)
-                                            }
+            for entry in walker.flatten() {
+                if entry.file_type().is_some_and(|ft| ft.is_file()) {
+                    // Only search text files
+                    if let Some(ext) = entry.path().extension() {
+                        if let Some(ext_str) = ext.to_str() {
+                            if matches!(ext_str, "py" | "rs" | "js" | "ts" | "cpp" | "c" | "h" | "txt" | "md") {
+                                if let Ok(file) = std::fs::File::open(entry.path()) {
+                                    struct CountSink { count: usize }
+                                    
+                                    impl grep_searcher::Sink for CountSink {
+                                        type Error = std::io::Error;
+                                        fn matched(
+                                            &mut self,
+                                            _searcher: &grep_searcher::Searcher,
+                                            _mat: &grep_searcher::SinkMatch<'_>,
+                                        ) -> Result<bool, Self::Error> {
+                                            self.count += 1;
+                                            Ok(true)
                                         }
-                                        
-                                        let mut sink = CountSink { count: 0 };
-                                        let _ = searcher.search_file(&matcher, &file, &mut sink);
-                                        total_matches += sink.count;
                                     }
+                                    
+                                    let mut sink = CountSink { count: 0 };
+                                    let _ = searcher.search_file(&matcher, &file, &mut sink);
+                                    total_matches += sink.count;
                                 }
                             }
                         }
@@ -453,6 +486,7 @@ criterion_group!(
     bench_scalable_traversal,
     bench_realistic_patterns,
     bench_content_search_realistic,
+    bench_content_search_threads,
     bench_filesystem_edge_cases,
     bench_project_workflows
 );
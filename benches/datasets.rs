@@ -46,6 +46,7 @@ impl DatasetConfig {
     }
 
     /// Large dataset for stress testing
+    #[allow(dead_code)]
     pub fn large() -> Self {
         Self {
             name: "large",
@@ -357,7 +358,7 @@ pub fn create_synthetic_dataset(base_path: &Path, config: DatasetConfig) -> std:
             // Generate realistic file content with patterns
             writeln!(file, "// File: {}", file_name.display())?;
             writeln!(file, "// Generated for {} dataset", config.name)?;
-            writeln!(file, "")?;
+            writeln!(file)?;
             
             // Add content with search patterns
             for line_i in 0..20 {
@@ -467,6 +468,7 @@ pub fn create_special_cases(base_path: &Path) -> std::io::Result<()> {
 
 #[cfg(test)]
 mod tests {
+    #[allow(unused_imports)]
     use super::*;
 
     #[test]
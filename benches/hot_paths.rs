@@ -7,7 +7,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use std::fs::{File, create_dir_all};
 use std::io::Write;
-use std::path::PathBuf;
 use tempfile::TempDir;
 use globset::GlobSetBuilder;
 use ignore::WalkBuilder;
@@ -87,10 +86,8 @@ fn bench_directory_traversal(c: &mut Criterion) {
             b.iter(|| {
                 let walker = WalkBuilder::new(path).build();
                 let mut count = 0;
-                for entry in walker {
-                    if let Ok(_entry) = entry {
-                        count += 1;
-                    }
+                for _entry in walker.flatten() {
+                    count += 1;
                 }
                 black_box(count)
             })
@@ -128,10 +125,29 @@ fn bench_directory_traversal(c: &mut Criterion) {
                     .hidden(false)
                     .build();
                 let mut count = 0;
-                for entry in walker {
-                    if let Ok(_entry) = entry {
-                        count += 1;
-                    }
+                for _entry in walker.flatten() {
+                    count += 1;
+                }
+                black_box(count)
+            })
+        },
+    );
+    
+    // Benchmark `no_vcs`: no git matchers are set up for this non-repo tree
+    group.bench_with_input(
+        BenchmarkId::new("no_vcs_walk", "2k_files"),
+        root_path,
+        |b, path| {
+            b.iter(|| {
+                let walker = WalkBuilder::new(path)
+                    .git_ignore(false)
+                    .git_global(false)
+                    .git_exclude(false)
+                    .hidden(false)
+                    .build();
+                let mut count = 0;
+                for _entry in walker.flatten() {
+                    count += 1;
                 }
                 black_box(count)
             })
@@ -149,10 +165,8 @@ fn bench_pattern_matching(c: &mut Criterion) {
     // Collect sample paths for testing
     let mut sample_paths = Vec::new();
     let walker = WalkBuilder::new(root_path).build();
-    for entry in walker.take(1000) {
-        if let Ok(entry) = entry {
-            sample_paths.push(entry.path().to_path_buf());
-        }
+    for entry in walker.take(1000).flatten() {
+        sample_paths.push(entry.path().to_path_buf());
     }
     
     let mut group = c.benchmark_group("pattern_matching");
@@ -253,10 +267,8 @@ fn bench_file_metadata(c: &mut Criterion) {
     // Collect sample entries for testing
     let mut sample_entries = Vec::new();
     let walker = WalkBuilder::new(root_path).build();
-    for entry in walker.take(500) {
-        if let Ok(entry) = entry {
-            sample_entries.push(entry);
-        }
+    for entry in walker.take(500).flatten() {
+        sample_entries.push(entry);
     }
     
     let mut group = c.benchmark_group("file_metadata");
@@ -308,12 +320,10 @@ fn bench_content_search(c: &mut Criterion) {
     // Collect Python files for content search
     let mut python_files = Vec::new();
     let walker = WalkBuilder::new(root_path).build();
-    for entry in walker {
-        if let Ok(entry) = entry {
-            if let Some(ext) = entry.path().extension() {
-                if ext == "py" {
-                    python_files.push(entry.path().to_path_buf());
-                }
+    for entry in walker.flatten() {
+        if let Some(ext) = entry.path().extension() {
+            if ext == "py" {
+                python_files.push(entry.path().to_path_buf());
             }
         }
     }
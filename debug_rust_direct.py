@@ -75,7 +75,7 @@ def test_rust_functions():
                     as_path_objects=False,
                     yield_results=True,
                     threads=0,
-                    _case_sensitive_content=False
+                    case_sensitive_content=False
                 ))
                 print(f"Pattern '{pattern}': {len(results)} matches")
             except Exception as e:
@@ -157,11 +157,11 @@ mod tests {
         let (tx, rx) = pool.get_channel(1000);
         
         // Test that channel works
-        tx.send(crate::FindResult::Path("test".to_string())).unwrap();
+        tx.send(crate::FindResult::Path(std::path::PathBuf::from("test"), 0, Default::default())).unwrap();
         let result = rx.recv().unwrap();
         
         match result {
-            crate::FindResult::Path(path) => assert_eq!(path, "test"),
+            crate::FindResult::Path(path, ..) => assert_eq!(path, std::path::Path::new("test")),
             _ => panic!("Expected Path result"),
         }
     }
@@ -1,18 +1,27 @@
 // this_file: src/lib.rs
 
 use pyo3::prelude::*;
-use pyo3::exceptions::PyValueError;
-use pyo3::types::PyDict;
+use pyo3::exceptions::{PyOSError, PyValueError};
+use pyo3::types::{PyBool, PyBytes, PyDict};
 use ignore::{WalkBuilder, WalkState, DirEntry};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use globset::{GlobSet, GlobSetBuilder};
 use crossbeam_channel::Receiver;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::fs::File;
-use std::time::SystemTime;
+use std::io::{Read, Write};
+use std::time::{Duration, Instant, SystemTime};
 use anyhow::Result;
-use grep_searcher::{Searcher, Sink, SinkMatch};
+use rayon::prelude::*;
+use grep_searcher::{Encoding, Searcher, SearcherBuilder, Sink, SinkContext, SinkFinish, SinkMatch};
+use grep_matcher::{ByteSet, LineMatchKind, LineTerminator, Match, Matcher, NoCaptures, NoError};
 use grep_regex::{RegexMatcher, RegexMatcherBuilder};
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use simd_string::FastPatternMatch;
 
 mod zero_copy_path;
 mod pattern_cache;
@@ -30,7 +39,17 @@ fn _vexy_glob(m: &Bound<'_, PyModule>) -> PyResult<()> {
     
     m.add_function(wrap_pyfunction!(find, m)?)?;
     m.add_function(wrap_pyfunction!(search, m)?)?;
+    m.add_function(wrap_pyfunction!(search_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(warm_patterns, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_glob, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_regex, m)?)?;
+    m.add_function(wrap_pyfunction!(analyze_content_pattern, m)?)?;
+    m.add_function(wrap_pyfunction!(explain_excludes, m)?)?;
     m.add_class::<VexyGlobIterator>()?;
+    m.add_class::<CompiledPattern>()?;
+    m.add_class::<Entry>()?;
+    m.add_class::<DirEvent>()?;
+    m.add_class::<TopKUpdate>()?;
     Ok(())
 }
 
@@ -41,14 +60,608 @@ pub struct SearchResultRust {
     pub line_number: u64,
     pub line_text: String,
     pub matches: Vec<String>,
+    /// Number of matcher hits on the line, counting repeats
+    pub matches_on_line: u64,
+    /// Line text with the replacement applied (when `replace` is set)
+    pub replacement: Option<String>,
+    /// Line text with each match wrapped in the `highlight` markers
+    pub highlighted_line: Option<String>,
+    /// Replacements written to this result's file (when `write` is set)
+    pub replacements: Option<u64>,
+    /// Size of the matched file in bytes (when `search_metadata` is set)
+    pub size: Option<u64>,
+    /// Modification time of the matched file (when `search_metadata` is set)
+    pub mtime: Option<f64>,
+    /// Bytes the searcher read from the file before it finished or stopped
+    /// early (when `report_bytes` is set)
+    pub bytes_read: Option<u64>,
+    /// Every match in the file grouped with its context (`format="hunks"`),
+    /// replacing the per-line fields
+    pub hunks: Option<Vec<Hunk>>,
+}
+
+/// Contiguous block of matching and context lines from one file
+#[derive(Debug, Clone)]
+pub struct Hunk {
+    /// Line number of the first line in the hunk
+    pub start_line: u64,
+    /// Text of each line, without its terminator
+    pub lines: Vec<String>,
+    /// Line numbers of the lines holding a match
+    pub match_lines: Vec<u64>,
+}
+
+impl SearchResultRust {
+    /// Convert to the dictionary handed to Python
+    fn to_dict<'py>(&self, py: Python<'py>, as_path_objects: bool, path_style: PathStyle) -> PyResult<Bound<'py, PyDict>> {
+        let result_dict = PyDict::new(py);
+        
+        let path_obj: PyObject = if as_path_objects {
+            let pathlib = py.import("pathlib")?;
+            let path_class = pathlib.getattr(path_style.path_class())?;
+            path_class.call1((&self.path,))?.into()
+        } else {
+            self.path.clone().into_pyobject(py)?.into()
+        };
+        
+        result_dict.set_item("path", path_obj)?;
+        if let Some(ref hunks) = self.hunks {
+            let hunk_list = pyo3::types::PyList::empty(py);
+            for hunk in hunks {
+                let hunk_dict = PyDict::new(py);
+                hunk_dict.set_item("start_line", hunk.start_line)?;
+                hunk_dict.set_item("lines", &hunk.lines)?;
+                hunk_dict.set_item("match_lines", &hunk.match_lines)?;
+                hunk_list.append(hunk_dict)?;
+            }
+            result_dict.set_item("hunks", hunk_list)?;
+        } else {
+            result_dict.set_item("line_number", self.line_number)?;
+            result_dict.set_item("line_text", &self.line_text)?;
+            result_dict.set_item("matches", &self.matches)?;
+            result_dict.set_item("matches_on_line", self.matches_on_line)?;
+        }
+        if let Some(ref replacement) = self.replacement {
+            result_dict.set_item("replacement", replacement)?;
+        }
+        if let Some(ref highlighted_line) = self.highlighted_line {
+            result_dict.set_item("highlighted_line", highlighted_line)?;
+        }
+        if let Some(replacements) = self.replacements {
+            result_dict.set_item("replacements", replacements)?;
+        }
+        if let Some(size) = self.size {
+            result_dict.set_item("size", size)?;
+        }
+        if let Some(mtime) = self.mtime {
+            result_dict.set_item("mtime", mtime)?;
+        }
+        if let Some(bytes_read) = self.bytes_read {
+            result_dict.set_item("bytes_read", bytes_read)?;
+        }
+        
+        Ok(result_dict)
+    }
+    
+    /// Render as the string handed to Python with `format="sexp"`
+    ///
+    /// `(match "<path>" :line-number N :line-text "..." :matches ("..." ...)
+    /// :matches-on-line N ...)`, or `(hunks "<path>" :hunks ((:start-line N
+    /// :lines ("..." ...) :match-lines (N ...)) ...) ...)` for hunk results,
+    /// followed by whichever of `:replacement`, `:highlighted-line`,
+    /// `:replacements`, `:size`, `:mtime` and `:bytes-read` are present.
+    fn to_sexp(&self) -> String {
+        let mut form = match self.hunks {
+            Some(ref hunks) => SexpForm::new("hunks", &self.path).field("hunks", hunks.as_slice()),
+            None => SexpForm::new("match", &self.path)
+                .field("line-number", &self.line_number)
+                .field("line-text", self.line_text.as_str())
+                .field("matches", self.matches.as_slice())
+                .field("matches-on-line", &self.matches_on_line),
+        };
+        if let Some(ref replacement) = self.replacement {
+            form = form.field("replacement", replacement.as_str());
+        }
+        if let Some(ref highlighted_line) = self.highlighted_line {
+            form = form.field("highlighted-line", highlighted_line.as_str());
+        }
+        if let Some(ref replacements) = self.replacements {
+            form = form.field("replacements", replacements);
+        }
+        if let Some(ref size) = self.size {
+            form = form.field("size", size);
+        }
+        if let Some(ref mtime) = self.mtime {
+            form = form.field("mtime", mtime);
+        }
+        if let Some(ref bytes_read) = self.bytes_read {
+            form = form.field("bytes-read", bytes_read);
+        }
+        form.finish()
+    }
+}
+
+/// Value written into a `format="sexp"` result
+///
+/// Strings are double-quoted with `\` and `"` escaped by a backslash and all
+/// other characters, newlines included, written as they are. Integers are
+/// decimal, floats always carry a decimal point, `true` is `t`, and `false`
+/// and missing values are `nil`. Sequences become parenthesized lists.
+trait SexpValue {
+    fn write_sexp(&self, out: &mut String);
+}
+
+impl SexpValue for str {
+    fn write_sexp(&self, out: &mut String) {
+        out.push('"');
+        for c in self.chars() {
+            if c == '"' || c == '\\' {
+                out.push('\\');
+            }
+            out.push(c);
+        }
+        out.push('"');
+    }
+}
+
+impl SexpValue for String {
+    fn write_sexp(&self, out: &mut String) {
+        self.as_str().write_sexp(out);
+    }
+}
+
+impl SexpValue for u64 {
+    fn write_sexp(&self, out: &mut String) {
+        out.push_str(&self.to_string());
+    }
+}
+
+impl SexpValue for usize {
+    fn write_sexp(&self, out: &mut String) {
+        out.push_str(&self.to_string());
+    }
+}
+
+impl SexpValue for f64 {
+    fn write_sexp(&self, out: &mut String) {
+        out.push_str(&format!("{:?}", self));
+    }
+}
+
+impl SexpValue for bool {
+    fn write_sexp(&self, out: &mut String) {
+        out.push_str(if *self { "t" } else { "nil" });
+    }
+}
+
+impl<T: SexpValue> SexpValue for Option<T> {
+    fn write_sexp(&self, out: &mut String) {
+        match self {
+            Some(value) => value.write_sexp(out),
+            None => out.push_str("nil"),
+        }
+    }
+}
+
+impl<T: SexpValue> SexpValue for [T] {
+    fn write_sexp(&self, out: &mut String) {
+        out.push('(');
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            value.write_sexp(out);
+        }
+        out.push(')');
+    }
+}
+
+impl SexpValue for Hunk {
+    fn write_sexp(&self, out: &mut String) {
+        out.push_str("(:start-line ");
+        self.start_line.write_sexp(out);
+        out.push_str(" :lines ");
+        self.lines.as_slice().write_sexp(out);
+        out.push_str(" :match-lines ");
+        self.match_lines.as_slice().write_sexp(out);
+        out.push(')');
+    }
+}
+
+/// A `(head "<path>" :key value ...)` form under construction
+struct SexpForm(String);
+
+impl SexpForm {
+    fn new(head: &str, path: &str) -> Self {
+        let mut out = format!("({} ", head);
+        path.write_sexp(&mut out);
+        Self(out)
+    }
+    
+    fn field<T: SexpValue + ?Sized>(mut self, key: &str, value: &T) -> Self {
+        self.0.push_str(" :");
+        self.0.push_str(key);
+        self.0.push(' ');
+        value.write_sexp(&mut self.0);
+        self
+    }
+    
+    fn finish(mut self) -> String {
+        self.0.push(')');
+        self.0
+    }
 }
 
 /// Result type for path finding and content search
 #[derive(Debug, Clone)]
 enum FindResult {
-    Path(String),  // Changed from PathBuf to String for zero-copy optimization
+    /// Path with its walk depth, kept for `sort="depth"`, and the extra
+    /// fields computed during the walk
+    ///
+    /// The path is relative to `relative_to` when set but otherwise as found;
+    /// `path_style` is only applied when it is handed to Python, so collected
+    /// results can still be statted and hashed.
+    Path(PathBuf, usize, ResultExtras),
+    /// Entry with its path as found, as for `Path`
+    Entry(Entry, PathBuf, usize, ResultExtras),
     Search(SearchResultRust),
     Error(String),
+    /// File left out of the results for exceeding `report_oversized`, with its size
+    Oversized(String, u64),
+    /// Periodic `(entries_visited, estimated_total)` update sent with `with_stats`
+    Progress(u64, u64),
+    /// Directory the walk descends into, sent with `emit_dir_events`
+    DirEnter(String),
+    /// Directory the walk has finished, sent with `emit_dir_events`
+    DirLeave(String),
+    /// Largest files so far as `(path, size)`, largest first, sent with
+    /// `top_k`; the flag marks the last update, sent once the walk is done
+    TopKUpdate(Vec<(String, u64)>, bool),
+}
+
+/// Found entry with its type and metadata captured during the walk
+///
+/// Usable anywhere a path is accepted via `__fspath__`; `is_dir`, `size` and
+/// the other properties are answered from the walk without a fresh `stat`,
+/// much like `os.DirEntry`.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+struct Entry {
+    #[pyo3(get)]
+    path: String,
+    #[pyo3(get)]
+    is_dir: bool,
+    #[pyo3(get)]
+    is_file: bool,
+    #[pyo3(get)]
+    is_symlink: bool,
+    /// Size in bytes, or None if the metadata could not be read
+    #[pyo3(get)]
+    size: Option<u64>,
+    /// Modification time as a Unix timestamp
+    #[pyo3(get)]
+    mtime: Option<f64>,
+}
+
+impl Entry {
+    /// Capture type and metadata from a walker entry
+    fn from_dir_entry(entry: &DirEntry, path: String, follow_file_symlinks: bool) -> Self {
+        let metadata = file_symlink_metadata(entry, follow_file_symlinks)
+            .or_else(|| entry.metadata().ok());
+        let file_type = metadata.as_ref().map(|m| m.file_type()).or_else(|| entry.file_type());
+        Self {
+            path,
+            is_dir: file_type.is_some_and(|ft| ft.is_dir()),
+            is_file: file_type.is_some_and(|ft| ft.is_file()),
+            is_symlink: entry.path_is_symlink(),
+            size: metadata.as_ref().map(|m| m.len()),
+            mtime: metadata.and_then(|m| m.modified().ok()).and_then(unix_seconds),
+        }
+    }
+    
+    /// Capture type and metadata of a directory the walker did not yield itself
+    fn from_dir_path(dir: &Path, path: String) -> Self {
+        let metadata = std::fs::metadata(dir).ok();
+        Self {
+            path,
+            is_dir: metadata.as_ref().is_some_and(|m| m.is_dir()),
+            is_file: false,
+            is_symlink: dir.is_symlink(),
+            size: metadata.as_ref().map(|m| m.len()),
+            mtime: metadata.and_then(|m| m.modified().ok()).and_then(unix_seconds),
+        }
+    }
+}
+
+/// Directory transition yielded by `emit_dir_events`
+///
+/// `kind` is `"enter"` before anything inside `path` and `"leave"` after it,
+/// so a consumer can rebuild the tree as results stream in.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+struct DirEvent {
+    #[pyo3(get)]
+    kind: &'static str,
+    #[pyo3(get)]
+    path: String,
+}
+
+#[pymethods]
+impl DirEvent {
+    fn __fspath__(&self) -> &str {
+        &self.path
+    }
+    
+    fn __repr__(&self) -> String {
+        format!("<DirEvent {} '{}'>", self.kind, self.path)
+    }
+}
+
+/// Snapshot of the largest files found so far, yielded with `top_k`
+///
+/// Each snapshot supersedes the previous one; only the one with `final` set
+/// reflects the whole walk.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+struct TopKUpdate {
+    /// `(path, size)` pairs, largest first
+    #[pyo3(get)]
+    files: Vec<(String, u64)>,
+    #[pyo3(get, name = "final")]
+    is_final: bool,
+}
+
+#[pymethods]
+impl TopKUpdate {
+    fn __repr__(&self) -> String {
+        format!("<TopKUpdate {} files{}>", self.files.len(), if self.is_final { ", final" } else { "" })
+    }
+}
+
+/// Seconds since the Unix epoch, or `None` for times before it
+fn unix_seconds(time: SystemTime) -> Option<f64> {
+    time.duration_since(SystemTime::UNIX_EPOCH).ok().map(|d| d.as_secs_f64())
+}
+
+#[pymethods]
+impl Entry {
+    /// The final path component
+    #[getter]
+    fn name(&self) -> &str {
+        Path::new(&self.path).file_name().and_then(|n| n.to_str()).unwrap_or(&self.path)
+    }
+    
+    fn __fspath__(&self) -> &str {
+        &self.path
+    }
+    
+    fn __str__(&self) -> &str {
+        &self.path
+    }
+    
+    fn __repr__(&self) -> String {
+        format!("<Entry '{}'>", self.path)
+    }
+}
+
+/// Separator style of emitted paths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PathStyle {
+    /// Paths as the OS reports them, as `pathlib.Path`
+    Native,
+    /// Forward slashes on every platform, as `pathlib.PurePosixPath`
+    Posix,
+    /// A caller-chosen separator on every platform, as strings only
+    Separator(char),
+}
+
+impl PathStyle {
+    /// Parse `path_style`, or use `separator` when one is given
+    fn parse(style: &str, separator: Option<char>) -> PyResult<Self> {
+        match (style, separator) {
+            ("native", Some(separator)) => Ok(PathStyle::Separator(separator)),
+            (_, Some(_)) => Err(PyValueError::new_err("separator cannot be combined with path_style")),
+            ("native", None) => Ok(PathStyle::Native),
+            ("posix", None) => Ok(PathStyle::Posix),
+            _ => Err(PyValueError::new_err(format!("Invalid path_style: {}. Use 'native' or 'posix'", style))),
+        }
+    }
+    
+    /// Name of the `pathlib` class used for path objects
+    fn path_class(self) -> &'static str {
+        match self {
+            PathStyle::Native | PathStyle::Separator(_) => "Path",
+            PathStyle::Posix => "PurePosixPath",
+        }
+    }
+    
+    /// A found path as a string in this style
+    fn display(self, path: &Path) -> String {
+        self.apply(path.to_string_lossy().into_owned())
+    }
+    
+    /// Rewrite a path string in this style
+    ///
+    /// Only the platform separator is replaced, so a `\` in a Unix file name
+    /// is left alone.
+    fn apply(self, path: String) -> String {
+        let separator = match self {
+            PathStyle::Native => return path,
+            PathStyle::Posix => '/',
+            PathStyle::Separator(separator) => separator,
+        };
+        if separator != std::path::MAIN_SEPARATOR {
+            path.replace(std::path::MAIN_SEPARATOR, separator.encode_utf8(&mut [0; 4]))
+        } else {
+            path
+        }
+    }
+}
+
+/// Where directories are emitted relative to their contents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirOrder {
+    /// Whatever order the parallel walk produces
+    Default,
+    /// Each directory before anything inside it
+    Pre,
+    /// Each directory after everything inside it
+    Post,
+}
+
+impl DirOrder {
+    fn parse(order: &str) -> PyResult<Self> {
+        match order {
+            "default" => Ok(DirOrder::Default),
+            "pre" => Ok(DirOrder::Pre),
+            "post" => Ok(DirOrder::Post),
+            _ => Err(PyValueError::new_err(format!("Invalid dir_order: {}. Use 'default', 'pre' or 'post'", order))),
+        }
+    }
+}
+
+/// Fields of a find result computed during the walk on request
+#[derive(Debug, Clone, Default)]
+struct ResultExtras {
+    /// Line count of a text file, with `with_line_count`
+    line_count: Option<u64>,
+    /// Owner user name, with `resolve_owner`
+    owner: Option<String>,
+    /// Owner group name, with `resolve_owner`
+    group: Option<String>,
+    /// Leading text of a file, with `preview_bytes`
+    preview: Option<String>,
+    /// Hex digest of a file's contents, with `with_hash`
+    hash: Option<String>,
+    /// Name of the first `classify` group matching the entry
+    classification: Option<String>,
+}
+
+/// Extra keys requested for find results
+///
+/// Requesting any of them turns each result into a `{"path": ..., ...}` dict.
+#[derive(Debug, Clone, Copy, Default)]
+struct ResultFields {
+    /// `depth`, from `with_depth`
+    depth: bool,
+    /// `line_count`, from `with_line_count`
+    line_count: bool,
+    /// `owner` and `group`, from `resolve_owner`
+    owner: bool,
+    /// `preview`, from `preview_bytes`
+    preview: bool,
+    /// `hash`, from `with_hash`
+    hash: bool,
+    /// `group`, from `classify`
+    classification: bool,
+}
+
+impl ResultFields {
+    fn any(self) -> bool {
+        self.depth || self.line_count || self.owner || self.preview || self.hash || self.classification
+    }
+}
+
+/// Convert a found path to the object requested by the caller
+///
+/// When `fields` requests extra keys the object is wrapped in a
+/// `{"path": ..., "depth": ..., "line_count": ..., "owner": ..., "group": ...,
+/// "preview": ..., "hash": ...}` dict holding the requested ones; values that could not be determined, such
+/// as the line count of a directory, are `None`. With `sexp` the result is
+/// rendered by [`path_to_sexp`] instead.
+#[allow(clippy::too_many_arguments)]
+fn path_to_object(
+    py: Python<'_>,
+    path: &Path,
+    entry: Option<Entry>,
+    depth: usize,
+    extras: ResultExtras,
+    fields: ResultFields,
+    as_path_objects: bool,
+    path_style: PathStyle,
+    sexp: bool,
+) -> PyResult<PyObject> {
+    let path = path_style.display(path);
+    let entry = entry.map(|entry| Entry { path: path.clone(), ..entry });
+    if sexp {
+        Ok(path_to_sexp(&path, entry.as_ref(), depth, &extras, fields).into_pyobject(py)?.into())
+    } else if fields.any() {
+        let dict = PyDict::new(py);
+        dict.set_item("path", plain_path_object(py, path, entry, as_path_objects, path_style)?)?;
+        if fields.depth {
+            dict.set_item("depth", depth)?;
+        }
+        if fields.line_count {
+            dict.set_item("line_count", extras.line_count)?;
+        }
+        if fields.owner {
+            dict.set_item("owner", extras.owner)?;
+            dict.set_item("group", extras.group)?;
+        }
+        if fields.preview {
+            dict.set_item("preview", extras.preview)?;
+        }
+        if fields.hash {
+            dict.set_item("hash", extras.hash)?;
+        }
+        if fields.classification {
+            dict.set_item("group", extras.classification)?;
+        }
+        Ok(dict.into())
+    } else {
+        plain_path_object(py, path, entry, as_path_objects, path_style)
+    }
+}
+
+/// A styled path as an `Entry`, a `pathlib` object or a string
+fn plain_path_object(py: Python<'_>, path: String, entry: Option<Entry>, as_path_objects: bool, path_style: PathStyle) -> PyResult<PyObject> {
+    if let Some(entry) = entry {
+        Ok(Py::new(py, entry)?.into())
+    } else if as_path_objects {
+        let pathlib = py.import("pathlib")?;
+        let path_class = pathlib.getattr(path_style.path_class())?;
+        Ok(path_class.call1((path,))?.into())
+    } else {
+        Ok(path.into_pyobject(py)?.into())
+    }
+}
+
+/// Render a found path as the string handed to Python with `format="sexp"`
+///
+/// `(path "<path>" ...)`, or `(entry "<path>" :is-dir t :is-file nil
+/// :is-symlink nil :size N :mtime F ...)` with `as_entry`, followed by the
+/// keys `fields` requests: `:depth`, `:line-count`, `:owner` and `:group`,
+/// `:preview`, `:hash` and the `classify` `:group`, each `nil` when it could
+/// not be determined.
+fn path_to_sexp(path: &str, entry: Option<&Entry>, depth: usize, extras: &ResultExtras, fields: ResultFields) -> String {
+    let mut form = match entry {
+        Some(entry) => SexpForm::new("entry", path)
+            .field("is-dir", &entry.is_dir)
+            .field("is-file", &entry.is_file)
+            .field("is-symlink", &entry.is_symlink)
+            .field("size", &entry.size)
+            .field("mtime", &entry.mtime),
+        None => SexpForm::new("path", path),
+    };
+    if fields.depth {
+        form = form.field("depth", &depth);
+    }
+    if fields.line_count {
+        form = form.field("line-count", &extras.line_count);
+    }
+    if fields.owner {
+        form = form.field("owner", &extras.owner).field("group", &extras.group);
+    }
+    if fields.preview {
+        form = form.field("preview", &extras.preview);
+    }
+    if fields.hash {
+        form = form.field("hash", &extras.hash);
+    }
+    if fields.classification {
+        form = form.field("group", &extras.classification);
+    }
+    form.finish()
 }
 
 /// Buffer configuration for channel capacity optimization
@@ -74,17 +687,169 @@ impl BufferConfig {
             // Standard file finding - scale with thread count for better parallelism
             // More threads = potentially more concurrent file discoveries
             BufferConfig {
-                channel_capacity: 1000 * thread_count.max(1).min(8), // Cap at 8000 for memory
+                channel_capacity: 1000 * thread_count.clamp(1, 8), // Cap at 8000 for memory
             }
         }
     }
 }
 
+/// Scan counters shared by all walker threads
+#[derive(Debug, Default)]
+struct ScanStats {
+    /// Directory entries produced by the walker
+    entries_visited: AtomicU64,
+    /// Results emitted (paths for find, matching lines for search)
+    matched: AtomicU64,
+    /// Traversal and content search errors
+    errors: AtomicU64,
+    /// Bytes of file content read by the searcher (content search only)
+    bytes_searched: AtomicU64,
+    /// Files held back by `report_oversized`
+    oversized: AtomicU64,
+    /// Rough number of entries the walk will visit, for progress updates
+    estimated_total: AtomicU64,
+}
+
+/// Visited entries between two progress updates
+const PROGRESS_INTERVAL: u64 = 1000;
+
+impl ScanStats {
+    /// Count the received result it describes
+    fn record(&self, result: &FindResult) {
+        match result {
+            FindResult::Error(_) => self.errors.fetch_add(1, Ordering::Relaxed),
+            FindResult::Oversized(..) => self.oversized.fetch_add(1, Ordering::Relaxed),
+            FindResult::Progress(..) | FindResult::DirEnter(_) | FindResult::DirLeave(_) | FindResult::TopKUpdate(..) => return,
+            _ => self.matched.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+    
+    /// Count a visited entry, sending a progress update every `PROGRESS_INTERVAL` entries
+    ///
+    /// The estimate is raised to the visited count once the walk outgrows it.
+    fn visit(&self, tx: &crossbeam_channel::Sender<FindResult>) {
+        let visited = self.entries_visited.fetch_add(1, Ordering::Relaxed) + 1;
+        if visited.is_multiple_of(PROGRESS_INTERVAL) {
+            let estimate = self.estimated_total.load(Ordering::Relaxed).max(visited);
+            let _ = tx.send(FindResult::Progress(visited, estimate));
+        }
+    }
+    
+    /// Snapshot the counters as a Python dict
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("entries_visited", self.entries_visited.load(Ordering::Relaxed))?;
+        dict.set_item("matched", self.matched.load(Ordering::Relaxed))?;
+        dict.set_item("errors", self.errors.load(Ordering::Relaxed))?;
+        dict.set_item("bytes_searched", self.bytes_searched.load(Ordering::Relaxed))?;
+        dict.set_item("oversized", self.oversized.load(Ordering::Relaxed))?;
+        Ok(dict)
+    }
+}
+
 /// Python iterator class for streaming results
 #[pyclass]
 struct VexyGlobIterator {
     receiver: Option<Receiver<FindResult>>,
     as_path_objects: bool,
+    path_style: PathStyle,
+    stats: Option<Arc<ScanStats>>,
+    /// Error messages received so far, in arrival order
+    errors: Vec<String>,
+    /// Raise on the first error instead of collecting it
+    fail_fast: bool,
+    /// Files held back by `report_oversized`, with their sizes
+    oversized: Vec<(String, u64)>,
+    /// Latest progress update, when `with_stats` is set
+    progress: Option<(u64, u64)>,
+    /// Yield lists of up to this many results instead of single results
+    batch_size: Option<usize>,
+    /// Extra keys that turn yielded results into dicts
+    fields: ResultFields,
+    /// Reorder buffer for `stream_sort_window`
+    sort_window: Option<SortWindow>,
+    /// Yield results as S-expression strings (`format="sexp"`)
+    sexp: bool,
+}
+
+/// Bounded reorder buffer giving approximately path-sorted streaming
+///
+/// Holds up to `size` results and releases the smallest path once full, so
+/// output is sorted within any run of `size` results but not globally.
+struct SortWindow {
+    size: usize,
+    heap: BinaryHeap<Reverse<WindowedResult>>,
+}
+
+impl SortWindow {
+    fn new(size: usize) -> Self {
+        Self { size, heap: BinaryHeap::with_capacity(size) }
+    }
+}
+
+/// Minimum time between two `top_k` updates sent during the walk
+const TOP_K_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Largest files seen so far by the walk, for `top_k`
+struct TopK {
+    k: usize,
+    /// Kept files with the smallest on top, ready to be displaced
+    heap: BinaryHeap<Reverse<(u64, String)>>,
+    last_sent: Instant,
+}
+
+impl TopK {
+    fn new(k: usize) -> Self {
+        Self { k, heap: BinaryHeap::with_capacity(k + 1), last_sent: Instant::now() }
+    }
+    
+    /// Consider a file, sending an update when it changes the set and
+    /// `TOP_K_INTERVAL` has passed since the last one
+    ///
+    /// `path` is only rendered for files that make it in.
+    fn offer(&mut self, size: u64, path: impl FnOnce() -> String, tx: &crossbeam_channel::Sender<FindResult>) {
+        if self.heap.len() == self.k && self.heap.peek().is_some_and(|Reverse((smallest, _))| size <= *smallest) {
+            return;
+        }
+        self.heap.push(Reverse((size, path())));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+        if self.last_sent.elapsed() >= TOP_K_INTERVAL {
+            let _ = tx.send(self.update(false));
+        }
+    }
+    
+    /// The kept files as an update, largest first with ties broken by path
+    fn update(&mut self, is_final: bool) -> FindResult {
+        self.last_sent = Instant::now();
+        let mut files: Vec<(String, u64)> = self.heap.iter().map(|Reverse((size, path))| (path.clone(), *size)).collect();
+        files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        FindResult::TopKUpdate(files, is_final)
+    }
+}
+
+/// A buffered result ordered by its path
+struct WindowedResult(String, FindResult);
+
+impl PartialEq for WindowedResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for WindowedResult {}
+
+impl PartialOrd for WindowedResult {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WindowedResult {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
 }
 
 #[pymethods]
@@ -93,137 +858,738 @@ impl VexyGlobIterator {
         slf
     }
     
-    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<PyObject> {
-        if let Some(receiver) = &slf.receiver {
-            match receiver.recv() {
-                Ok(FindResult::Path(path_str)) => {
-                    Python::with_gil(|py| {
-                        if slf.as_path_objects {
-                            // Return as pathlib.Path
-                            let pathlib = py.import("pathlib").ok()?;
-                            let path_class = pathlib.getattr("Path").ok()?;
-                            Some(path_class.call1((path_str,)).ok()?.into())
-                        } else {
-                            // Return as string (already a string, no conversion needed)
-                            Some(path_str.into_pyobject(py).ok()?.into())
-                        }
-                    })
+    /// Scan statistics, available once the iterator is exhausted
+    ///
+    /// `None` while results are still streaming or when `with_stats` was not set.
+    #[getter]
+    fn stats(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        match (&self.stats, &self.receiver) {
+            (Some(stats), None) => Ok(Some(stats.to_dict(py)?.into())),
+            _ => Ok(None),
+        }
+    }
+    
+    /// Latest `(entries_visited, estimated_total)` progress update
+    ///
+    /// Only sent with `with_stats`, every thousand visited entries; once the
+    /// iterator is exhausted both numbers are the final entry count. `None`
+    /// before the first update.
+    #[getter]
+    fn progress(&self) -> Option<(u64, u64)> {
+        self.progress
+    }
+    
+    /// Errors encountered so far, e.g. unreadable directories or broken links
+    ///
+    /// Iteration skips over failures unless `fail_fast` is set; call this
+    /// afterwards to report a partial scan.
+    fn errors(&self) -> Vec<String> {
+        self.errors.clone()
+    }
+    
+    /// Files left out for exceeding `report_oversized`, as `(path, size)` pairs
+    fn oversized(&self) -> Vec<(String, u64)> {
+        self.oversized.clone()
+    }
+    
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
+        let Some(batch_size) = slf.batch_size else {
+            return slf.next_result();
+        };
+        // Fill each batch completely; only the last one can come up short
+        let mut batch = Vec::with_capacity(batch_size);
+        while batch.len() < batch_size {
+            match slf.next_result()? {
+                Some(item) => batch.push(item),
+                None => break,
+            }
+        }
+        if batch.is_empty() {
+            return Ok(None);
+        }
+        Ok(Python::with_gil(|py| pyo3::types::PyList::new(py, batch).ok().map(|list| list.into())))
+    }
+}
+
+impl VexyGlobIterator {
+    /// Receive the next result from the walker, through the sort window if set
+    ///
+    /// Errors and oversized files skip the window, since they are not yielded.
+    fn receive(&mut self) -> Option<FindResult> {
+        loop {
+            let received = self.receiver.as_ref().and_then(|rx| rx.recv().ok());
+            if let Some(FindResult::Progress(visited, estimate)) = received {
+                self.progress = Some((visited, estimate));
+                continue;
+            }
+            match (&self.stats, &received) {
+                (Some(stats), Some(result)) => stats.record(result),
+                // Channel closed, iteration complete
+                (stats, None) => {
+                    if let Some(stats) = stats {
+                        let visited = stats.entries_visited.load(Ordering::Relaxed);
+                        self.progress = Some((visited, visited));
+                    }
+                    self.receiver = None;
+                }
+                _ => {}
+            }
+            let Some(window) = self.sort_window.as_mut() else {
+                return received;
+            };
+            if let Some(result) = received {
+                let key = match &result {
+                    FindResult::Path(path, ..) | FindResult::Entry(_, path, ..) => path.to_string_lossy().into_owned(),
+                    _ => return Some(result),
+                };
+                window.heap.push(Reverse(WindowedResult(key, result)));
+                if window.heap.len() < window.size {
+                    continue;
+                }
+            }
+            return window.heap.pop().map(|Reverse(WindowedResult(_, result))| result);
+        }
+    }
+    
+    /// Receive the next result as a Python object, or `None` once the walk is done
+    ///
+    /// With `fail_fast` the first error ends iteration by raising `OSError`.
+    fn next_result(&mut self) -> PyResult<Option<PyObject>> {
+        loop {
+            let Some(result) = self.receive() else {
+                return Ok(None);
+            };
+            return Ok(match result {
+                FindResult::Path(path, depth, extras) => {
+                    Python::with_gil(|py| path_to_object(py, &path, None, depth, extras, self.fields, self.as_path_objects, self.path_style, self.sexp).ok())
+                }
+                FindResult::Entry(entry, path, depth, extras) => {
+                    Python::with_gil(|py| path_to_object(py, &path, Some(entry), depth, extras, self.fields, self.as_path_objects, self.path_style, self.sexp).ok())
+                }
+                FindResult::Search(search_result) if self.sexp => {
+                    Python::with_gil(|py| search_result.to_sexp().into_pyobject(py).ok().map(|text| text.into_any().unbind()))
                 }
-                Ok(FindResult::Search(search_result)) => {
+                FindResult::Search(search_result) => {
                     Python::with_gil(|py| {
                         // Create a dictionary representing SearchResult
-                        let result_dict = PyDict::new(py);
-                        
-                        let path_obj: PyObject = if slf.as_path_objects {
-                            let pathlib = py.import("pathlib").ok()?;
-                            let path_class = pathlib.getattr("Path").ok()?;
-                            path_class.call1((&search_result.path,)).ok()?.into()
-                        } else {
-                            search_result.path.clone().into_pyobject(py).ok()?.into()
-                        };
-                        
-                        result_dict.set_item("path", path_obj).ok()?;
-                        result_dict.set_item("line_number", search_result.line_number).ok()?;
-                        result_dict.set_item("line_text", search_result.line_text).ok()?;
-                        result_dict.set_item("matches", search_result.matches).ok()?;
-                        
+                        let result_dict = search_result.to_dict(py, self.as_path_objects, self.path_style).ok()?;
                         Some(result_dict.into())
                     })
                 }
-                Ok(FindResult::Error(err)) => {
-                    // Log error but continue iteration
-                    eprintln!("Error during traversal: {}", err);
-                    Self::__next__(slf)
+                FindResult::Error(err) if self.fail_fast => {
+                    // Dropping the receiver makes any further sends from the walker fail
+                    self.receiver = None;
+                    self.sort_window = None;
+                    self.errors.push(err.clone());
+                    return Err(PyOSError::new_err(err));
                 }
-                Err(_) => {
-                    // Channel closed, iteration complete
-                    slf.receiver = None;
-                    None
+                FindResult::Error(err) => {
+                    // Keep the error for errors() and continue iteration
+                    self.errors.push(err);
+                    continue;
                 }
-            }
-        } else {
-            None
+                FindResult::Oversized(path, size) => {
+                    self.oversized.push((path, size));
+                    continue;
+                }
+                FindResult::Progress(..) => continue,
+                FindResult::DirEnter(path) => Python::with_gil(|py| Py::new(py, DirEvent { kind: "enter", path }).ok().map(|event| event.into_any())),
+                FindResult::DirLeave(path) => Python::with_gil(|py| Py::new(py, DirEvent { kind: "leave", path }).ok().map(|event| event.into_any())),
+                FindResult::TopKUpdate(files, is_final) => {
+                    Python::with_gil(|py| Py::new(py, TopKUpdate { files, is_final }).ok().map(|update| update.into_any()))
+                }
+            });
         }
     }
 }
 
-/// Custom Sink implementation for collecting search results
-struct SearchSink {
-    path: String,  // Changed to String for zero-copy optimization
-    results: Vec<SearchResultRust>,
+/// Substitution applied to content search matches
+struct Replacer {
+    /// Byte regex equivalent to the content matcher
+    regex: regex::bytes::Regex,
+    /// Replacement text, may reference capture groups as `$1` or `${name}`
+    replacement: String,
+    /// Rewrite files on disk instead of only previewing
+    write: bool,
+    /// Keep the original file as `<name>.bak` when rewriting
+    backup: bool,
 }
 
-impl SearchSink {
-    fn new(path: String) -> Self {
-        Self {
-            path,
-            results: Vec::new(),
+impl Replacer {
+    /// Compile the substitution as a byte regex using the content matcher's flags
+    fn new(content_regex: &str, replacement: String, case_sensitive: bool, dotall: bool, write: bool, backup: bool) -> PyResult<Self> {
+        let regex = regex::bytes::RegexBuilder::new(content_regex)
+            .case_insensitive(!case_sensitive)
+            .dot_matches_new_line(dotall)
+            .multi_line(true)
+            .build()
+            .map_err(|e| PyValueError::new_err(format!("Invalid content regex: {}", e)))?;
+        Ok(Self { regex, replacement, write, backup })
+    }
+    
+    /// Apply the substitution, returning the new bytes and the number of replacements
+    fn apply(&self, haystack: &[u8]) -> (Vec<u8>, u64) {
+        let count = self.regex.find_iter(haystack).count() as u64;
+        let replaced = self.regex.replace_all(haystack, self.replacement.as_bytes());
+        (replaced.into_owned(), count)
+    }
+    
+    /// Rewrite a file with all substitutions applied, returning the replacement count
+    ///
+    /// Binary files (NUL bytes) are refused and files without matches are left
+    /// untouched. The new content is written to a temporary file next to the
+    /// original and atomically renamed over it.
+    fn rewrite_file(&self, path: &Path) -> std::io::Result<u64> {
+        let original = std::fs::read(path)?;
+        if original.contains(&0) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "refusing to rewrite binary file",
+            ));
+        }
+        
+        let (replaced, count) = self.apply(&original);
+        if count == 0 {
+            return Ok(0);
+        }
+        
+        let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let tmp_path = path.with_file_name(format!(".{}.{}.vexy_glob.tmp", file_name, std::process::id()));
+        let write_result = (|| {
+            let mut tmp = File::create(&tmp_path)?;
+            tmp.write_all(&replaced)?;
+            tmp.sync_all()?;
+            std::fs::set_permissions(&tmp_path, std::fs::metadata(path)?.permissions())?;
+            if self.backup {
+                std::fs::copy(path, path.with_file_name(format!("{}.bak", file_name)))?;
+            }
+            std::fs::rename(&tmp_path, path)
+        })();
+        if let Err(e) = write_result {
+            let _ = std::fs::remove_file(&tmp_path);
+            return Err(e);
+        }
+        
+        Ok(count)
+    }
+}
+
+/// Caps how many entries are emitted from each parent directory
+///
+/// Walker threads share the counters, so the cap is exact, but which entries of
+/// a directory get in depends on thread scheduling and varies between runs.
+struct DirLimiter {
+    limit: usize,
+    counts: Mutex<HashMap<PathBuf, usize>>,
+}
+
+impl DirLimiter {
+    fn new(limit: usize) -> Self {
+        Self { limit, counts: Mutex::new(HashMap::new()) }
+    }
+    
+    /// Count an entry against its parent directory, returning false once the cap is reached
+    fn admit(&self, path: &Path) -> bool {
+        let parent = path.parent().unwrap_or(path);
+        let mut counts = self.counts.lock().unwrap();
+        let count = match counts.get_mut(parent) {
+            Some(count) => count,
+            None => counts.entry(parent.to_path_buf()).or_insert(0),
+        };
+        if *count >= self.limit {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+}
+
+/// Counting semaphore bounding the files content search holds open at once
+///
+/// Each search worker takes a slot before opening a file and keeps it until
+/// the file, including any rewrite, is done.
+struct OpenFileLimiter {
+    available: Mutex<usize>,
+    freed: Condvar,
+}
+
+impl OpenFileLimiter {
+    fn new(limit: usize) -> Self {
+        Self { available: Mutex::new(limit), freed: Condvar::new() }
+    }
+    
+    /// Wait for a free slot, which is given back when the guard is dropped
+    fn acquire(&self) -> OpenFileSlot<'_> {
+        let mut available = self.freed.wait_while(self.available.lock().unwrap(), |n| *n == 0).unwrap();
+        *available -= 1;
+        OpenFileSlot(self)
+    }
+}
+
+/// A slot taken from an [`OpenFileLimiter`]
+struct OpenFileSlot<'a>(&'a OpenFileLimiter);
+
+impl Drop for OpenFileSlot<'_> {
+    fn drop(&mut self) {
+        *self.0.available.lock().unwrap() += 1;
+        self.0.freed.notify_one();
+    }
+}
+
+/// Matcher for a set of literal strings, backed by an Aho-Corasick automaton
+///
+/// Much faster than an equivalent regex alternation for large literal sets.
+/// The longest literal wins when several match at the same position.
+struct LiteralMatcher {
+    automaton: AhoCorasick,
+    /// No literal contains the line terminator, so matches never span lines
+    line_terminator: Option<LineTerminator>,
+}
+
+impl LiteralMatcher {
+    fn new(literals: &[String], case_insensitive: bool, line_terminator: u8) -> Result<Self> {
+        let automaton = AhoCorasickBuilder::new()
+            .ascii_case_insensitive(case_insensitive)
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(literals)?;
+        let line_terminator = (!literals.iter().any(|l| l.as_bytes().contains(&line_terminator)))
+            .then(|| LineTerminator::byte(line_terminator));
+        Ok(Self { automaton, line_terminator })
+    }
+}
+
+impl Matcher for LiteralMatcher {
+    type Captures = NoCaptures;
+    type Error = NoError;
+    
+    fn find_at(&self, haystack: &[u8], at: usize) -> Result<Option<Match>, NoError> {
+        let input = aho_corasick::Input::new(haystack).span(at..haystack.len());
+        Ok(self.automaton.find(input).map(|m| Match::new(m.start(), m.end())))
+    }
+    
+    fn new_captures(&self) -> Result<NoCaptures, NoError> {
+        Ok(NoCaptures::new())
+    }
+    
+    fn line_terminator(&self) -> Option<LineTerminator> {
+        self.line_terminator
+    }
+}
+
+/// Content search matcher: a regex or a set of literals
+///
+/// Only match positions are used, so captures are not exposed.
+enum ContentMatcher {
+    Regex(RegexMatcher),
+    Literals(LiteralMatcher),
+}
+
+impl Matcher for ContentMatcher {
+    type Captures = NoCaptures;
+    type Error = NoError;
+    
+    fn find_at(&self, haystack: &[u8], at: usize) -> Result<Option<Match>, NoError> {
+        match self {
+            ContentMatcher::Regex(matcher) => matcher.find_at(haystack, at),
+            ContentMatcher::Literals(matcher) => matcher.find_at(haystack, at),
+        }
+    }
+    
+    fn new_captures(&self) -> Result<NoCaptures, NoError> {
+        Ok(NoCaptures::new())
+    }
+    
+    fn shortest_match_at(&self, haystack: &[u8], at: usize) -> Result<Option<usize>, NoError> {
+        match self {
+            ContentMatcher::Regex(matcher) => matcher.shortest_match_at(haystack, at),
+            ContentMatcher::Literals(matcher) => matcher.shortest_match_at(haystack, at),
+        }
+    }
+    
+    fn non_matching_bytes(&self) -> Option<&ByteSet> {
+        match self {
+            ContentMatcher::Regex(matcher) => matcher.non_matching_bytes(),
+            ContentMatcher::Literals(matcher) => matcher.non_matching_bytes(),
+        }
+    }
+    
+    fn line_terminator(&self) -> Option<LineTerminator> {
+        match self {
+            ContentMatcher::Regex(matcher) => matcher.line_terminator(),
+            ContentMatcher::Literals(matcher) => matcher.line_terminator(),
+        }
+    }
+    
+    fn find_candidate_line(&self, haystack: &[u8]) -> Result<Option<LineMatchKind>, NoError> {
+        match self {
+            ContentMatcher::Regex(matcher) => matcher.find_candidate_line(haystack),
+            ContentMatcher::Literals(matcher) => matcher.find_candidate_line(haystack),
+        }
+    }
+}
+
+/// Settings shared by every file of a content search
+struct SearchOptions {
+    content_matcher: ContentMatcher,
+    /// Let matches span lines (`dotall`)
+    multi_line: bool,
+    /// Byte that ends each line (record), `\n` unless overridden
+    line_terminator: u8,
+    /// Transcode files from this encoding instead of sniffing a BOM
+    encoding: Option<Encoding>,
+    replacer: Option<Replacer>,
+    /// Collapse repeated identical matches on a line
+    unique_matches: bool,
+    /// Drop matches shorter than this many characters
+    min_match_length: Option<usize>,
+    /// Only search this many lines at the top of each file
+    head_lines: Option<u64>,
+    /// Group matches into hunks with this many context lines (`format="hunks"`)
+    hunk_context: Option<usize>,
+    /// Prefix and suffix inserted around each match in `highlighted_line`
+    highlight: Option<(String, String)>,
+    /// Search inside `.tar`, `.tar.gz` and `.tgz` files
+    archives: bool,
+    /// Only the existence of a match matters, so stop at the first one
+    quiet: bool,
+    /// Attach the file's size and mtime to each result
+    search_metadata: bool,
+    /// Attach the bytes read from the file to each result
+    report_bytes: bool,
+    /// Separator style of reported paths
+    path_style: PathStyle,
+    /// Caps how many files are open for searching at once
+    open_files: Option<OpenFileLimiter>,
+    /// Set by the first match of a quiet search, telling the walker to stop
+    quit: AtomicBool,
+}
+
+impl SearchOptions {
+    /// Searcher configured for these options
+    ///
+    /// Files with a UTF-16 or UTF-8 BOM, or any file when `encoding` is set, are
+    /// transcoded to UTF-8 before matching, so sinks always see UTF-8 lines.
+    ///
+    /// With `head_lines`, non-matching lines are passed through to the sink
+    /// so it can see where the head ends and stop reading there. Hunks get
+    /// their context lines from the searcher too.
+    fn searcher(&self) -> Searcher {
+        let context = self.hunk_context.unwrap_or(0);
+        SearcherBuilder::new()
+            .multi_line(self.multi_line)
+            .line_terminator(LineTerminator::byte(self.line_terminator))
+            .encoding(self.encoding.clone())
+            .passthru(self.head_lines.is_some())
+            .before_context(context)
+            .after_context(context)
+            .build()
+    }
+}
+
+/// Custom Sink implementation for collecting search results
+struct SearchSink<'a> {
+    path: String,  // Changed to String for zero-copy optimization
+    results: Vec<SearchResultRust>,
+    /// Hunks built so far with `hunk_context`
+    hunks: Vec<Hunk>,
+    /// Bytes the searcher reported reading once it finished
+    bytes_read: u64,
+    options: &'a SearchOptions,
+}
+
+impl<'a> SearchSink<'a> {
+    fn new(path: String, options: &'a SearchOptions) -> Self {
+        Self {
+            path: options.path_style.apply(path),
+            results: Vec::new(),
+            hunks: Vec::new(),
+            bytes_read: 0,
+            options,
         }
     }
     
+    /// Results for the searched file; with hunks, a single result holding them all
     fn into_results(self) -> Vec<SearchResultRust> {
-        self.results
+        let bytes_read = self.options.report_bytes.then_some(self.bytes_read);
+        let mut results = if self.hunks.is_empty() {
+            self.results
+        } else {
+            self.hunk_result()
+        };
+        if bytes_read.is_some() {
+            for result in &mut results {
+                result.bytes_read = bytes_read;
+            }
+        }
+        results
+    }
+    
+    /// Single result holding every hunk of the file
+    fn hunk_result(self) -> Vec<SearchResultRust> {
+        let match_lines = self.hunks.iter().map(|hunk| hunk.match_lines.len() as u64).sum();
+        vec![SearchResultRust {
+            path: self.path,
+            line_number: self.hunks[0].start_line,
+            line_text: String::new(),
+            matches: Vec::new(),
+            matches_on_line: match_lines,
+            replacement: None,
+            highlighted_line: None,
+            replacements: None,
+            size: None,
+            mtime: None,
+            bytes_read: None,
+            hunks: Some(self.hunks),
+        }]
+    }
+    
+    /// Add the lines in `bytes`, starting at `line_number`, to the current hunk
+    ///
+    /// The searcher only reports context lines next to a match, so a line that
+    /// does not directly follow the current hunk starts a new one.
+    fn push_hunk_lines(&mut self, mut line_number: u64, bytes: &[u8], matched: bool) {
+        let terminator = self.options.line_terminator;
+        for line in bytes.split_inclusive(|&byte| byte == terminator) {
+            let mut text = line.strip_suffix(&[terminator]).unwrap_or(line);
+            if terminator == b'\n' {
+                text = text.strip_suffix(b"\r").unwrap_or(text);
+            }
+            let contiguous = self.hunks.last().is_some_and(|hunk| hunk.start_line + hunk.lines.len() as u64 == line_number);
+            if !contiguous {
+                self.hunks.push(Hunk { start_line: line_number, lines: Vec::new(), match_lines: Vec::new() });
+            }
+            let hunk = self.hunks.last_mut().unwrap();
+            hunk.lines.push(String::from_utf8_lossy(text).into_owned());
+            if matched {
+                hunk.match_lines.push(line_number);
+            }
+            line_number += 1;
+        }
     }
 }
 
-impl Sink for SearchSink {
+impl Sink for SearchSink<'_> {
     type Error = std::io::Error;
     
     fn matched(&mut self, _searcher: &Searcher, mat: &SinkMatch<'_>) -> Result<bool, Self::Error> {
         let line_number = mat.line_number().unwrap_or(0);
+        if self.options.head_lines.is_some_and(|head| line_number > head) {
+            return Ok(false);
+        }
         
         // Extract the line text from the buffer
         let mut line_bytes = Vec::new();
         for line in mat.lines() {
             line_bytes.extend_from_slice(line);
         }
-        let line_text = String::from_utf8_lossy(&line_bytes).to_string();
+        // Transcoded lines are already UTF-8; only undecoded input can be invalid
+        let line_text = match std::str::from_utf8(&line_bytes) {
+            Ok(text) => text.to_owned(),
+            Err(_) => String::from_utf8_lossy(&line_bytes).into_owned(),
+        };
+        
+        // Extract each match from the line, in order of appearance
+        let mut matches: Vec<String> = Vec::new();
+        let mut spans: Vec<(usize, usize)> = Vec::new();
+        let mut matches_on_line = 0;
+        let mut too_short = false;
+        self.options.content_matcher.find_iter(&line_bytes, |m| {
+            let matched = String::from_utf8_lossy(&line_bytes[m.start()..m.end()]).into_owned();
+            if self.options.min_match_length.is_some_and(|min| matched.chars().count() < min) {
+                too_short = true;
+                return true;
+            }
+            matches_on_line += 1;
+            if self.options.highlight.is_some() {
+                spans.push((m.start(), m.end()));
+            }
+            if !(self.options.unique_matches && matches.contains(&matched)) {
+                matches.push(matched);
+            }
+            true
+        })?;
+        if matches.is_empty() {
+            // Lines whose only matches are too short are noise
+            if too_short {
+                if self.options.hunk_context.is_some() {
+                    self.push_hunk_lines(line_number, &line_bytes, false);
+                }
+                return Ok(true);
+            }
+            // Look-around style matches the line-level matcher cannot re-find
+            matches.push(line_text.trim().to_string());
+            matches_on_line = 1;
+        }
         
-        // Extract matches from the line
-        let mut matches = Vec::new();
-        // For now, just use the entire line as a match
-        // TODO: Extract actual regex matches
-        matches.push(line_text.trim().to_string());
+        // Preview the substitution on this line
+        let replacement = self.options.replacer.as_ref().map(|replacer| {
+            let (replaced, _) = replacer.apply(&line_bytes);
+            String::from_utf8_lossy(&replaced).into_owned()
+        });
         
-        self.results.push(SearchResultRust {
-            path: self.path.clone(),
-            line_number,
-            line_text,
-            matches,
+        let highlighted_line = self.options.highlight.as_ref().map(|(prefix, suffix)| {
+            highlight_spans(&line_bytes, &mut spans, prefix, suffix)
         });
         
+        if self.options.hunk_context.is_some() {
+            self.push_hunk_lines(line_number, &line_bytes, true);
+        } else {
+            self.results.push(SearchResultRust {
+                path: self.path.clone(),
+                line_number,
+                line_text,
+                matches,
+                matches_on_line,
+                replacement,
+                highlighted_line,
+                replacements: None,
+                size: None,
+                mtime: None,
+                bytes_read: None,
+                hunks: None,
+            });
+        }
+        
+        // One match answers a quiet search
+        if self.options.quiet {
+            self.options.quit.store(true, Ordering::Relaxed);
+            return Ok(false);
+        }
+        
         Ok(true) // Continue searching
     }
+    
+    fn context(&mut self, _searcher: &Searcher, context: &SinkContext<'_>) -> Result<bool, Self::Error> {
+        let line_number = context.line_number().unwrap_or(0);
+        if self.options.hunk_context.is_some() {
+            self.push_hunk_lines(line_number, context.bytes(), false);
+            return Ok(true);
+        }
+        // Otherwise only passthru lines arrive here; stop once the head has been read
+        Ok(self.options.head_lines.is_none_or(|head| line_number < head))
+    }
+    
+    /// Called on early stops too, with the offset the searcher reached
+    fn finish(&mut self, _searcher: &Searcher, finish: &SinkFinish) -> Result<(), Self::Error> {
+        self.bytes_read = finish.byte_count();
+        Ok(())
+    }
+}
+
+/// Wrap each match span of `line` in `prefix` and `suffix`
+///
+/// Overlapping and adjacent spans are merged first, so markers never nest
+/// and a run of touching matches is wrapped once.
+fn highlight_spans(line: &[u8], spans: &mut [(usize, usize)], prefix: &str, suffix: &str) -> String {
+    spans.sort_unstable();
+    let mut highlighted = Vec::with_capacity(line.len() + spans.len() * (prefix.len() + suffix.len()));
+    let mut written = 0;
+    let mut spans = spans.iter().copied().peekable();
+    while let Some((start, mut end)) = spans.next() {
+        while let Some(&(next_start, next_end)) = spans.peek() {
+            if next_start > end {
+                break;
+            }
+            end = end.max(next_end);
+            spans.next();
+        }
+        highlighted.extend_from_slice(&line[written..start]);
+        highlighted.extend_from_slice(prefix.as_bytes());
+        highlighted.extend_from_slice(&line[start..end]);
+        highlighted.extend_from_slice(suffix.as_bytes());
+        written = end;
+    }
+    highlighted.extend_from_slice(&line[written..]);
+    String::from_utf8_lossy(&highlighted).into_owned()
 }
 
 /// Find files and directories matching the given criteria
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 #[pyo3(signature = (
     paths,
     glob = None,
     regex = None,
+    regex_target = String::from("path"),
     file_type = None,
+    symlink_target_type = None,
+    symlink_broken = None,
+    content_type = None,
+    skip_empty_dirs = false,
+    dirs_with_matches = false,
     extension = None,
     exclude = None,
+    exclude_name = None,
     max_depth = None,
+    shallow_dirs = None,
+    per_dir_limit = None,
     min_size = None,
     max_size = None,
+    min_lines = None,
+    max_lines = None,
+    report_oversized = None,
     mtime_after = None,
     mtime_before = None,
+    changed_within = None,
+    changed_before = None,
+    newer_than_file = None,
     atime_after = None,
     atime_before = None,
     ctime_after = None,
     ctime_before = None,
+    include_inaccessible = false,
     hidden = false,
+    hidden_only = false,
     no_ignore = false,
     no_global_ignore = false,
+    no_vcs = false,
+    parents = true,
+    require_git = true,
+    git_tracked_only = false,
+    git_status = None,
     custom_ignore_files = None,
     follow_symlinks = false,
+    follow_file_symlinks = None,
+    follow_dir_symlinks = None,
+    metadata_follow = None,
+    max_symlink_hops = None,
     same_file_system = false,
+    skip_fs_types = None,
+    canonicalize_dedup = false,
     case_sensitive_glob = true,
+    full_path = false,
+    literal_separator = true,
+    case_sensitive_exclude = None,
     as_path_objects = false,
+    path_style = String::from("native"),
+    separator = None,
+    strict_utf8 = false,
+    fail_fast = false,
+    as_entries = false,
+    with_depth = false,
+    with_line_count = false,
+    resolve_owner = false,
+    preview_bytes = None,
+    with_hash = None,
+    classify = None,
+    exclude_unclassified = false,
+    relative_to = None,
     yield_results = true,
+    predicate = None,
     sort = None,
+    dir_order = String::from("default"),
+    emit_dir_events = false,
+    priority_roots = None,
+    stream_sort_window = None,
+    top_k = None,
+    group_by_dir = false,
+    dedupe_content = false,
+    null_separated = false,
+    sexp = false,
+    total_size = false,
+    count_by_extension = false,
+    with_stats = false,
+    batch_size = None,
     threads = 0
 ))]
 fn find(
@@ -231,50 +1597,96 @@ fn find(
     paths: Vec<String>,
     glob: Option<String>,
     regex: Option<String>,
-    file_type: Option<String>,
+    regex_target: String,
+    file_type: Option<StringOrList>,
+    symlink_target_type: Option<String>,
+    symlink_broken: Option<bool>,
+    content_type: Option<String>,
+    skip_empty_dirs: bool,
+    dirs_with_matches: bool,
     extension: Option<Vec<String>>,
     exclude: Option<Vec<String>>,
+    exclude_name: Option<Vec<String>>,
     max_depth: Option<usize>,
+    shallow_dirs: Option<Vec<String>>,
+    per_dir_limit: Option<usize>,
     min_size: Option<u64>,
     max_size: Option<u64>,
+    min_lines: Option<u64>,
+    max_lines: Option<u64>,
+    report_oversized: Option<u64>,
     mtime_after: Option<f64>,  // Unix timestamp as float
     mtime_before: Option<f64>, // Unix timestamp as float
+    changed_within: Option<f64>, // Seconds before now
+    changed_before: Option<f64>, // Seconds before now
+    newer_than_file: Option<String>,
     atime_after: Option<f64>,  // Unix timestamp as float
     atime_before: Option<f64>, // Unix timestamp as float
     ctime_after: Option<f64>,  // Unix timestamp as float
     ctime_before: Option<f64>, // Unix timestamp as float
+    include_inaccessible: bool,
     hidden: bool,
+    hidden_only: bool,
     no_ignore: bool,
     no_global_ignore: bool,
+    no_vcs: bool,
+    parents: bool,
+    require_git: bool,
+    git_tracked_only: bool,
+    git_status: Option<Vec<String>>,
     custom_ignore_files: Option<Vec<String>>,
     follow_symlinks: bool,
+    follow_file_symlinks: Option<bool>,
+    follow_dir_symlinks: Option<bool>,
+    metadata_follow: Option<bool>,
+    max_symlink_hops: Option<usize>,
     same_file_system: bool,
+    skip_fs_types: Option<Vec<String>>,
+    canonicalize_dedup: bool,
     case_sensitive_glob: bool,
+    full_path: bool,
+    literal_separator: bool,
+    case_sensitive_exclude: Option<bool>,
     as_path_objects: bool,
+    path_style: String,
+    separator: Option<char>,
+    strict_utf8: bool,
+    fail_fast: bool,
+    as_entries: bool,
+    with_depth: bool,
+    with_line_count: bool,
+    resolve_owner: bool,
+    preview_bytes: Option<usize>,
+    with_hash: Option<String>,
+    classify: Option<Vec<(String, String)>>,
+    exclude_unclassified: bool,
+    relative_to: Option<String>,
     yield_results: bool,
+    predicate: Option<PyObject>,
     sort: Option<String>,
+    dir_order: String,
+    emit_dir_events: bool,
+    priority_roots: Option<Vec<String>>,
+    stream_sort_window: Option<usize>,
+    top_k: Option<usize>,
+    group_by_dir: bool,
+    dedupe_content: bool,
+    null_separated: bool,
+    sexp: bool,
+    total_size: bool,
+    count_by_extension: bool,
+    with_stats: bool,
+    batch_size: Option<usize>,
     threads: usize,
 ) -> PyResult<PyObject> {
     // Build glob pattern matcher with literal optimization
     let pattern_matcher = if let Some(pattern) = glob {
-        Some(PatternMatcher::new(&pattern, case_sensitive_glob)
+        Some(PatternMatcher::new(&pattern, case_sensitive_glob, full_path, literal_separator)
             .map_err(|e| PyValueError::new_err(format!("Invalid glob pattern: {}", e)))?)
     } else {
         None
     };
     
-    // Build exclude pattern matcher
-    let exclude_set = if let Some(ref patterns) = exclude {
-        if !patterns.is_empty() {
-            Some(build_glob_set(patterns, case_sensitive_glob)
-                .map_err(|e| PyValueError::new_err(format!("Invalid exclude pattern: {}", e)))?)
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-    
     // Build regex matcher if provided
     let regex_matcher = if let Some(pattern) = regex {
         Some(regex::Regex::new(&pattern)
@@ -282,107 +1694,498 @@ fn find(
     } else {
         None
     };
+    let regex_target = parse_regex_target(&regex_target)?;
+    
+    find_compiled(
+        py,
+        Arc::new(pattern_matcher),
+        Arc::new(regex_matcher),
+        regex_target,
+        paths,
+        file_type,
+        symlink_target_type,
+        symlink_broken,
+        content_type,
+        skip_empty_dirs,
+        dirs_with_matches,
+        extension,
+        exclude,
+        exclude_name,
+        max_depth,
+        shallow_dirs,
+        per_dir_limit,
+        min_size,
+        max_size,
+        min_lines,
+        max_lines,
+        report_oversized,
+        mtime_after,
+        mtime_before,
+        changed_within,
+        changed_before,
+        newer_than_file,
+        atime_after,
+        atime_before,
+        ctime_after,
+        ctime_before,
+        include_inaccessible,
+        hidden,
+        hidden_only,
+        no_ignore,
+        no_global_ignore,
+        no_vcs,
+        parents,
+        require_git,
+        git_tracked_only,
+        git_status,
+        custom_ignore_files,
+        follow_symlinks,
+        follow_file_symlinks,
+        follow_dir_symlinks,
+        metadata_follow,
+        max_symlink_hops,
+        same_file_system,
+        skip_fs_types,
+        canonicalize_dedup,
+        case_sensitive_glob,
+        case_sensitive_exclude,
+        as_path_objects,
+        path_style,
+        separator,
+        strict_utf8,
+        fail_fast,
+        as_entries,
+        with_depth,
+        with_line_count,
+        resolve_owner,
+        preview_bytes,
+        with_hash,
+        classify,
+        exclude_unclassified,
+        relative_to,
+        yield_results,
+        predicate,
+        sort,
+        dir_order,
+        emit_dir_events,
+        priority_roots,
+        stream_sort_window,
+        top_k,
+        group_by_dir,
+        dedupe_content,
+        null_separated,
+        sexp,
+        total_size,
+        count_by_extension,
+        with_stats,
+        batch_size,
+        threads,
+    )
+}
+
+/// Walk `paths` with already compiled glob and regex matchers
+///
+/// Shared by `find()` and `CompiledPattern.find()`, which reuses its matchers
+/// across calls instead of compiling them each time.
+#[allow(clippy::too_many_arguments)]
+fn find_compiled(
+    py: Python<'_>,
+    pattern_matcher: Arc<Option<PatternMatcher>>,
+    regex_matcher: Arc<Option<regex::Regex>>,
+    regex_target: MatchTarget,
+    paths: Vec<String>,
+    file_type: Option<StringOrList>,
+    symlink_target_type: Option<String>,
+    symlink_broken: Option<bool>,
+    content_type: Option<String>,
+    skip_empty_dirs: bool,
+    dirs_with_matches: bool,
+    extension: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    exclude_name: Option<Vec<String>>,
+    max_depth: Option<usize>,
+    shallow_dirs: Option<Vec<String>>,
+    per_dir_limit: Option<usize>,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    min_lines: Option<u64>,
+    max_lines: Option<u64>,
+    report_oversized: Option<u64>,
+    mtime_after: Option<f64>,  // Unix timestamp as float
+    mtime_before: Option<f64>, // Unix timestamp as float
+    changed_within: Option<f64>, // Seconds before now
+    changed_before: Option<f64>, // Seconds before now
+    newer_than_file: Option<String>,
+    atime_after: Option<f64>,  // Unix timestamp as float
+    atime_before: Option<f64>, // Unix timestamp as float
+    ctime_after: Option<f64>,  // Unix timestamp as float
+    ctime_before: Option<f64>, // Unix timestamp as float
+    include_inaccessible: bool,
+    hidden: bool,
+    hidden_only: bool,
+    no_ignore: bool,
+    no_global_ignore: bool,
+    no_vcs: bool,
+    parents: bool,
+    require_git: bool,
+    git_tracked_only: bool,
+    git_status: Option<Vec<String>>,
+    custom_ignore_files: Option<Vec<String>>,
+    follow_symlinks: bool,
+    follow_file_symlinks: Option<bool>,
+    follow_dir_symlinks: Option<bool>,
+    metadata_follow: Option<bool>,
+    max_symlink_hops: Option<usize>,
+    same_file_system: bool,
+    skip_fs_types: Option<Vec<String>>,
+    canonicalize_dedup: bool,
+    case_sensitive_glob: bool,
+    case_sensitive_exclude: Option<bool>,
+    as_path_objects: bool,
+    path_style: String,
+    separator: Option<char>,
+    strict_utf8: bool,
+    fail_fast: bool,
+    as_entries: bool,
+    with_depth: bool,
+    with_line_count: bool,
+    resolve_owner: bool,
+    preview_bytes: Option<usize>,
+    with_hash: Option<String>,
+    classify: Option<Vec<(String, String)>>,
+    exclude_unclassified: bool,
+    relative_to: Option<String>,
+    yield_results: bool,
+    predicate: Option<PyObject>,
+    sort: Option<String>,
+    dir_order: String,
+    emit_dir_events: bool,
+    priority_roots: Option<Vec<String>>,
+    stream_sort_window: Option<usize>,
+    top_k: Option<usize>,
+    group_by_dir: bool,
+    dedupe_content: bool,
+    null_separated: bool,
+    sexp: bool,
+    total_size: bool,
+    count_by_extension: bool,
+    with_stats: bool,
+    batch_size: Option<usize>,
+    threads: usize,
+) -> PyResult<PyObject> {
+    if paths.is_empty() {
+        return Err(PyValueError::new_err("at least one root path is required"));
+    }
+    
+    // Excludes and ignore files follow each root's filesystem case sensitivity unless overridden
+    let root_cases = Arc::new(RootCases::probe(&paths));
+    let case_sensitive_exclude = case_sensitive_exclude.or((!case_sensitive_glob).then_some(false));
+    
+    // Each kind of symlink can override the follow_symlinks default
+    let follow_file_symlinks = follow_file_symlinks.unwrap_or(follow_symlinks);
+    let follow_dir_symlinks = follow_dir_symlinks.unwrap_or(follow_symlinks);
+    
+    // Overlapping roots would walk the shared part twice
+    let git_decides = git_tracked_only || git_status.is_some();
+    let max_symlink_hops = max_symlink_hops.filter(|_| follow_file_symlinks || follow_dir_symlinks);
+    let rules = WalkRules {
+        hidden: hidden || hidden_only,
+        no_ignore,
+        no_vcs,
+        no_global_ignore,
+        git_decides,
+        parents,
+        require_git,
+        cases: &root_cases,
+        follow_dir_symlinks,
+        same_file_system,
+        max_depth,
+        custom_ignore_files: custom_ignore_files.as_deref(),
+        filtered: git_decides || shallow_dirs.is_some() || skip_fs_types.is_some() || max_symlink_hops.is_some(),
+    };
+    let paths = normalize_roots(paths, &rules);
+    
+    let path_style = PathStyle::parse(&path_style, separator)?;
+    if as_path_objects && matches!(path_style, PathStyle::Separator(_)) {
+        return Err(PyValueError::new_err("separator applies to path strings and cannot be combined with as_path_objects"));
+    }
+    if batch_size == Some(0) {
+        return Err(PyValueError::new_err("batch_size must be at least 1"));
+    }
+    let dir_order = DirOrder::parse(&dir_order)?;
+    if priority_roots.is_some() && dir_order != DirOrder::Default {
+        return Err(PyValueError::new_err("priority_roots cannot be combined with dir_order"));
+    }
+    // Priority roots as they appear under each walk root, so entry paths compare directly
+    let priority_paths: Vec<PathBuf> = priority_roots
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|priority| {
+            let priority = absolute_path(Path::new(priority));
+            paths.iter().filter_map(move |root| {
+                let inside = priority.strip_prefix(absolute_path(Path::new(root))).ok()?;
+                Some(Path::new(root).join(inside))
+            })
+        })
+        .collect();
+    
+    // Build exclude pattern matcher
+    let exclude = exclude.unwrap_or_default();
+    let exclude_name = exclude_name.unwrap_or_default();
+    let exclude_set = if exclude.is_empty() && exclude_name.is_empty() {
+        None
+    } else {
+        Some(CaseMatchers::build(&root_cases, case_sensitive_exclude, |case_sensitive| {
+            ExcludeMatcher::new(&exclude, &exclude_name, case_sensitive)
+        })
+        .map_err(|e| PyValueError::new_err(format!("Invalid exclude pattern: {}", e)))?)
+    };
+    
+    // Fold relative change windows and the reference file into the absolute mtime bounds
+    let newer_than = newer_than_file.as_deref().map(reference_mtime).transpose()?;
+    let (mtime_after, mtime_before) = resolve_changed_bounds(
+        mtime_after, mtime_before, changed_within, changed_before, newer_than,
+    );
     
     // Parse file type filter
-    let file_type_filter = file_type.as_ref().and_then(|t| match t.as_str() {
-        "f" => Some(FileType::File),
-        "d" => Some(FileType::Dir),
-        "l" => Some(FileType::Symlink),
-        _ => None,
-    });
+    let file_type_filter = file_type.map(|types| FileTypeFilter::parse(&types.into_vec())).transpose()?;
+    let symlink_target_filter = symlink_target_type.as_deref().map(parse_symlink_target_type).transpose()?;
+    let content_type_filter = content_type.as_deref().map(parse_content_type).transpose()?;
+    
+    let fields = ResultFields {
+        depth: with_depth,
+        line_count: with_line_count,
+        owner: resolve_owner,
+        preview: preview_bytes.is_some(),
+        hash: with_hash.is_some(),
+        classification: classify.is_some(),
+    };
+    let with_hash = with_hash.as_deref().map(HashAlgorithm::parse).transpose()?;
+    if classify.is_some() && resolve_owner {
+        return Err(PyValueError::new_err("classify cannot be combined with resolve_owner, which also sets the group key"));
+    }
+    if exclude_unclassified && classify.is_none() {
+        return Err(PyValueError::new_err("exclude_unclassified requires classify"));
+    }
+    let classifier = classify
+        .map(|groups| Classifier::new(groups, case_sensitive_glob))
+        .transpose()?
+        .map(Arc::new);
+    if null_separated && (group_by_dir || fields.any()) {
+        return Err(PyValueError::new_err(
+            "null_separated cannot be combined with group_by_dir, with_depth, with_line_count, resolve_owner, preview_bytes or with_hash",
+        ));
+    }
+    if sexp && null_separated {
+        return Err(PyValueError::new_err("format='sexp' cannot be combined with null_separated"));
+    }
+    if dedupe_content && (null_separated || group_by_dir) {
+        return Err(PyValueError::new_err("dedupe_content cannot be combined with null_separated or group_by_dir"));
+    }
+    if total_size && (null_separated || group_by_dir || dedupe_content || dirs_with_matches || as_entries || fields.any() || predicate.is_some()) {
+        return Err(PyValueError::new_err(
+            "total_size cannot be combined with null_separated, group_by_dir, dedupe_content, dirs_with_matches, as_entry, predicate or per-result fields",
+        ));
+    }
+    if count_by_extension && (total_size || null_separated || group_by_dir || dedupe_content || dirs_with_matches || as_entries || fields.any() || predicate.is_some()) {
+        return Err(PyValueError::new_err(
+            "count_by_extension cannot be combined with total_size, null_separated, group_by_dir, dedupe_content, dirs_with_matches, as_entry, predicate or per-result fields",
+        ));
+    }
+    if dir_order != DirOrder::Default && sort.is_some() {
+        return Err(PyValueError::new_err("dir_order cannot be combined with sort"));
+    }
+    if stream_sort_window == Some(0) {
+        return Err(PyValueError::new_err("stream_sort_window must be at least 1"));
+    }
+    if dirs_with_matches && (dedupe_content || dir_order != DirOrder::Default) {
+        return Err(PyValueError::new_err("dirs_with_matches cannot be combined with dedupe_content or dir_order"));
+    }
+    if top_k == Some(0) {
+        return Err(PyValueError::new_err("top_k must be at least 1"));
+    }
+    if top_k.is_some() && (dirs_with_matches || stream_sort_window.is_some()) {
+        return Err(PyValueError::new_err("top_k cannot be combined with dirs_with_matches or stream_sort_window"));
+    }
+    if emit_dir_events && (!priority_paths.is_empty() || stream_sort_window.is_some()) {
+        return Err(PyValueError::new_err("emit_dir_events cannot be combined with priority_roots or stream_sort_window"));
+    }
     
-    // Force collection when sorting is requested
-    let actual_yield_results = yield_results && sort.is_none();
+    // Force collection when sorting, grouping, joining or filtering in Python is requested
+    let actual_yield_results = yield_results
+        && sort.is_none()
+        && !group_by_dir
+        && !null_separated
+        && !total_size
+        && !count_by_extension
+        && !dedupe_content
+        && predicate.is_none();
+    
+    // Oversized files are only reported through the iterator
+    if report_oversized.is_some() && !actual_yield_results {
+        return Err(PyValueError::new_err(
+            "report_oversized needs streamed results; it cannot be combined with as_list, sort, group_by_dir, dedupe_content, null_separated or predicate",
+        ));
+    }
+    if emit_dir_events && !actual_yield_results {
+        return Err(PyValueError::new_err(
+            "emit_dir_events needs streamed results; it cannot be combined with as_list, sort, group_by_dir, dedupe_content, null_separated, total_size, count_by_extension or predicate",
+        ));
+    }
+    if top_k.is_some() && !actual_yield_results {
+        return Err(PyValueError::new_err(
+            "top_k needs streamed results; it cannot be combined with as_list, sort, group_by_dir, dedupe_content, null_separated, total_size, count_by_extension or predicate",
+        ));
+    }
     
     // Get optimal buffer configuration
-    let buffer_config = BufferConfig::for_workload(false, sort.is_some(), threads);
+    let buffer_config = BufferConfig::for_workload(false, sort.is_some() || group_by_dir, threads);
     
     // Create channel for results with optimal capacity using global pool
     let (tx, rx) = global_init::get_channel_pool().get_channel(buffer_config.channel_capacity);
     
-    // Build the walker
-    let mut builder = WalkBuilder::new(&paths[0]);
-    
-    // Add additional paths
-    for path in &paths[1..] {
-        builder.add(path);
-    }
-    
-    // Configure walker options
-    builder
-        .hidden(!hidden)
-        .ignore(!no_ignore)  // respect .ignore files
-        .git_ignore(!no_ignore)  // respect .gitignore files
-        .git_global(!no_global_ignore)  // respect global gitignore
-        .git_exclude(!no_ignore)  // respect .git/info/exclude
-        .follow_links(follow_symlinks)  // follow symbolic links
-        .same_file_system(same_file_system)  // don't cross filesystem boundaries
-        .max_depth(max_depth)
-        .threads(if threads == 0 { num_cpus::get() } else { threads });
-    
-    // Add custom ignore files
-    if let Some(ref ignore_files) = custom_ignore_files {
-        for ignore_file in ignore_files {
-            if std::path::Path::new(ignore_file).exists() {
-                builder.add_ignore(ignore_file);
-            }
+    // Build the walkers
+    let mut builders = rules.builders(&paths);
+    for builder in &mut builders {
+        builder.threads(if threads == 0 { num_cpus::get() } else { threads });
+        
+        // A guaranteed directory order, and directory events, need the sequential
+        // walker; sorting by name also makes the output deterministic
+        if dir_order != DirOrder::Default || emit_dir_events {
+            builder.sort_by_file_name(|a, b| a.cmp(b));
         }
     }
     
-    // Automatically add .fdignore files if they exist and no_ignore is false
-    if !no_ignore {
-        for path in &paths {
-            let fdignore_path = std::path::Path::new(path).join(".fdignore");
-            if fdignore_path.exists() {
-                builder.add_ignore(&fdignore_path);
+    // Prune everything git does not track or report in the requested status
+    // categories, including untracked directories, anything below the direct
+    // children of a shallow directory, directories on skipped filesystem
+    // types, and links whose chain is longer than max_symlink_hops, reporting
+    // those as errors
+    let tracked = git_selected_paths(&paths, git_tracked_only, git_status.as_deref())?;
+    let shallow = shallow_dir_set(shallow_dirs, &root_cases, case_sensitive_exclude)?;
+    let fs_types = skip_fs_types.map(FsTypeFilter::new);
+    // Set once fail_fast stops the walk; the entry filter cannot return a
+    // walk state itself, so its errors stop the walk through this flag
+    let failed = Arc::new(AtomicBool::new(false));
+    let entry_filter: Option<EntryFilter> = if tracked.is_some() || shallow.is_some() || fs_types.is_some() || max_symlink_hops.is_some() {
+        let hops_tx = tx.clone();
+        let hops_failed = Arc::clone(&failed);
+        Some(Arc::new(move |entry: &DirEntry| {
+            if let Some(limit) = max_symlink_hops.filter(|_| entry.path_is_symlink()) {
+                if let Some(err) = symlink_hops_error(entry.path(), limit) {
+                    let _ = hops_tx.send(FindResult::Error(err));
+                    if fail_fast {
+                        hops_failed.store(true, Ordering::Relaxed);
+                    }
+                    return false;
+                }
             }
+            tracked.as_ref().is_none_or(|tracked| tracked.contains(entry.path()))
+                && shallow.as_ref().is_none_or(|shallow| !below_shallow_dir(entry, shallow.for_path(entry.path())))
+                && fs_types.as_ref().is_none_or(|fs_types| !fs_types.skips(entry))
+        }))
+    } else {
+        None
+    };
+    if let Some(ref filter) = entry_filter {
+        for builder in &mut builders {
+            let filter = Arc::clone(filter);
+            builder.filter_entry(move |entry| filter(entry));
         }
     }
     
+    // Entries these filters cannot stat are reported unless include_inaccessible is set
+    let size_filtered = min_size.is_some() || max_size.is_some();
+    let time_filtered = [mtime_after, mtime_before, atime_after, atime_before, ctime_after, ctime_before].iter().any(Option::is_some);
+    let check_metadata = !include_inaccessible && (size_filtered || time_filtered);
+    
     // Clone necessary data for the thread
-    let pattern_matcher = Arc::new(pattern_matcher);
     let exclude_set = Arc::new(exclude_set);
-    let regex_matcher = Arc::new(regex_matcher);
-    let extension = Arc::new(extension);
+    let extension = Arc::new(extension.map(ExtensionFilter::parse).transpose()?.flatten());
     let min_size = Arc::new(min_size);
     let max_size = Arc::new(max_size);
+    let min_lines = Arc::new(min_lines);
+    let max_lines = Arc::new(max_lines);
     let mtime_after = Arc::new(mtime_after);
     let mtime_before = Arc::new(mtime_before);
     let atime_after = Arc::new(atime_after);
     let atime_before = Arc::new(atime_before);
     let ctime_after = Arc::new(ctime_after);
     let ctime_before = Arc::new(ctime_before);
+    let stats: Option<Arc<ScanStats>> = with_stats.then(Default::default);
+    let walker_stats = stats.clone();
+    let dir_limiter = per_dir_limit.map(|limit| Arc::new(DirLimiter::new(limit)));
+    let matched_dirs: Option<Arc<Mutex<HashSet<PathBuf>>>> = dirs_with_matches.then(Default::default);
+    let canonical_paths: Option<Arc<Mutex<HashSet<PathBuf>>>> = canonicalize_dedup.then(Default::default);
+    let owner_names: Option<Arc<OwnerNames>> = resolve_owner.then(Default::default);
+    let total_bytes: Option<Arc<AtomicU64>> = total_size.then(Default::default);
+    let walker_total_bytes = total_bytes.clone();
+    let extension_counts: Option<Arc<Mutex<HashMap<String, u64>>>> = count_by_extension.then(Default::default);
+    let walker_extension_counts = extension_counts.clone();
+    let relative_base = relative_to.map(|base| Arc::new(absolute_path(Path::new(&base))));
+    let walker_relative_base = relative_base.clone();
+    let walker_top_k: Option<Arc<Mutex<TopK>>> = top_k.map(|k| Arc::new(Mutex::new(TopK::new(k))));
     
     // Spawn walker thread
     let walker_thread = std::thread::spawn(move || {
-        let walker = builder.build_parallel();
-        walker.run(|| {
-            let tx = tx.clone();
+        if let Some(ref stats) = walker_stats {
+            stats.estimated_total.store(estimate_walk_size(&paths), Ordering::Relaxed);
+        }
+        
+        // Each walker thread gets a visitor turning walk results into the result to send
+        let make_visitor = || {
             let pattern_matcher = Arc::clone(&pattern_matcher);
             let exclude_set = Arc::clone(&exclude_set);
             let regex_matcher = Arc::clone(&regex_matcher);
             let extension = Arc::clone(&extension);
             let min_size = Arc::clone(&min_size);
             let max_size = Arc::clone(&max_size);
+            let min_lines = Arc::clone(&min_lines);
+            let max_lines = Arc::clone(&max_lines);
             let mtime_after = Arc::clone(&mtime_after);
             let mtime_before = Arc::clone(&mtime_before);
             let atime_after = Arc::clone(&atime_after);
             let atime_before = Arc::clone(&atime_before);
             let ctime_after = Arc::clone(&ctime_after);
             let ctime_before = Arc::clone(&ctime_before);
+            let walker_stats = walker_stats.clone();
+            let dir_limiter = dir_limiter.clone();
+            let matched_dirs = matched_dirs.clone();
+            let canonical_paths = canonical_paths.clone();
+            let owner_names = owner_names.clone();
+            let classifier = classifier.clone();
+            let total_bytes = walker_total_bytes.clone();
+            let extension_counts = walker_extension_counts.clone();
+            let relative_base = walker_relative_base.clone();
+            let top_k = walker_top_k.clone();
+            let progress_tx = tx.clone();
             
-            Box::new(move |result| {
+            move |result: Result<ignore::DirEntry, ignore::Error>| -> Option<FindResult> {
                 match result {
                     Ok(entry) => {
+                        if let Some(ref stats) = walker_stats {
+                            stats.visit(&progress_tx);
+                        }
                         if should_include_entry(
                             &entry,
                             &pattern_matcher,
+                            &None,
                             &exclude_set,
                             &regex_matcher,
+                            regex_target,
                             file_type_filter,
+                            symlink_target_filter,
+                            symlink_broken,
+                            content_type_filter,
+                            hidden_only,
+                            skip_empty_dirs,
+                            follow_file_symlinks,
+                            metadata_follow,
                             &extension,
                             *min_size,
                             *max_size,
+                            *min_lines,
+                            *max_lines,
                             *mtime_after,
                             *mtime_before,
                             *atime_after,
@@ -390,169 +2193,1429 @@ fn find(
                             *ctime_after,
                             *ctime_before,
                         ) {
-                            // Zero-copy optimization: convert path to string once
-                            let path_string = entry.path().to_string_lossy().into_owned();
-                            let _ = tx.send(FindResult::Path(path_string));
+                            if check_metadata {
+                                if let Some(err) = unchecked_metadata_error(&entry, follow_file_symlinks, size_filtered, time_filtered) {
+                                    return Some(FindResult::Error(err));
+                                }
+                            }
+                            if strict_utf8 && entry.path().to_str().is_none() {
+                                return Some(FindResult::Error(non_utf8_path_error(entry.path())));
+                            }
+                            let classification = classifier.as_ref().map(|classifier| classifier.classify(&entry));
+                            if classification == Some(None) && exclude_unclassified {
+                                return None;
+                            }
+                            // Results keep the path as found; other reports are styled right away
+                            let found_path = |path: &Path| match relative_base.as_deref() {
+                                Some(base) => relative_display_path(path, base),
+                                None => path.to_path_buf(),
+                            };
+                            let path_string = |path: &Path| path_style.display(&found_path(path));
+                            // Oversized files are reported aside and take no per-directory slot
+                            if let Some(size) = report_oversized.and_then(|limit| oversized_file_size(&entry, limit, follow_file_symlinks)) {
+                                return Some(FindResult::Oversized(path_string(entry.path()), size));
+                            }
+                            // With dirs_with_matches each match stands in for its parent, emitted once
+                            let (path, depth) = match matched_dirs {
+                                Some(ref matched_dirs) => {
+                                    let parent = entry.path().parent().filter(|_| entry.depth() > 0)?;
+                                    if !matched_dirs.lock().unwrap().insert(parent.to_path_buf()) {
+                                        return None;
+                                    }
+                                    (parent, entry.depth() - 1)
+                                }
+                                None => (entry.path(), entry.depth()),
+                            };
+                            // Paths that resolve to an already emitted file are duplicates,
+                            // e.g. the same tree reached through a bind mount or a symlink
+                            if let Some(ref canonical_paths) = canonical_paths {
+                                let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+                                if !canonical_paths.lock().unwrap().insert(canonical) {
+                                    return None;
+                                }
+                            }
+                            if dir_limiter.as_ref().is_some_and(|limiter| !limiter.admit(path)) {
+                                return None;
+                            }
+                            if let Some(ref top_k) = top_k {
+                                if let Some(size) = regular_file_size(&entry, follow_file_symlinks) {
+                                    top_k.lock().unwrap().offer(size, || path_string(path), &progress_tx);
+                                }
+                            }
+                            // Sizes are summed in place; no path is ever sent
+                            if let Some(ref total_bytes) = total_bytes {
+                                if let Some(size) = regular_file_size(&entry, follow_file_symlinks) {
+                                    total_bytes.fetch_add(size, Ordering::Relaxed);
+                                }
+                                return None;
+                            }
+                            // Extensions are tallied in place; directories are not counted
+                            if let Some(ref extension_counts) = extension_counts {
+                                if !entry.file_type().is_some_and(|ft| ft.is_dir()) {
+                                    let extension = path.extension().map(|ext| ext.to_string_lossy()).unwrap_or_default();
+                                    let mut counts = extension_counts.lock().unwrap();
+                                    match counts.get_mut(extension.as_ref()) {
+                                        Some(count) => *count += 1,
+                                        None => {
+                                            counts.insert(extension.into_owned(), 1);
+                                        }
+                                    }
+                                }
+                                return None;
+                            }
+                            let found = found_path(path);
+                            let mut extras = ResultExtras {
+                                classification: classification.flatten().map(str::to_string),
+                                ..Default::default()
+                            };
+                            if with_line_count && matched_dirs.is_none() {
+                                extras.line_count = regular_file_line_count(&entry, follow_file_symlinks);
+                            }
+                            if let Some(limit) = preview_bytes.filter(|_| matched_dirs.is_none()) {
+                                extras.preview = regular_file_preview(&entry, follow_file_symlinks, limit);
+                            }
+                            if let Some(algorithm) = with_hash.filter(|_| matched_dirs.is_none()) {
+                                extras.hash = regular_file_hash(&entry, follow_file_symlinks, algorithm);
+                            }
+                            if let Some(ref owner_names) = owner_names {
+                                // A matched directory stands in for the entry, so stat it instead
+                                let metadata = match matched_dirs {
+                                    Some(_) => std::fs::metadata(path),
+                                    None => entry.metadata().map_err(std::io::Error::other),
+                                };
+                                (extras.owner, extras.group) = resolve_owner_names(metadata.ok(), owner_names);
+                            }
+                            Some(if !as_entries {
+                                FindResult::Path(found, depth, extras)
+                            } else if matched_dirs.is_some() {
+                                let entry = Entry::from_dir_path(path, found.to_string_lossy().into_owned());
+                                FindResult::Entry(entry, found, depth, extras)
+                            } else {
+                                let entry = Entry::from_dir_entry(&entry, found.to_string_lossy().into_owned(), follow_file_symlinks);
+                                FindResult::Entry(entry, found, depth, extras)
+                            })
+                        } else {
+                            None
                         }
                     }
-                    Err(err) => {
-                        let _ = tx.send(FindResult::Error(err.to_string()));
-                    }
+                    Err(err) => Some(FindResult::Error(err.to_string())),
                 }
-                WalkState::Continue
-            })
-        });
-    });
-    
-    if actual_yield_results {
-        // Return iterator for streaming
-        Ok(Py::new(py, VexyGlobIterator {
-            receiver: Some(rx),
-            as_path_objects,
-        })?.into())
-    } else {
-        // Collect all results into a list
-        py.allow_threads(|| {
-            walker_thread.join().unwrap();
-        });
-        
-        let mut results = Vec::new();
-        while let Ok(result) = rx.recv() {
-            if let FindResult::Path(path) = result {
-                results.push(path);
             }
-        }
+        };
         
-        // Sort results if requested
-        if let Some(ref sort_by) = sort {
-            match sort_by.as_str() {
-                "name" => results.sort_by(|a, b| {
-                    let a_name = std::path::Path::new(a).file_name().and_then(|n| n.to_str()).unwrap_or("");
-                    let b_name = std::path::Path::new(b).file_name().and_then(|n| n.to_str()).unwrap_or("");
-                    a_name.cmp(b_name)
-                }),
-                "path" => results.sort(),
+        if dir_order == DirOrder::Default && !emit_dir_events {
+            // `skip` entries are walked through without being visited
+            let run = |builder: &WalkBuilder, skip: &(dyn Fn(&DirEntry) -> bool + Sync)| {
+                builder.build_parallel().run(|| {
+                    let tx = tx.clone();
+                    let visit = make_visitor();
+                    let failed = &failed;
+                    Box::new(move |result| {
+                        if failed.load(Ordering::Relaxed) {
+                            return WalkState::Quit;
+                        }
+                        if result.as_ref().is_ok_and(skip) {
+                            return WalkState::Continue;
+                        }
+                        if let Some(result) = visit(result) {
+                            let quit = fail_fast && matches!(result, FindResult::Error(_));
+                            let _ = tx.send(result);
+                            if quit {
+                                failed.store(true, Ordering::Relaxed);
+                                return WalkState::Quit;
+                            }
+                        }
+                        WalkState::Continue
+                    })
+                });
+            };
+            if priority_paths.is_empty() {
+                for builder in &builders {
+                    if !failed.load(Ordering::Relaxed) {
+                        run(builder, &|_| false);
+                    }
+                }
+            } else {
+                // Walk the priority roots and the directories leading to them
+                // first, then everything else, skipping what was already visited
+                let priority_paths = Arc::new(priority_paths);
+                let phase = |builder: &WalkBuilder, keep: fn(&Path, &[PathBuf]) -> bool| {
+                    let mut builder = builder.clone();
+                    let entry_filter = entry_filter.clone();
+                    let priority_paths = Arc::clone(&priority_paths);
+                    builder.filter_entry(move |entry| {
+                        entry_filter.as_ref().is_none_or(|filter| filter(entry)) && keep(entry.path(), &priority_paths)
+                    });
+                    builder
+                };
+                let leads_to_priority = |entry: &DirEntry| priority_paths.iter().any(|p| p.starts_with(entry.path()));
+                // Roots bypass the filter, so other roots are held back for the second phase
+                for builder in &builders {
+                    if !failed.load(Ordering::Relaxed) {
+                        let first = phase(builder, |path, priority| priority.iter().any(|p| p.starts_with(path) || path.starts_with(p)));
+                        run(&first, &|entry| entry.depth() == 0 && !leads_to_priority(entry));
+                    }
+                }
+                for builder in &builders {
+                    if !failed.load(Ordering::Relaxed) {
+                        let rest = phase(builder, |path, priority| !priority.iter().any(|p| path.starts_with(p)));
+                        run(&rest, &leads_to_priority);
+                    }
+                }
+            }
+        } else {
+            // The sequential walk is already pre-order; for post-order each
+            // directory waits on a stack until the walk leaves it
+            let visit = make_visitor();
+            let mut pending: Vec<(usize, FindResult)> = Vec::new();
+            // Directories entered but not yet left, innermost last; each is left
+            // before its own post-order result is sent
+            let mut open_dirs: Vec<(usize, String)> = Vec::new();
+            let event_path = |path: &Path| path_style.apply(match walker_relative_base.as_deref() {
+                Some(base) => relative_display(path, base),
+                None => path.to_string_lossy().into_owned(),
+            });
+            for result in builders.iter().flat_map(WalkBuilder::build) {
+                if failed.load(Ordering::Relaxed) {
+                    return;
+                }
+                let dir_depth = match &result {
+                    Ok(entry) if entry.file_type().is_some_and(|t| t.is_dir()) => Some(entry.depth()),
+                    _ => None,
+                };
+                if let Ok(entry) = &result {
+                    let depth = entry.depth();
+                    loop {
+                        let leave = open_dirs.last().is_some_and(|(d, _)| *d >= depth);
+                        let post = dir_order == DirOrder::Post && pending.last().is_some_and(|(d, _)| *d >= depth);
+                        if leave && (!post || open_dirs.last().unwrap().0 >= pending.last().unwrap().0) {
+                            let _ = tx.send(FindResult::DirLeave(open_dirs.pop().unwrap().1));
+                        } else if post {
+                            let _ = tx.send(pending.pop().unwrap().1);
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                let entered = match (&result, dir_depth) {
+                    (Ok(entry), Some(depth)) if emit_dir_events => Some((depth, event_path(entry.path()))),
+                    _ => None,
+                };
+                if let Some(found) = visit(result) {
+                    if fail_fast && matches!(found, FindResult::Error(_)) {
+                        let _ = tx.send(found);
+                        return;
+                    }
+                    match dir_depth {
+                        Some(depth) if dir_order == DirOrder::Post => pending.push((depth, found)),
+                        _ => {
+                            let _ = tx.send(found);
+                        }
+                    }
+                }
+                if let Some((depth, path)) = entered {
+                    let _ = tx.send(FindResult::DirEnter(path.clone()));
+                    open_dirs.push((depth, path));
+                }
+            }
+            while !open_dirs.is_empty() || !pending.is_empty() {
+                if open_dirs.last().map(|(d, _)| *d) >= pending.last().map(|(d, _)| *d) {
+                    let _ = tx.send(FindResult::DirLeave(open_dirs.pop().unwrap().1));
+                } else {
+                    let _ = tx.send(pending.pop().unwrap().1);
+                }
+            }
+        }
+        if let Some(ref top_k) = walker_top_k {
+            let _ = tx.send(top_k.lock().unwrap().update(true));
+        }
+    });
+    
+    if actual_yield_results {
+        // Return iterator for streaming
+        Ok(Py::new(py, VexyGlobIterator {
+            receiver: Some(rx),
+            as_path_objects,
+            path_style,
+            stats,
+            errors: Vec::new(),
+            fail_fast,
+            oversized: Vec::new(),
+            progress: None,
+            batch_size,
+            fields,
+            sort_window: stream_sort_window.map(SortWindow::new),
+            sexp,
+        })?.into())
+    } else {
+        // Collect all results into a list, draining while the walker runs so a
+        // full channel can never block it
+        let mut results = Vec::new();
+        let mut first_error = None;
+        py.allow_threads(|| {
+            for result in rx.iter() {
+                if let Some(ref stats) = stats {
+                    stats.record(&result);
+                }
+                match result {
+                    FindResult::Path(path, depth, extras) => results.push((path, depth, extras, None)),
+                    FindResult::Entry(entry, path, depth, extras) => results.push((path, depth, extras, Some(entry))),
+                    FindResult::Error(err) if fail_fast => {
+                        first_error.get_or_insert(err);
+                    }
+                    _ => {}
+                }
+            }
+            walker_thread.join().unwrap();
+        });
+        if let Some(err) = first_error {
+            return Err(PyOSError::new_err(err));
+        }
+        if let Some(total_bytes) = total_bytes {
+            let total = total_bytes.load(Ordering::Relaxed).into_pyobject(py)?.into_any().unbind();
+            return attach_stats(py, total, &stats);
+        }
+        if let Some(extension_counts) = extension_counts {
+            // Most common first, ties broken by extension
+            let mut counts: Vec<(String, u64)> = std::mem::take(&mut *extension_counts.lock().unwrap()).into_iter().collect();
+            counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            let dict = PyDict::new(py);
+            for (extension, count) in counts {
+                dict.set_item(extension, count)?;
+            }
+            return attach_stats(py, dict.into(), &stats);
+        }
+        
+        // Keep only what the Python predicate accepts, before the more costly sorts
+        if let Some(predicate) = predicate {
+            let mut kept = Vec::with_capacity(results.len());
+            for (path, depth, extras, entry) in results {
+                let accepted = match entry {
+                    Some(ref entry) => predicate.bind(py).call1((Py::new(py, Entry { path: path_style.display(&path), ..entry.clone() })?,))?,
+                    None => predicate.bind(py).call1((path_style.display(&path),))?,
+                }
+                .is_truthy()?;
+                if accepted {
+                    kept.push((path, depth, extras, entry));
+                }
+            }
+            results = kept;
+        }
+        
+        // Sort results if requested, statting relative results against their base
+        let resolve = |p: &Path| match relative_base.as_deref() {
+            Some(base) => base.join(p),
+            None => p.to_path_buf(),
+        };
+        if let Some(ref sort_by) = sort {
+            match sort_by.as_str() {
+                "name" => results.sort_by(|(a, ..), (b, ..)| {
+                    let a_name = a.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                    let b_name = b.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                    a_name.cmp(b_name)
+                }),
+                "path" => results.sort_by(|(a, ..), (b, ..)| a.as_os_str().cmp(b.as_os_str())),
+                "depth" => results.sort_by(|(a, a_depth, ..), (b, b_depth, ..)| {
+                    a_depth.cmp(b_depth).then_with(|| a.as_os_str().cmp(b.as_os_str()))
+                }),
                 "size" => {
-                    results.sort_by_key(|p| {
-                        std::fs::metadata(p).ok().map(|m| m.len()).unwrap_or(0)
+                    results.sort_by_key(|(p, ..)| {
+                        std::fs::metadata(resolve(p)).ok().map(|m| m.len()).unwrap_or(0)
                     });
                 }
                 "mtime" => {
-                    results.sort_by_key(|p| {
-                        std::fs::metadata(p).ok()
+                    results.sort_by_key(|(p, ..)| {
+                        std::fs::metadata(resolve(p)).ok()
                             .and_then(|m| m.modified().ok())
                             .unwrap_or(SystemTime::UNIX_EPOCH)
                     });
                 }
-                _ => return Err(PyValueError::new_err(format!("Invalid sort option: {}. Use 'name', 'path', 'size', or 'mtime'", sort_by))),
+                _ => return Err(PyValueError::new_err(format!("Invalid sort option: {}. Use 'name', 'path', 'depth', 'size', or 'mtime'", sort_by))),
             }
         }
         
-        // Convert to Python list
-        Python::with_gil(|py| {
-            let py_list = pyo3::types::PyList::empty(py);
-            for path in results {
-                if as_path_objects {
-                    let pathlib = py.import("pathlib")?;
-                    let path_class = pathlib.getattr("Path")?;
-                    let path_obj = path_class.call1((path,))?;
-                    py_list.append(path_obj)?;
-                } else {
-                    py_list.append(path)?;
+        let collected: PyObject = if null_separated {
+            // Join in Rust so no intermediate list of Python strings is built
+            let mut joined = Vec::with_capacity(results.iter().map(|(p, ..)| p.as_os_str().len() + 1).sum());
+            for (path, ..) in &results {
+                push_path_bytes(&mut joined, path, path_style);
+                joined.push(0);
+            }
+            PyBytes::new(py, &joined).into()
+        } else if group_by_dir {
+            // Bucket by parent directory, keeping the sorted order within each group
+            Python::with_gil(|py| -> PyResult<PyObject> {
+                let groups = PyDict::new(py);
+                for (path, depth, extras, entry) in results {
+                    let parent = path.parent().map(|p| path_style.display(p)).unwrap_or_default();
+                    let entry = path_to_object(py, &path, entry, depth, extras, fields, as_path_objects, path_style, sexp)?;
+                    match groups.get_item(&parent)? {
+                        Some(group) => group.downcast::<pyo3::types::PyList>()?.append(entry)?,
+                        None => groups.set_item(parent, pyo3::types::PyList::new(py, [entry])?)?,
+                    }
+                }
+                Ok(groups.into())
+            })?
+        } else if dedupe_content {
+            // Hash outside the GIL, then key each group of duplicates by its hash
+            let files: Vec<PathBuf> = results.iter().map(|(p, ..)| resolve(p)).collect();
+            let groups = py.allow_threads(|| duplicate_groups(&files, follow_file_symlinks));
+            let mut results: Vec<Option<_>> = results.into_iter().map(Some).collect();
+            Python::with_gil(|py| -> PyResult<PyObject> {
+                let dict = PyDict::new(py);
+                for (digest, indices) in groups {
+                    let group = pyo3::types::PyList::empty(py);
+                    for index in indices {
+                        if let Some((path, depth, extras, entry)) = results[index].take() {
+                            group.append(path_to_object(py, &path, entry, depth, extras, fields, as_path_objects, path_style, sexp)?)?;
+                        }
+                    }
+                    dict.set_item(digest, group)?;
+                }
+                Ok(dict.into())
+            })?
+        } else {
+            // Convert to Python list
+            Python::with_gil(|py| -> PyResult<PyObject> {
+                let py_list = pyo3::types::PyList::empty(py);
+                for (path, depth, extras, entry) in results {
+                    py_list.append(path_to_object(py, &path, entry, depth, extras, fields, as_path_objects, path_style, sexp)?)?;
+                }
+                Ok(py_list.into())
+            })?
+        };
+        
+        attach_stats(py, collected, &stats)
+    }
+}
+
+/// Group files with identical contents, as `(digest, indices)` for each group of two or more
+///
+/// Files are bucketed by size first, so only files sharing a size with another
+/// file are read, and then by their XXH3 digest; contents are not compared
+/// byte for byte, so a 64-bit collision would merge two groups. Unfollowed
+/// symlinks are not files here, and of several paths to one file (hard links,
+/// followed symlinks) only the first is kept, so a file is never its own
+/// duplicate. Indices within a group, and the groups themselves, keep the
+/// order of `files`. Directories and unreadable files are never grouped.
+fn duplicate_groups(files: &[PathBuf], follow_file_symlinks: bool) -> Vec<(String, Vec<usize>)> {
+    let mut by_size: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut seen_files = HashSet::new();
+    for (index, path) in files.iter().enumerate() {
+        let metadata = if follow_file_symlinks { std::fs::metadata(path) } else { std::fs::symlink_metadata(path) };
+        if let Ok(metadata) = metadata {
+            if metadata.is_file() && file_identity(&metadata).is_none_or(|id| seen_files.insert(id)) {
+                by_size.entry(metadata.len()).or_default().push(index);
+            }
+        }
+    }
+    let candidates: Vec<(u64, usize)> = by_size
+        .into_iter()
+        .filter(|(_, group)| group.len() > 1)
+        .flat_map(|(size, group)| group.into_iter().map(move |index| (size, index)))
+        .collect();
+    let digests: Vec<(u64, usize, Option<String>)> = candidates
+        .par_iter()
+        .map(|&(size, index)| (size, index, HashAlgorithm::Xxh3.digest_file(&files[index]).ok()))
+        .collect();
+    
+    let mut by_digest: HashMap<(u64, String), Vec<usize>> = HashMap::new();
+    for (size, index, digest) in digests {
+        if let Some(digest) = digest {
+            by_digest.entry((size, digest)).or_default().push(index);
+        }
+    }
+    let mut groups: Vec<(String, Vec<usize>)> = by_digest
+        .into_iter()
+        .filter(|(_, group)| group.len() > 1)
+        .map(|((_, digest), mut group)| {
+            group.sort_unstable();
+            (digest, group)
+        })
+        .collect();
+    groups.sort_unstable_by_key(|(_, group)| group[0]);
+    groups
+}
+
+/// Device and inode identifying the file behind `metadata`
+#[cfg(unix)]
+fn file_identity(metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_identity(_metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Digest algorithm for `with_hash`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HashAlgorithm {
+    /// 64-bit XXH3, fast but not cryptographic
+    Xxh3,
+    Sha256,
+}
+
+impl HashAlgorithm {
+    fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "xxh3" => Ok(HashAlgorithm::Xxh3),
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            _ => Err(PyValueError::new_err(format!("Invalid with_hash: {}. Use 'xxh3' or 'sha256'", name))),
+        }
+    }
+    
+    /// Lowercase hex digest of a file's contents, reading it in chunks
+    fn digest_file(self, path: &Path) -> std::io::Result<String> {
+        use sha2::Digest;
+        
+        let mut file = File::open(path)?;
+        let mut buffer = [0u8; 64 * 1024];
+        let mut xxh3 = xxhash_rust::xxh3::Xxh3::new();
+        let mut sha256 = sha2::Sha256::new();
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            match self {
+                HashAlgorithm::Xxh3 => xxh3.update(&buffer[..read]),
+                HashAlgorithm::Sha256 => sha256.update(&buffer[..read]),
+            }
+        }
+        Ok(match self {
+            HashAlgorithm::Xxh3 => format!("{:016x}", xxh3.digest()),
+            HashAlgorithm::Sha256 => format!("{:x}", sha256.finalize()),
+        })
+    }
+}
+
+/// Glob and/or regex compiled once and reusable across many `find()` calls
+///
+/// Holds the compiled `GlobSet`/`Regex`, so repeated walks with different roots
+/// skip pattern parsing and the pattern cache entirely.
+#[pyclass(frozen)]
+struct CompiledPattern {
+    pattern: Option<String>,
+    regex: Option<String>,
+    case_sensitive: bool,
+    pattern_matcher: Arc<Option<PatternMatcher>>,
+    regex_matcher: Arc<Option<regex::Regex>>,
+    regex_target: MatchTarget,
+}
+
+#[pymethods]
+impl CompiledPattern {
+    #[new]
+    #[pyo3(signature = (glob = None, regex = None, regex_target = String::from("path"), case_sensitive = true, full_path = false, literal_separator = true))]
+    fn new(
+        glob: Option<String>,
+        regex: Option<String>,
+        regex_target: String,
+        case_sensitive: bool,
+        full_path: bool,
+        literal_separator: bool,
+    ) -> PyResult<Self> {
+        let pattern_matcher = glob.as_deref()
+            .map(|pattern| PatternMatcher::new(pattern, case_sensitive, full_path, literal_separator))
+            .transpose()
+            .map_err(|e| PyValueError::new_err(format!("Invalid glob pattern: {}", e)))?;
+        let regex_matcher = regex.as_deref()
+            .map(regex::Regex::new)
+            .transpose()
+            .map_err(|e| PyValueError::new_err(format!("Invalid regex pattern: {}", e)))?;
+        
+        Ok(Self {
+            pattern: glob,
+            regex,
+            case_sensitive,
+            pattern_matcher: Arc::new(pattern_matcher),
+            regex_matcher: Arc::new(regex_matcher),
+            regex_target: parse_regex_target(&regex_target)?,
+        })
+    }
+    
+    /// The glob this pattern was compiled from
+    #[getter]
+    fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+    
+    /// The path regex this pattern was compiled from
+    #[getter]
+    fn regex(&self) -> Option<&str> {
+        self.regex.as_deref()
+    }
+    
+    fn __repr__(&self) -> String {
+        format!("CompiledPattern(glob={:?}, regex={:?})", self.pattern, self.regex)
+    }
+    
+    /// Find entries under `paths` using the compiled matchers
+    ///
+    /// Accepts the same filters as the module-level `find()`.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        paths,
+        file_type = None,
+        symlink_target_type = None,
+        symlink_broken = None,
+        content_type = None,
+        skip_empty_dirs = false,
+        dirs_with_matches = false,
+        extension = None,
+        exclude = None,
+        exclude_name = None,
+        max_depth = None,
+        shallow_dirs = None,
+        per_dir_limit = None,
+        min_size = None,
+        max_size = None,
+        min_lines = None,
+        max_lines = None,
+        report_oversized = None,
+        mtime_after = None,
+        mtime_before = None,
+        changed_within = None,
+        changed_before = None,
+        newer_than_file = None,
+        atime_after = None,
+        atime_before = None,
+        ctime_after = None,
+        ctime_before = None,
+        include_inaccessible = false,
+        hidden = false,
+        hidden_only = false,
+        no_ignore = false,
+        no_global_ignore = false,
+        no_vcs = false,
+        parents = true,
+        require_git = true,
+        git_tracked_only = false,
+        git_status = None,
+        custom_ignore_files = None,
+        follow_symlinks = false,
+        follow_file_symlinks = None,
+        follow_dir_symlinks = None,
+        metadata_follow = None,
+        max_symlink_hops = None,
+        same_file_system = false,
+        skip_fs_types = None,
+        canonicalize_dedup = false,
+        case_sensitive_exclude = None,
+        as_path_objects = false,
+        path_style = String::from("native"),
+        separator = None,
+        strict_utf8 = false,
+        fail_fast = false,
+        as_entries = false,
+        with_depth = false,
+        with_line_count = false,
+        resolve_owner = false,
+        preview_bytes = None,
+        with_hash = None,
+        classify = None,
+        exclude_unclassified = false,
+        relative_to = None,
+        yield_results = true,
+        predicate = None,
+        sort = None,
+        dir_order = String::from("default"),
+        emit_dir_events = false,
+        priority_roots = None,
+        stream_sort_window = None,
+        top_k = None,
+        group_by_dir = false,
+        dedupe_content = false,
+        null_separated = false,
+        sexp = false,
+        total_size = false,
+        count_by_extension = false,
+        with_stats = false,
+        batch_size = None,
+        threads = 0
+    ))]
+    fn find(
+        &self,
+        py: Python<'_>,
+        paths: Vec<String>,
+        file_type: Option<StringOrList>,
+        symlink_target_type: Option<String>,
+        symlink_broken: Option<bool>,
+        content_type: Option<String>,
+        skip_empty_dirs: bool,
+        dirs_with_matches: bool,
+        extension: Option<Vec<String>>,
+        exclude: Option<Vec<String>>,
+        exclude_name: Option<Vec<String>>,
+        max_depth: Option<usize>,
+        shallow_dirs: Option<Vec<String>>,
+        per_dir_limit: Option<usize>,
+        min_size: Option<u64>,
+        max_size: Option<u64>,
+        min_lines: Option<u64>,
+        max_lines: Option<u64>,
+        report_oversized: Option<u64>,
+        mtime_after: Option<f64>,  // Unix timestamp as float
+        mtime_before: Option<f64>, // Unix timestamp as float
+        changed_within: Option<f64>, // Seconds before now
+        changed_before: Option<f64>, // Seconds before now
+        newer_than_file: Option<String>,
+        atime_after: Option<f64>,  // Unix timestamp as float
+        atime_before: Option<f64>, // Unix timestamp as float
+        ctime_after: Option<f64>,  // Unix timestamp as float
+        ctime_before: Option<f64>, // Unix timestamp as float
+        include_inaccessible: bool,
+        hidden: bool,
+        hidden_only: bool,
+        no_ignore: bool,
+        no_global_ignore: bool,
+        no_vcs: bool,
+        parents: bool,
+        require_git: bool,
+        git_tracked_only: bool,
+        git_status: Option<Vec<String>>,
+        custom_ignore_files: Option<Vec<String>>,
+        follow_symlinks: bool,
+        follow_file_symlinks: Option<bool>,
+        follow_dir_symlinks: Option<bool>,
+        metadata_follow: Option<bool>,
+        max_symlink_hops: Option<usize>,
+        same_file_system: bool,
+        skip_fs_types: Option<Vec<String>>,
+        canonicalize_dedup: bool,
+        case_sensitive_exclude: Option<bool>,
+        as_path_objects: bool,
+        path_style: String,
+        separator: Option<char>,
+        strict_utf8: bool,
+        fail_fast: bool,
+        as_entries: bool,
+        with_depth: bool,
+        with_line_count: bool,
+        resolve_owner: bool,
+        preview_bytes: Option<usize>,
+        with_hash: Option<String>,
+        classify: Option<Vec<(String, String)>>,
+        exclude_unclassified: bool,
+        relative_to: Option<String>,
+        yield_results: bool,
+        predicate: Option<PyObject>,
+        sort: Option<String>,
+        dir_order: String,
+        emit_dir_events: bool,
+        priority_roots: Option<Vec<String>>,
+        stream_sort_window: Option<usize>,
+        top_k: Option<usize>,
+        group_by_dir: bool,
+        dedupe_content: bool,
+        null_separated: bool,
+        sexp: bool,
+        total_size: bool,
+        count_by_extension: bool,
+        with_stats: bool,
+        batch_size: Option<usize>,
+        threads: usize,
+    ) -> PyResult<PyObject> {
+        find_compiled(
+            py,
+            Arc::clone(&self.pattern_matcher),
+            Arc::clone(&self.regex_matcher),
+            self.regex_target,
+            paths,
+            file_type,
+            symlink_target_type,
+            symlink_broken,
+            content_type,
+            skip_empty_dirs,
+            dirs_with_matches,
+            extension,
+            exclude,
+            exclude_name,
+            max_depth,
+            shallow_dirs,
+            per_dir_limit,
+            min_size,
+            max_size,
+            min_lines,
+            max_lines,
+            report_oversized,
+            mtime_after,
+            mtime_before,
+            changed_within,
+            changed_before,
+            newer_than_file,
+            atime_after,
+            atime_before,
+            ctime_after,
+            ctime_before,
+            include_inaccessible,
+            hidden,
+            hidden_only,
+            no_ignore,
+            no_global_ignore,
+            no_vcs,
+            parents,
+            require_git,
+            git_tracked_only,
+            git_status,
+            custom_ignore_files,
+            follow_symlinks,
+            follow_file_symlinks,
+            follow_dir_symlinks,
+            metadata_follow,
+            max_symlink_hops,
+            same_file_system,
+            skip_fs_types,
+            canonicalize_dedup,
+            self.case_sensitive,
+            case_sensitive_exclude,
+            as_path_objects,
+            path_style,
+            separator,
+            strict_utf8,
+            fail_fast,
+            as_entries,
+            with_depth,
+            with_line_count,
+            resolve_owner,
+            preview_bytes,
+            with_hash,
+            classify,
+            exclude_unclassified,
+            relative_to,
+            yield_results,
+            predicate,
+            sort,
+            dir_order,
+            emit_dir_events,
+            priority_roots,
+            stream_sort_window,
+            top_k,
+            group_by_dir,
+            dedupe_content,
+            null_separated,
+            sexp,
+            total_size,
+            count_by_extension,
+            with_stats,
+            batch_size,
+            threads,
+        )
+    }
+}
+
+/// Pre-compile glob patterns into the shared pattern cache
+///
+/// Returns a dict with how many patterns were newly `compiled` and how many
+/// were already `cached`.
+#[pyfunction]
+#[pyo3(signature = (patterns, case_sensitive = false))]
+fn warm_patterns(py: Python<'_>, patterns: Vec<String>, case_sensitive: bool) -> PyResult<PyObject> {
+    let mut compiled = 0;
+    let mut cached = 0;
+    for pattern in &patterns {
+        if pattern_cache::PATTERN_CACHE.contains(pattern, case_sensitive) {
+            cached += 1;
+        } else {
+            pattern_cache::PATTERN_CACHE
+                .get_or_compile(pattern, case_sensitive)
+                .map_err(|e| PyValueError::new_err(format!("Invalid glob pattern: {}", e)))?;
+            compiled += 1;
+        }
+    }
+    let counts = PyDict::new(py);
+    counts.set_item("compiled", compiled)?;
+    counts.set_item("cached", cached)?;
+    Ok(counts.into())
+}
+
+/// Check that a glob pattern compiles, returning the error message if not
+#[pyfunction]
+#[pyo3(signature = (pattern, case_sensitive = true))]
+fn validate_glob(pattern: &str, case_sensitive: bool) -> Option<String> {
+    PatternMatcher::new(pattern, case_sensitive, false, true).err().map(|e| e.to_string())
+}
+
+/// Check that a regular expression compiles, returning the error message if not
+#[pyfunction]
+fn validate_regex(pattern: &str) -> Option<String> {
+    regex::Regex::new(pattern).err().map(|e| e.to_string())
+}
+
+/// Describe what a content regex offers the search engine's literal optimizations
+///
+/// Returns `{"has_literal_prefix", "literal", "is_anchored"}`: `literal` is the
+/// text every match starts with, which lets the engine skip ahead with a fast
+/// substring search, or `None` when no such text can be extracted.
+/// `is_anchored` is set when every match must start at `^` or `\A`.
+#[pyfunction]
+fn analyze_content_pattern(py: Python<'_>, pattern: &str) -> PyResult<PyObject> {
+    use regex_syntax::hir::literal::{ExtractKind, Extractor};
+    use regex_syntax::hir::Look;
+    let hir = regex_syntax::parse(pattern)
+        .map_err(|e| PyValueError::new_err(format!("Invalid content regex: {}", e)))?;
+    let prefixes = Extractor::new().kind(ExtractKind::Prefix).extract(&hir);
+    let literal = prefixes
+        .longest_common_prefix()
+        .filter(|prefix| !prefix.is_empty())
+        .map(|prefix| String::from_utf8_lossy(prefix).into_owned());
+    let looks = hir.properties().look_set_prefix();
+    let is_anchored = looks.contains(Look::Start) || looks.contains(Look::StartLF) || looks.contains(Look::StartCRLF);
+    let dict = PyDict::new(py);
+    dict.set_item("has_literal_prefix", literal.is_some())?;
+    dict.set_item("literal", literal)?;
+    dict.set_item("is_anchored", is_anchored)?;
+    Ok(dict.into())
+}
+
+/// Explain why each walk candidate would be left out of `find` results
+///
+/// Walks `paths` with every ignore rule disabled and returns one
+/// `{"path", "excluded_by", "source", "pattern"}` dict for each entry the
+/// glob matches. `excluded_by` names the first rule that drops the entry:
+/// `"ignore_file"` (with the ignore file as `source` and its line as
+/// `pattern`), `"hidden"` (with the hidden path component), `"exclude"` or
+/// `"exclude_name"` (with the matching pattern), or `None` when none of these
+/// apply. Ignored directories are walked in full, so this is a debugging aid.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (
+    paths,
+    glob = None,
+    case_sensitive_glob = true,
+    full_path = false,
+    literal_separator = true,
+    exclude = None,
+    exclude_name = None,
+    case_sensitive_exclude = None,
+    max_depth = None,
+    hidden = false,
+    no_ignore = false,
+    no_vcs = false,
+    parents = true,
+    require_git = true,
+    custom_ignore_files = None,
+    follow_symlinks = false,
+))]
+fn explain_excludes(
+    py: Python<'_>,
+    paths: Vec<String>,
+    glob: Option<String>,
+    case_sensitive_glob: bool,
+    full_path: bool,
+    literal_separator: bool,
+    exclude: Option<Vec<String>>,
+    exclude_name: Option<Vec<String>>,
+    case_sensitive_exclude: Option<bool>,
+    max_depth: Option<usize>,
+    hidden: bool,
+    no_ignore: bool,
+    no_vcs: bool,
+    parents: bool,
+    require_git: bool,
+    custom_ignore_files: Option<Vec<String>>,
+    follow_symlinks: bool,
+) -> PyResult<Vec<PyObject>> {
+    if paths.is_empty() {
+        return Err(PyValueError::new_err("At least one path is required"));
+    }
+    let root_cases = Arc::new(RootCases::probe(&paths));
+    let case_sensitive_exclude = case_sensitive_exclude.or((!case_sensitive_glob).then_some(false));
+    let pattern_matcher = glob
+        .map(|pattern| PatternMatcher::new(&pattern, case_sensitive_glob, full_path, literal_separator))
+        .transpose()
+        .map_err(|e| PyValueError::new_err(format!("Invalid glob pattern: {}", e)))?;
+    let exclude = exclude.unwrap_or_default();
+    let exclude_name = exclude_name.unwrap_or_default();
+    let exclude_set = if exclude.is_empty() && exclude_name.is_empty() {
+        None
+    } else {
+        Some(CaseMatchers::build(&root_cases, case_sensitive_exclude, |case_sensitive| {
+            ExcludeMatcher::new(&exclude, &exclude_name, case_sensitive)
+        })
+        .map_err(|e| PyValueError::new_err(format!("Invalid exclude pattern: {}", e)))?)
+    };
+    
+    // The same explicit ignore files the walker is given
+    let mut explicit = Vec::new();
+    if !no_ignore {
+        explicit.extend(custom_ignore_files.unwrap_or_default().into_iter().map(PathBuf::from));
+        explicit.extend(paths.iter().map(|root| Path::new(root).join(".fdignore")));
+    }
+    
+    let mut results = Vec::new();
+    for root in &paths {
+        let case_insensitive = !root_cases.for_path(Path::new(root));
+        let mut explainer = IgnoreExplainer::new(Path::new(root), &explicit, parents, require_git, !no_vcs, case_insensitive);
+        let walker = WalkBuilder::new(root)
+            .standard_filters(false)
+            .follow_links(follow_symlinks)
+            .max_depth(max_depth)
+            .build();
+        for entry in walker.flatten() {
+            if entry.depth() == 0 || pattern_matcher.as_ref().is_some_and(|matcher| !matcher.is_match(&entry)) {
+                continue;
+            }
+            let path = entry.path();
+            let is_dir = entry.file_type().is_some_and(|file_type| file_type.is_dir());
+            let reason = if no_ignore { None } else { explainer.explain(path, is_dir) }
+                .map(|(source, line)| ("ignore_file", Some(source), line))
+                .or_else(|| {
+                    (!hidden).then(|| hidden_component(path, Path::new(root))).flatten()
+                        .map(|component| ("hidden", None, component))
+                })
+                .or_else(|| {
+                    exclude_set.as_ref()?.for_path(path).excluded_by(path).map(|(kind, pattern)| (kind, None, pattern))
+                });
+            let dict = PyDict::new(py);
+            dict.set_item("path", path.to_string_lossy())?;
+            match reason {
+                Some((kind, source, pattern)) => {
+                    dict.set_item("excluded_by", kind)?;
+                    dict.set_item("source", source)?;
+                    dict.set_item("pattern", pattern)?;
+                }
+                None => {
+                    dict.set_item("excluded_by", py.None())?;
+                    dict.set_item("source", py.None())?;
+                    dict.set_item("pattern", py.None())?;
                 }
             }
-            Ok(py_list.into())
+            results.push(dict.into());
+        }
+    }
+    Ok(results)
+}
+
+/// Search an in-memory buffer for content, returning results labelled `label`
+///
+/// Runs the same matcher and sink as `search` without touching the
+/// filesystem, so data that is already in memory can be searched directly.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (
+    data,
+    content_regex = None,
+    content_literals = None,
+    label = String::from("<bytes>"),
+    case_sensitive = true,
+    dotall = false,
+    whole_line = false,
+    line_terminator = None,
+    encoding = None,
+    unique_matches = false,
+    min_match_length = None,
+    head_lines = None,
+    replace = None
+))]
+fn search_bytes(
+    py: Python<'_>,
+    data: &[u8],
+    content_regex: Option<String>,
+    content_literals: Option<Vec<String>>,
+    label: String,
+    case_sensitive: bool,
+    dotall: bool,
+    whole_line: bool,
+    line_terminator: Option<u8>,
+    encoding: Option<String>,
+    unique_matches: bool,
+    min_match_length: Option<usize>,
+    head_lines: Option<u64>,
+    replace: Option<String>,
+) -> PyResult<Vec<PyObject>> {
+    let (content_matcher, content_regex) = build_content_matcher(
+        content_regex, content_literals, case_sensitive, dotall, whole_line, line_terminator,
+    )?;
+    let replacer = replace
+        .map(|replacement| Replacer::new(&content_regex, replacement, case_sensitive, dotall, false, false))
+        .transpose()?;
+    let encoding = encoding
+        .map(|label| Encoding::new(&label))
+        .transpose()
+        .map_err(|e| PyValueError::new_err(format!("Invalid encoding: {}", e)))?;
+    let options = SearchOptions {
+        content_matcher,
+        multi_line: dotall,
+        line_terminator: line_terminator.unwrap_or(b'\n'),
+        encoding,
+        replacer,
+        unique_matches,
+        min_match_length,
+        head_lines,
+        hunk_context: None,
+        highlight: None,
+        archives: false,
+        quiet: false,
+        search_metadata: false,
+        report_bytes: false,
+        path_style: PathStyle::Native,
+        open_files: None,
+        quit: AtomicBool::new(false),
+    };
+    
+    // Copy the buffer so the search can run without holding the GIL
+    let data = data.to_vec();
+    let results = py
+        .allow_threads(|| {
+            let mut sink = SearchSink::new(label, &options);
+            options.searcher()
+                .search_slice(&options.content_matcher, &data, &mut sink)
+                .map(|_| sink.into_results())
         })
+        .map_err(|e| PyValueError::new_err(format!("Search error: {}", e)))?;
+    results
+        .iter()
+        .map(|result| Ok(result.to_dict(py, false, PathStyle::Native)?.into()))
+        .collect()
+}
+
+/// Build the content matcher for a regex or a set of literals
+///
+/// `dotall` lets `.` match newlines, so matches may span lines, and
+/// `whole_line` anchors the pattern to line boundaries like `grep -x`.
+/// Also returns the pattern as a regex, which the substitution needs.
+fn build_content_matcher(
+    content_regex: Option<String>,
+    content_literals: Option<Vec<String>>,
+    case_sensitive: bool,
+    dotall: bool,
+    whole_line: bool,
+    line_terminator: Option<u8>,
+) -> PyResult<(ContentMatcher, String)> {
+    if whole_line && line_terminator.is_some_and(|term| term != b'\n') {
+        return Err(PyValueError::new_err("whole_line only supports the newline line_terminator"));
+    }
+    let build_regex = |content_regex: String| -> PyResult<(ContentMatcher, String)> {
+        let content_regex = if whole_line {
+            format!("(?m:^(?:{})$)", content_regex)
+        } else {
+            content_regex
+        };
+        // A custom terminator must not be matched, so records stay separate
+        let record_terminator = line_terminator.filter(|_| !dotall);
+        let matcher = RegexMatcherBuilder::new()
+            .case_insensitive(!case_sensitive)
+            .dot_matches_new_line(dotall)
+            .line_terminator(record_terminator)
+            .build(&content_regex)
+            .map_err(|e| PyValueError::new_err(format!("Invalid content regex: {}", e)))?;
+        Ok((ContentMatcher::Regex(matcher), content_regex))
+    };
+    match (content_regex, content_literals) {
+        (Some(content_regex), None) => build_regex(content_regex),
+        (None, Some(literals)) => {
+            // Only the substitution needs the literals as a regex, unless they
+            // must be anchored or folded beyond ASCII, which the automaton
+            // cannot do. Longest first, so the leftmost-first alternation
+            // picks the same match as the leftmost-longest automaton.
+            let mut by_length: Vec<&String> = literals.iter().collect();
+            by_length.sort_by_key(|literal| std::cmp::Reverse(literal.len()));
+            let alternation = by_length.iter().map(|l| regex::escape(l)).collect::<Vec<_>>().join("|");
+            if whole_line || (!case_sensitive && !literals.iter().all(|literal| literal.is_ascii())) {
+                build_regex(alternation)
+            } else {
+                let matcher = LiteralMatcher::new(&literals, !case_sensitive, line_terminator.unwrap_or(b'\n'))
+                    .map_err(|e| PyValueError::new_err(format!("Invalid content literals: {}", e)))?;
+                Ok((ContentMatcher::Literals(matcher), alternation))
+            }
+        }
+        _ => Err(PyValueError::new_err(
+            "Exactly one of content_regex and content_literals is required",
+        )),
     }
 }
 
 /// Search for content within files using grep functionality
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 #[pyo3(signature = (
     content_regex,
     paths,
+    file_list = None,
+    content_literals = None,
     glob = None,
+    name_filter = None,
     regex = None,
+    regex_target = String::from("path"),
     file_type = None,
+    symlink_target_type = None,
+    symlink_broken = None,
+    content_type = None,
     extension = None,
     exclude = None,
+    exclude_name = None,
     max_depth = None,
+    shallow_dirs = None,
+    per_dir_limit = None,
     min_size = None,
     max_size = None,
+    min_lines = None,
+    max_lines = None,
     mtime_after = None,
     mtime_before = None,
+    changed_within = None,
+    changed_before = None,
+    newer_than_file = None,
     atime_after = None,
     atime_before = None,
     ctime_after = None,
     ctime_before = None,
+    include_inaccessible = false,
     hidden = false,
+    hidden_only = false,
     no_ignore = false,
     no_global_ignore = false,
+    no_vcs = false,
+    parents = true,
+    require_git = true,
+    git_tracked_only = false,
+    git_status = None,
     custom_ignore_files = None,
     follow_symlinks = false,
+    follow_file_symlinks = None,
+    follow_dir_symlinks = None,
+    metadata_follow = None,
+    max_symlink_hops = None,
     same_file_system = false,
+    skip_fs_types = None,
     case_sensitive_glob = true,
-    _case_sensitive_content = true,
+    full_path = false,
+    literal_separator = true,
+    case_sensitive_exclude = None,
+    case_sensitive_content = true,
     as_path_objects = false,
+    path_style = String::from("native"),
+    separator = None,
+    strict_utf8 = false,
     yield_results = true,
+    sort = None,
     _multiline = false,
-    threads = 0
+    dotall = false,
+    whole_line = false,
+    line_terminator = None,
+    encoding = None,
+    unique_matches = false,
+    distinct_matches = false,
+    pattern_histogram = false,
+    min_match_length = None,
+    head_lines = None,
+    hunk_context = None,
+    sexp = false,
+    highlight = None,
+    archives = false,
+    quiet = false,
+    replace = None,
+    write = false,
+    backup = false,
+    search_metadata = false,
+    report_bytes = false,
+    with_stats = false,
+    batch_size = None,
+    threads = 0,
+    deterministic = false,
+    max_open_files = None
 ))]
 fn search(
     py: Python<'_>,
-    content_regex: String,
+    content_regex: Option<String>,
     paths: Vec<String>,
+    file_list: Option<Vec<String>>,
+    content_literals: Option<Vec<String>>,
     glob: Option<String>,
+    name_filter: Option<String>,
     regex: Option<String>,
-    file_type: Option<String>,
+    regex_target: String,
+    file_type: Option<StringOrList>,
+    symlink_target_type: Option<String>,
+    symlink_broken: Option<bool>,
+    content_type: Option<String>,
     extension: Option<Vec<String>>,
     exclude: Option<Vec<String>>,
+    exclude_name: Option<Vec<String>>,
     max_depth: Option<usize>,
+    shallow_dirs: Option<Vec<String>>,
+    per_dir_limit: Option<usize>,
     min_size: Option<u64>,
     max_size: Option<u64>,
+    min_lines: Option<u64>,
+    max_lines: Option<u64>,
     mtime_after: Option<f64>,
     mtime_before: Option<f64>,
+    changed_within: Option<f64>,
+    changed_before: Option<f64>,
+    newer_than_file: Option<String>,
     atime_after: Option<f64>,
     atime_before: Option<f64>,
     ctime_after: Option<f64>,
     ctime_before: Option<f64>,
+    include_inaccessible: bool,
     hidden: bool,
+    hidden_only: bool,
     no_ignore: bool,
     no_global_ignore: bool,
+    no_vcs: bool,
+    parents: bool,
+    require_git: bool,
+    git_tracked_only: bool,
+    git_status: Option<Vec<String>>,
     custom_ignore_files: Option<Vec<String>>,
     follow_symlinks: bool,
+    follow_file_symlinks: Option<bool>,
+    follow_dir_symlinks: Option<bool>,
+    metadata_follow: Option<bool>,
+    max_symlink_hops: Option<usize>,
     same_file_system: bool,
+    skip_fs_types: Option<Vec<String>>,
     case_sensitive_glob: bool,
-    _case_sensitive_content: bool,
+    full_path: bool,
+    literal_separator: bool,
+    case_sensitive_exclude: Option<bool>,
+    case_sensitive_content: bool,
     as_path_objects: bool,
+    path_style: String,
+    separator: Option<char>,
+    strict_utf8: bool,
     yield_results: bool,
+    sort: Option<String>,
     _multiline: bool,
+    dotall: bool,
+    whole_line: bool,
+    line_terminator: Option<u8>,
+    encoding: Option<String>,
+    unique_matches: bool,
+    distinct_matches: bool,
+    pattern_histogram: bool,
+    min_match_length: Option<usize>,
+    head_lines: Option<u64>,
+    hunk_context: Option<usize>,
+    sexp: bool,
+    highlight: Option<(String, String)>,
+    archives: bool,
+    quiet: bool,
+    replace: Option<String>,
+    write: bool,
+    backup: bool,
+    search_metadata: bool,
+    report_bytes: bool,
+    with_stats: bool,
+    batch_size: Option<usize>,
     threads: usize,
+    deterministic: bool,
+    max_open_files: Option<usize>,
 ) -> PyResult<PyObject> {
+    if paths.is_empty() {
+        return Err(PyValueError::new_err("at least one root path is required"));
+    }
+    
+    // Patterns the histogram counts matches for, in the order they were given
+    let histogram_patterns = pattern_histogram.then(|| match (&content_regex, &content_literals) {
+        (_, Some(literals)) => literals.clone(),
+        (Some(content_regex), None) => vec![content_regex.clone()],
+        (None, None) => Vec::new(),
+    });
+    let literal_histogram = content_literals.is_some();
+    
+    let sort_by_match_count = match sort.as_deref() {
+        None => false,
+        Some("match_count") => true,
+        Some(other) => return Err(PyValueError::new_err(format!("Invalid sort option for content search: {}. Use 'match_count'", other))),
+    };
+    if sort_by_match_count && (quiet || distinct_matches || pattern_histogram) {
+        return Err(PyValueError::new_err("sort cannot be combined with quiet, distinct_matches or pattern_histogram"));
+    }
+    
     // Build content pattern matcher with case sensitivity
-    let content_matcher = RegexMatcherBuilder::new()
-        .case_insensitive(!_case_sensitive_content)
-        .build(&content_regex)
-        .map_err(|e| PyValueError::new_err(format!("Invalid content regex: {}", e)))?;
+    let (content_matcher, content_regex) = build_content_matcher(
+        content_regex, content_literals, case_sensitive_content, dotall, whole_line, line_terminator,
+    )?;
+    
+    // Build the substitution with a byte regex using the same flags
+    if write && replace.is_none() {
+        return Err(PyValueError::new_err("write=True requires a replace string"));
+    }
+    if write && head_lines.is_some() {
+        return Err(PyValueError::new_err("head_lines cannot be combined with write"));
+    }
+    if pattern_histogram && (distinct_matches || quiet) {
+        return Err(PyValueError::new_err("pattern_histogram cannot be combined with distinct_matches or quiet"));
+    }
+    if hunk_context.is_some() && (replace.is_some() || head_lines.is_some() || distinct_matches || pattern_histogram || highlight.is_some()) {
+        return Err(PyValueError::new_err(
+            "format='hunks' cannot be combined with replace, head_lines, distinct_matches, pattern_histogram or highlight",
+        ));
+    }
+    if sexp && (quiet || distinct_matches || pattern_histogram) {
+        return Err(PyValueError::new_err("format='sexp' cannot be combined with quiet, distinct_matches or pattern_histogram"));
+    }
+    let replacer = replace
+        .map(|replacement| Replacer::new(&content_regex, replacement, case_sensitive_content, dotall, write, backup))
+        .transpose()?;
     
     // Build glob pattern matcher with literal optimization
     let pattern_matcher = if let Some(pattern) = glob {
-        Some(PatternMatcher::new(&pattern, case_sensitive_glob)
+        Some(PatternMatcher::new(&pattern, case_sensitive_glob, full_path, literal_separator)
             .map_err(|e| PyValueError::new_err(format!("Invalid glob pattern: {}", e)))?)
     } else {
         None
     };
+    // The name filter sees only the file name, whatever the glob matches against
+    let name_filter = name_filter
+        .map(|pattern| build_name_set(&[pattern], case_sensitive_glob))
+        .transpose()
+        .map_err(|e| PyValueError::new_err(format!("Invalid name_filter pattern: {}", e)))?;
+    
+    // Excludes and ignore files follow each root's filesystem case sensitivity unless overridden
+    let root_cases = Arc::new(RootCases::probe(&paths));
+    let case_sensitive_exclude = case_sensitive_exclude.or((!case_sensitive_glob).then_some(false));
+    
+    // Each kind of symlink can override the follow_symlinks default
+    let follow_file_symlinks = follow_file_symlinks.unwrap_or(follow_symlinks);
+    let follow_dir_symlinks = follow_dir_symlinks.unwrap_or(follow_symlinks);
+    
+    // Overlapping roots would search the shared part twice
+    let git_decides = git_tracked_only || git_status.is_some();
+    let max_symlink_hops = max_symlink_hops.filter(|_| follow_file_symlinks || follow_dir_symlinks);
+    let rules = WalkRules {
+        hidden: hidden || hidden_only,
+        no_ignore,
+        no_vcs,
+        no_global_ignore,
+        git_decides,
+        parents,
+        require_git,
+        cases: &root_cases,
+        follow_dir_symlinks,
+        same_file_system,
+        max_depth,
+        custom_ignore_files: custom_ignore_files.as_deref(),
+        filtered: git_decides || shallow_dirs.is_some() || skip_fs_types.is_some() || max_symlink_hops.is_some(),
+    };
+    let paths = normalize_roots(paths, &rules);
+    
+    let path_style = PathStyle::parse(&path_style, separator)?;
+    if as_path_objects && matches!(path_style, PathStyle::Separator(_)) {
+        return Err(PyValueError::new_err("separator applies to path strings and cannot be combined with as_path_objects"));
+    }
+    if batch_size == Some(0) {
+        return Err(PyValueError::new_err("batch_size must be at least 1"));
+    }
+    if max_open_files == Some(0) {
+        return Err(PyValueError::new_err("max_open_files must be at least 1"));
+    }
     
     // Build exclude pattern matcher
-    let exclude_set = if let Some(ref patterns) = exclude {
-        if !patterns.is_empty() {
-            Some(build_glob_set(patterns, case_sensitive_glob)
-                .map_err(|e| PyValueError::new_err(format!("Invalid exclude pattern: {}", e)))?)
-        } else {
-            None
-        }
-    } else {
+    let exclude = exclude.unwrap_or_default();
+    let exclude_name = exclude_name.unwrap_or_default();
+    let exclude_set = if exclude.is_empty() && exclude_name.is_empty() {
         None
+    } else {
+        Some(CaseMatchers::build(&root_cases, case_sensitive_exclude, |case_sensitive| {
+            ExcludeMatcher::new(&exclude, &exclude_name, case_sensitive)
+        })
+        .map_err(|e| PyValueError::new_err(format!("Invalid exclude pattern: {}", e)))?)
     };
     
     // Build regex matcher if provided
@@ -562,14 +3625,18 @@ fn search(
     } else {
         None
     };
+    let regex_target = parse_regex_target(&regex_target)?;
+    
+    // Fold relative change windows and the reference file into the absolute mtime bounds
+    let newer_than = newer_than_file.as_deref().map(reference_mtime).transpose()?;
+    let (mtime_after, mtime_before) = resolve_changed_bounds(
+        mtime_after, mtime_before, changed_within, changed_before, newer_than,
+    );
     
     // Parse file type filter
-    let file_type_filter = file_type.as_ref().and_then(|t| match t.as_str() {
-        "f" => Some(FileType::File),
-        "d" => Some(FileType::Dir),
-        "l" => Some(FileType::Symlink),
-        _ => None,
-    });
+    let file_type_filter = file_type.map(|types| FileTypeFilter::parse(&types.into_vec())).transpose()?;
+    let symlink_target_filter = symlink_target_type.as_deref().map(parse_symlink_target_type).transpose()?;
+    let content_type_filter = content_type.as_deref().map(parse_content_type).transpose()?;
     
     // Get optimal buffer configuration for content search
     let buffer_config = BufferConfig::for_workload(true, false, threads);
@@ -577,226 +3644,1459 @@ fn search(
     // Create channel for results with optimal capacity using global pool
     let (tx, rx) = global_init::get_channel_pool().get_channel(buffer_config.channel_capacity);
     
-    // Build the walker
-    let mut builder = WalkBuilder::new(&paths[0]);
-    
-    // Add additional paths
-    for path in &paths[1..] {
-        builder.add(path);
-    }
-    
-    // Configure walker options
-    builder
-        .hidden(!hidden)
-        .ignore(!no_ignore)  // respect .ignore files
-        .git_ignore(!no_ignore)  // respect .gitignore files
-        .git_global(!no_global_ignore)  // respect global gitignore
-        .git_exclude(!no_ignore)  // respect .git/info/exclude
-        .follow_links(follow_symlinks)  // follow symbolic links
-        .same_file_system(same_file_system)  // don't cross filesystem boundaries
-        .max_depth(max_depth)
-        .threads(if threads == 0 { num_cpus::get() } else { threads });
-    
-    // Add custom ignore files
-    if let Some(ref ignore_files) = custom_ignore_files {
-        for ignore_file in ignore_files {
-            if std::path::Path::new(ignore_file).exists() {
-                builder.add_ignore(ignore_file);
-            }
+    // Build the walkers
+    let mut builders = rules.builders(&paths);
+    for builder in &mut builders {
+        builder.threads(if threads == 0 { num_cpus::get() } else { threads });
+        
+        // A reproducible order needs the sequential walker over sorted directories
+        if deterministic {
+            builder.sort_by_file_name(|a, b| a.cmp(b));
         }
     }
     
-    // Automatically add .fdignore files if they exist and no_ignore is false
-    if !no_ignore {
-        for path in &paths {
-            let fdignore_path = std::path::Path::new(path).join(".fdignore");
-            if fdignore_path.exists() {
-                builder.add_ignore(&fdignore_path);
+    // Prune everything git does not track or report in the requested status
+    // categories, including untracked directories, anything below the direct
+    // children of a shallow directory, and links whose chain is longer than
+    // max_symlink_hops, reporting those as errors
+    let tracked = git_selected_paths(&paths, git_tracked_only, git_status.as_deref())?;
+    let shallow = shallow_dir_set(shallow_dirs, &root_cases, case_sensitive_exclude)?;
+    let fs_types = skip_fs_types.map(FsTypeFilter::new);
+    if tracked.is_some() || shallow.is_some() || fs_types.is_some() || max_symlink_hops.is_some() {
+        let hops_tx = tx.clone();
+        let filter: EntryFilter = Arc::new(move |entry: &DirEntry| {
+            if let Some(limit) = max_symlink_hops.filter(|_| entry.path_is_symlink()) {
+                if let Some(err) = symlink_hops_error(entry.path(), limit) {
+                    let _ = hops_tx.send(FindResult::Error(err));
+                    return false;
+                }
             }
+            tracked.as_ref().is_none_or(|tracked| tracked.contains(entry.path()))
+                && shallow.as_ref().is_none_or(|shallow| !below_shallow_dir(entry, shallow.for_path(entry.path())))
+                && fs_types.as_ref().is_none_or(|fs_types| !fs_types.skips(entry))
+        });
+        for builder in &mut builders {
+            let filter = Arc::clone(&filter);
+            builder.filter_entry(move |entry| filter(entry));
         }
     }
     
+    // Entries these filters cannot stat are reported unless include_inaccessible is set
+    let size_filtered = min_size.is_some() || max_size.is_some();
+    let time_filtered = [mtime_after, mtime_before, atime_after, atime_before, ctime_after, ctime_before].iter().any(Option::is_some);
+    let check_metadata = !include_inaccessible && (size_filtered || time_filtered);
+    
     // Clone necessary data for the thread
     let pattern_matcher = Arc::new(pattern_matcher);
+    let name_filter = Arc::new(name_filter);
     let exclude_set = Arc::new(exclude_set);
     let regex_matcher = Arc::new(regex_matcher);
-    let extension = Arc::new(extension);
+    let extension = Arc::new(extension.map(ExtensionFilter::parse).transpose()?.flatten());
     let min_size = Arc::new(min_size);
     let max_size = Arc::new(max_size);
+    let min_lines = Arc::new(min_lines);
+    let max_lines = Arc::new(max_lines);
     let mtime_after = Arc::new(mtime_after);
     let mtime_before = Arc::new(mtime_before);
     let atime_after = Arc::new(atime_after);
     let atime_before = Arc::new(atime_before);
     let ctime_after = Arc::new(ctime_after);
     let ctime_before = Arc::new(ctime_before);
-    let content_matcher = Arc::new(content_matcher);
+    let stats: Option<Arc<ScanStats>> = with_stats.then(Default::default);
+    let walker_stats = stats.clone();
+    let dir_limiter = per_dir_limit.map(|limit| Arc::new(DirLimiter::new(limit)));
+    let encoding = encoding
+        .map(|label| Encoding::new(&label))
+        .transpose()
+        .map_err(|e| PyValueError::new_err(format!("Invalid encoding: {}", e)))?;
+    let search_options = Arc::new(SearchOptions {
+        content_matcher,
+        multi_line: dotall,
+        line_terminator: line_terminator.unwrap_or(b'\n'),
+        encoding,
+        replacer,
+        // The histogram counts every match, repeats on a line included
+        unique_matches: unique_matches && !pattern_histogram,
+        min_match_length,
+        head_lines,
+        hunk_context,
+        highlight,
+        archives,
+        quiet,
+        search_metadata,
+        report_bytes,
+        path_style,
+        open_files: max_open_files.map(OpenFileLimiter::new),
+        quit: AtomicBool::new(false),
+    });
     
-    // Spawn walker thread
-    let walker_thread = std::thread::spawn(move || {
-        let walker = builder.build_parallel();
-        walker.run(|| {
-            let tx = tx.clone();
-            let pattern_matcher = Arc::clone(&pattern_matcher);
-            let exclude_set = Arc::clone(&exclude_set);
-            let regex_matcher = Arc::clone(&regex_matcher);
-            let extension = Arc::clone(&extension);
-            let min_size = Arc::clone(&min_size);
-            let max_size = Arc::clone(&max_size);
-            let mtime_after = Arc::clone(&mtime_after);
-            let mtime_before = Arc::clone(&mtime_before);
-            let atime_after = Arc::clone(&atime_after);
-            let atime_before = Arc::clone(&atime_before);
-            let ctime_after = Arc::clone(&ctime_after);
-            let ctime_before = Arc::clone(&ctime_before);
-            let content_matcher = Arc::clone(&content_matcher);
-            
-            Box::new(move |result| {
-                match result {
-                    Ok(entry) => {
-                        // First check if path matches our filters
-                        if should_include_entry(
-                            &entry,
-                            &pattern_matcher,
-                            &exclude_set,
-                            &regex_matcher,
-                            file_type_filter,
-                            &extension,
-                            *min_size,
-                            *max_size,
-                            *mtime_after,
-                            *mtime_before,
-                            *atime_after,
-                            *atime_before,
-                            *ctime_after,
-                            *ctime_before,
-                        ) {
-                            // Only search content in files, not directories
-                            if entry.file_type().map_or(false, |ft| ft.is_file()) {
-                                if let Err(e) = search_file_content(&tx, &entry, &content_matcher) {
-                                    let _ = tx.send(FindResult::Error(format!("Content search error: {}", e)));
+    // An explicit file list bypasses the walker and the path filters entirely
+    let walker_thread = if let Some(files) = file_list {
+        if let Some(ref stats) = walker_stats {
+            stats.estimated_total.store(files.len() as u64, Ordering::Relaxed);
+        }
+        std::thread::spawn(move || {
+            let search_one = |tx: &crossbeam_channel::Sender<FindResult>, file: &String| {
+                if search_options.quit.load(Ordering::Relaxed) {
+                    return;
+                }
+                if let Some(ref stats) = walker_stats {
+                    stats.visit(tx);
+                }
+                if let Err(e) = search_file_content(tx, Path::new(file), None, &search_options, walker_stats.as_deref()) {
+                    let _ = tx.send(FindResult::Error(format!("Content search error: {}", e)));
+                }
+            };
+            // Deterministic searches keep the order the files were listed in
+            if deterministic {
+                files.iter().for_each(|file| search_one(&tx, file));
+            } else {
+                files.par_iter().for_each_with(tx, |tx, file| search_one(tx, file));
+            }
+        })
+    } else {
+        // Spawn walker thread
+        std::thread::spawn(move || {
+            if let Some(ref stats) = walker_stats {
+                stats.estimated_total.store(estimate_walk_size(&paths), Ordering::Relaxed);
+            }
+            let make_visitor = || -> Box<dyn FnMut(std::result::Result<DirEntry, ignore::Error>) -> WalkState + Send> {
+                let tx = tx.clone();
+                let pattern_matcher = Arc::clone(&pattern_matcher);
+                let name_filter = Arc::clone(&name_filter);
+                let exclude_set = Arc::clone(&exclude_set);
+                let regex_matcher = Arc::clone(&regex_matcher);
+                let extension = Arc::clone(&extension);
+                let min_size = Arc::clone(&min_size);
+                let max_size = Arc::clone(&max_size);
+                let min_lines = Arc::clone(&min_lines);
+                let max_lines = Arc::clone(&max_lines);
+                let mtime_after = Arc::clone(&mtime_after);
+                let mtime_before = Arc::clone(&mtime_before);
+                let atime_after = Arc::clone(&atime_after);
+                let atime_before = Arc::clone(&atime_before);
+                let ctime_after = Arc::clone(&ctime_after);
+                let ctime_before = Arc::clone(&ctime_before);
+                let walker_stats = walker_stats.clone();
+                let dir_limiter = dir_limiter.clone();
+                let search_options = Arc::clone(&search_options);
+                
+                Box::new(move |result| {
+                    if search_options.quit.load(Ordering::Relaxed) {
+                        return WalkState::Quit;
+                    }
+                    match result {
+                        Ok(entry) => {
+                            if let Some(ref stats) = walker_stats {
+                                stats.visit(&tx);
+                            }
+                            // First check if path matches our filters
+                            if should_include_entry(
+                                &entry,
+                                &pattern_matcher,
+                                &name_filter,
+                                &exclude_set,
+                                &regex_matcher,
+                                regex_target,
+                                file_type_filter,
+                                symlink_target_filter,
+                                symlink_broken,
+                                content_type_filter,
+                                hidden_only,
+                                false,  // only files are searched
+                                follow_file_symlinks,
+                                metadata_follow,
+                                &extension,
+                                *min_size,
+                                *max_size,
+                                *min_lines,
+                                *max_lines,
+                                *mtime_after,
+                                *mtime_before,
+                                *atime_after,
+                                *atime_before,
+                                *ctime_after,
+                                *ctime_before,
+                            ) {
+                                if check_metadata {
+                                    if let Some(err) = unchecked_metadata_error(&entry, follow_file_symlinks, size_filtered, time_filtered) {
+                                        let _ = tx.send(FindResult::Error(err));
+                                        return WalkState::Continue;
+                                    }
+                                }
+                                if strict_utf8 && entry.path().to_str().is_none() {
+                                    let _ = tx.send(FindResult::Error(non_utf8_path_error(entry.path())));
+                                    return WalkState::Continue;
+                                }
+                                // Only search content in files, not directories
+                                let link_metadata = file_symlink_metadata(&entry, follow_file_symlinks);
+                                let is_file = match link_metadata {
+                                    Some(ref metadata) => metadata.is_file(),
+                                    None => entry.file_type().is_some_and(|ft| ft.is_file()),
+                                };
+                                if is_file
+                                    && dir_limiter.as_ref().is_none_or(|limiter| limiter.admit(entry.path()))
+                                {
+                                    // Reuse the walker's metadata rather than statting the file again
+                                    let metadata = if search_options.search_metadata {
+                                        link_metadata.or_else(|| entry.metadata().ok())
+                                    } else {
+                                        None
+                                    };
+                                    if let Err(e) = search_file_content(&tx, entry.path(), metadata.as_ref(), &search_options, walker_stats.as_deref()) {
+                                        let _ = tx.send(FindResult::Error(format!("Content search error: {}", e)));
+                                    }
                                 }
                             }
                         }
+                        Err(err) => {
+                            let _ = tx.send(FindResult::Error(err.to_string()));
+                        }
                     }
-                    Err(err) => {
-                        let _ = tx.send(FindResult::Error(err.to_string()));
+                    WalkState::Continue
+                })
+            };
+            if deterministic {
+                let mut visit = make_visitor();
+                for result in builders.iter().flat_map(WalkBuilder::build) {
+                    if visit(result) == WalkState::Quit {
+                        break;
                     }
                 }
-                WalkState::Continue
-            })
-        });
-    });
+            } else {
+                for builder in &builders {
+                    builder.build_parallel().run(&make_visitor);
+                }
+            }
+        })
+    };
     
-    if yield_results {
+    if quiet {
+        // Stop at the first match; dropping the receiver unblocks pending sends
+        let found = py.allow_threads(|| {
+            let mut found = false;
+            for result in rx.iter() {
+                if let Some(ref stats) = stats {
+                    stats.record(&result);
+                }
+                if let FindResult::Search(_) = result {
+                    found = true;
+                    break;
+                }
+            }
+            drop(rx);
+            walker_thread.join().unwrap();
+            found
+        });
+        let found: PyObject = PyBool::new(py, found).to_owned().into_any().unbind();
+        attach_stats(py, found, &stats)
+    } else if distinct_matches {
+        // Only the set of matched values is kept, not where they were found
+        let distinct = py.allow_threads(|| {
+            let mut distinct = BTreeSet::new();
+            for result in rx.iter() {
+                if let Some(ref stats) = stats {
+                    stats.record(&result);
+                }
+                if let FindResult::Search(search_result) = result {
+                    distinct.extend(search_result.matches);
+                }
+            }
+            walker_thread.join().unwrap();
+            distinct
+        });
+        let collected: PyObject = pyo3::types::PyList::new(py, distinct)?.into();
+        attach_stats(py, collected, &stats)
+    } else if let Some(patterns) = histogram_patterns {
+        // Each literal match spells the literal it came from; a regex gets every match
+        let fold = |text: &str| if case_sensitive_content { text.to_string() } else { text.to_lowercase() };
+        let slots: HashMap<String, usize> = patterns.iter().enumerate().map(|(i, p)| (fold(p), i)).collect();
+        let counts = py.allow_threads(|| {
+            let mut counts = vec![0u64; patterns.len()];
+            for result in rx.iter() {
+                if let Some(ref stats) = stats {
+                    stats.record(&result);
+                }
+                if let FindResult::Search(search_result) = result {
+                    for text in &search_result.matches {
+                        let slot = if literal_histogram { slots.get(&fold(text)).copied() } else { Some(0) };
+                        if let Some(slot) = slot {
+                            counts[slot] += 1;
+                        }
+                    }
+                }
+            }
+            walker_thread.join().unwrap();
+            counts
+        });
+        let histogram = PyDict::new(py);
+        for (pattern, count) in patterns.iter().zip(counts) {
+            histogram.set_item(pattern, count)?;
+        }
+        attach_stats(py, histogram.into(), &stats)
+    } else if yield_results && !sort_by_match_count {
         // Return iterator for streaming
         Ok(Py::new(py, VexyGlobIterator {
             receiver: Some(rx),
             as_path_objects,
+            path_style,
+            stats,
+            errors: Vec::new(),
+            fail_fast: false,
+            oversized: Vec::new(),
+            progress: None,
+            batch_size,
+            fields: ResultFields::default(),
+            sort_window: None,
+            sexp,
         })?.into())
     } else {
-        // Collect all results into a list
+        // Collect all results into a list, draining while the walker runs so a
+        // full channel can never block it
+        let mut results = Vec::new();
         py.allow_threads(|| {
+            for result in rx.iter() {
+                if let Some(ref stats) = stats {
+                    stats.record(&result);
+                }
+                if let FindResult::Search(search_result) = result {
+                    results.push(search_result);
+                }
+            }
             walker_thread.join().unwrap();
+            if sort_by_match_count {
+                sort_by_match_count_per_file(&mut results);
+            }
         });
         
-        let mut results = Vec::new();
-        while let Ok(result) = rx.recv() {
-            if let FindResult::Search(search_result) = result {
-                results.push(search_result);
+        // Convert to Python list
+        Python::with_gil(|py| {
+            let py_list = pyo3::types::PyList::empty(py);
+            for search_result in results {
+                if sexp {
+                    py_list.append(search_result.to_sexp())?;
+                } else {
+                    py_list.append(search_result.to_dict(py, as_path_objects, path_style)?)?;
+                }
             }
+            let collected: PyObject = py_list.into();
+            attach_stats(py, collected, &stats)
+        })
+    }
+}
+
+/// Order search results by how often their file matched, most first
+///
+/// Ties break by path. The sort is stable and a file's results arrive
+/// together in line order, so they stay together and in order.
+fn sort_by_match_count_per_file(results: &mut [SearchResultRust]) {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for result in results.iter() {
+        *counts.entry(result.path.clone()).or_default() += result.matches_on_line;
+    }
+    results.sort_by(|a, b| counts[&b.path].cmp(&counts[&a.path]).then_with(|| a.path.cmp(&b.path)));
+}
+
+/// Pair collected results with scan statistics when they were requested
+///
+/// Returns the results unchanged without stats, or a `(results, stats)` tuple.
+fn attach_stats(py: Python<'_>, collected: PyObject, stats: &Option<Arc<ScanStats>>) -> PyResult<PyObject> {
+    match stats {
+        Some(stats) => Ok((collected, stats.to_dict(py)?).into_pyobject(py)?.into_any().unbind()),
+        None => Ok(collected),
+    }
+}
+
+// Helper types and functions
+
+/// Option value given as a single string or a list of strings
+#[derive(FromPyObject)]
+enum StringOrList {
+    One(String),
+    List(Vec<String>),
+}
+
+impl StringOrList {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            StringOrList::One(value) => vec![value],
+            StringOrList::List(values) => values,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FileType {
+    File,
+    Dir,
+    Symlink,
+}
+
+impl FileType {
+    /// Classify a file type; other kinds of entries, like sockets, are `None`
+    fn of(file_type: std::fs::FileType) -> Option<Self> {
+        if file_type.is_file() {
+            Some(FileType::File)
+        } else if file_type.is_dir() {
+            Some(FileType::Dir)
+        } else if file_type.is_symlink() {
+            Some(FileType::Symlink)
+        } else {
+            None
+        }
+    }
+}
+
+/// Entry types kept by the `file_type` filter
+///
+/// Listed types are combined with OR, and a type prefixed with `!` is left
+/// out. With only exclusions, every other entry is kept, special files included.
+#[derive(Debug, Clone, Copy, Default)]
+struct FileTypeFilter {
+    /// Types listed without `!`, indexed by `FileType`
+    include: [bool; 3],
+    /// Types listed with `!`, indexed by `FileType`
+    exclude: [bool; 3],
+}
+
+impl FileTypeFilter {
+    /// Parse the `file_type` option, e.g. `["f", "l"]` or `["!d"]`
+    fn parse(types: &[String]) -> PyResult<Self> {
+        let mut filter = Self::default();
+        for spec in types {
+            let (excluded, name) = match spec.strip_prefix('!') {
+                Some(name) => (true, name),
+                None => (false, spec.as_str()),
+            };
+            let file_type = match name {
+                "f" => FileType::File,
+                "d" => FileType::Dir,
+                "l" => FileType::Symlink,
+                _ => return Err(PyValueError::new_err(format!(
+                    "Invalid file_type: {}. Use 'f', 'd' or 'l', optionally prefixed with '!'", spec,
+                ))),
+            };
+            if excluded {
+                filter.exclude[file_type as usize] = true;
+            } else {
+                filter.include[file_type as usize] = true;
+            }
+        }
+        Ok(filter)
+    }
+    
+    fn matches(self, file_type: Option<std::fs::FileType>) -> bool {
+        let any_included = self.include.contains(&true);
+        match file_type.map(FileType::of) {
+            Some(Some(kind)) => !self.exclude[kind as usize] && (!any_included || self.include[kind as usize]),
+            Some(None) => !any_included,
+            None => false,
+        }
+    }
+}
+
+/// Extensions kept by the `extension` option
+///
+/// Plain entries must equal the extension exactly. Entries with glob syntax,
+/// e.g. `c*` for `.c`, `.cc` and `.cpp`, match it regardless of case.
+#[derive(Debug)]
+struct ExtensionFilter {
+    exact: Vec<String>,
+    globs: Option<GlobSet>,
+}
+
+impl ExtensionFilter {
+    /// Parse the `extension` option; an empty list keeps every entry
+    fn parse(extensions: Vec<String>) -> PyResult<Option<Self>> {
+        if extensions.is_empty() {
+            return Ok(None);
+        }
+        let (globs, exact): (Vec<String>, Vec<String>) = extensions
+            .into_iter()
+            .partition(|extension| extension.contains(['*', '?', '[', '{']));
+        let globs = if globs.is_empty() {
+            None
+        } else {
+            Some(build_name_set(&globs, false)
+                .map_err(|e| PyValueError::new_err(format!("Invalid extension pattern: {}", e)))?)
+        };
+        Ok(Some(Self { exact, globs }))
+    }
+    
+    fn matches(&self, extension: &str) -> bool {
+        self.exact.iter().any(|exact| exact == extension)
+            || self.globs.as_ref().is_some_and(|globs| globs.is_match(extension))
+    }
+}
+
+/// Named glob groups for `classify`, checked in the order given
+///
+/// Each group's glob is compiled into its own matcher and matched like the
+/// `find()` pattern, so an entry belongs to the first group whose glob it
+/// would have matched as the pattern.
+struct Classifier {
+    groups: Vec<(String, PatternMatcher)>,
+}
+
+impl Classifier {
+    fn new(groups: Vec<(String, String)>, case_sensitive: bool) -> PyResult<Self> {
+        let groups = groups
+            .into_iter()
+            .map(|(name, glob)| {
+                PatternMatcher::new(&glob, case_sensitive, false, true)
+                    .map(|matcher| (name, matcher))
+                    .map_err(|e| PyValueError::new_err(format!("Invalid glob pattern for classify group '{}': {}", glob, e)))
+            })
+            .collect::<PyResult<_>>()?;
+        Ok(Self { groups })
+    }
+    
+    /// Name of the first group matching `entry`, or `None` when none does
+    fn classify(&self, entry: &DirEntry) -> Option<&str> {
+        self.groups.iter().find(|(_, matcher)| matcher.is_match(entry)).map(|(name, _)| name.as_str())
+    }
+}
+
+/// Content classification used by the `content_type` filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentType {
+    Text,
+    Binary,
+}
+
+/// Parse the `symlink_target_type` option
+fn parse_symlink_target_type(target_type: &str) -> PyResult<FileType> {
+    match target_type {
+        "f" => Ok(FileType::File),
+        "d" => Ok(FileType::Dir),
+        _ => Err(PyValueError::new_err(format!("Invalid symlink_target_type: {}. Use 'f' or 'd'", target_type))),
+    }
+}
+
+/// Parse the `content_type` option
+fn parse_content_type(content_type: &str) -> PyResult<ContentType> {
+    match content_type {
+        "text" => Ok(ContentType::Text),
+        "binary" => Ok(ContentType::Binary),
+        _ => Err(PyValueError::new_err(format!("Invalid content_type: {}. Use 'text' or 'binary'", content_type))),
+    }
+}
+
+/// Which part of an entry's path a glob pattern is matched against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchTarget {
+    /// The final path component (file or directory name)
+    Name,
+    /// The path as produced by the walker, including the search root
+    Path,
+    /// The path relative to the search root the entry was found under
+    Relative,
+}
+
+impl MatchTarget {
+    /// Choose the match target for a pattern
+    ///
+    /// With `full_path` the pattern is matched against the path relative to the
+    /// search root. Otherwise patterns without a path separator match the file
+    /// name only, and patterns containing one match the walker path.
+    fn for_pattern(pattern: &str, full_path: bool) -> Self {
+        if full_path {
+            MatchTarget::Relative
+        } else if pattern.contains('/') || pattern.contains('\\') {
+            MatchTarget::Path
+        } else {
+            MatchTarget::Name
+        }
+    }
+}
+
+/// Pattern matcher that optimizes for literal patterns
+#[derive(Debug)]
+enum PatternMatcher {
+    /// Literal pattern - direct string comparison
+    Literal { pattern: String, case_sensitive: bool, target: MatchTarget },
+    /// Glob pattern - uses GlobSet
+    Glob { glob_set: GlobSet, target: MatchTarget },
+}
+
+impl PatternMatcher {
+    /// Create a new pattern matcher using cached compilation, optimizing for literal patterns
+    ///
+    /// A leading `/` anchors the pattern to the search root, as in `.gitignore`:
+    /// `/src/*.rs` only matches files directly under the root's `src` directory.
+    ///
+    /// With `literal_separator`, `*`, `?` and `[...]` never match `/` and only
+    /// `**` crosses directories, so `src/*.rs` matches `src/main.rs` in any
+    /// directory but not `src/a/b.rs`. Without it, path patterns are matched
+    /// against the whole walker path and `*` may span directories.
+    fn new(pattern: &str, case_sensitive: bool, full_path: bool, literal_separator: bool) -> Result<Self> {
+        let (pattern, anchored) = match pattern.strip_prefix('/') {
+            Some(rest) => (rest, true),
+            None => (pattern, false),
+        };
+        // Regex syntax often parses as a valid glob that silently matches nothing
+        if let Some(hint) = pattern_cache::regex_syntax_hint(pattern) {
+            anyhow::bail!(
+                "'{}' looks like a regular expression ({}); pass it as regex= instead, \
+                 or wrap the character in brackets (e.g. '[$]') to match it literally",
+                pattern,
+                hint
+            );
+        }
+        let target = MatchTarget::for_pattern(pattern, full_path || anchored);
+        if pattern_cache::is_literal_pattern(pattern) {
+            Ok(PatternMatcher::Literal { 
+                pattern: pattern.to_string(), 
+                case_sensitive,
+                target,
+            })
+        } else if target == MatchTarget::Relative || (literal_separator && target == MatchTarget::Path) {
+            // Relative patterns are used verbatim; path patterns get a `**/`
+            // prefix so they still match below the root once `*` stops at `/`
+            let pattern = match target {
+                MatchTarget::Path if !pattern.starts_with("**/") => format!("**/{}", pattern),
+                _ => pattern.to_string(),
+            };
+            let glob = globset::GlobBuilder::new(&pattern_cache::expand_posix_classes(&pattern)?)
+                .case_insensitive(!case_sensitive)
+                .literal_separator(literal_separator)
+                .build()?;
+            let mut builder = GlobSetBuilder::new();
+            builder.add(glob);
+            Ok(PatternMatcher::Glob { glob_set: builder.build()?, target })
+        } else {
+            // Use cached pattern compilation for performance. The cached glob is
+            // prefixed with `**/`, which also matches a bare file name.
+            let cached_entry = pattern_cache::PATTERN_CACHE.get_or_compile(pattern, case_sensitive)?;
+            Ok(PatternMatcher::Glob { glob_set: (*cached_entry.glob_set).clone(), target })
+        }
+    }
+    
+    /// Check if a directory entry matches the pattern
+    ///
+    /// Paths are compared in forward-slash form, so `src/*.rs` matches the same
+    /// files on Windows as on Unix.
+    fn is_match(&self, entry: &DirEntry) -> bool {
+        let path = entry.path();
+        match self {
+            PatternMatcher::Literal { pattern, case_sensitive, target } => {
+                match target {
+                    MatchTarget::Path => {
+                        let path = pattern_cache::slash_path(path);
+                        FastPatternMatch::path_ends_with(&path.to_string_lossy(), pattern, *case_sensitive)
+                    }
+                    MatchTarget::Relative => {
+                        let relative = relative_path(entry);
+                        let relative = pattern_cache::slash_path(&relative);
+                        FastPatternMatch::filename_equals(&relative.to_string_lossy(), pattern, *case_sensitive)
+                    }
+                    MatchTarget::Name => path.file_name().is_some_and(|filename| {
+                        FastPatternMatch::filename_equals(&filename.to_string_lossy(), pattern, *case_sensitive)
+                    }),
+                }
+            }
+            PatternMatcher::Glob { glob_set, target } => match target {
+                MatchTarget::Path => glob_set.is_match(pattern_cache::slash_path(path)),
+                MatchTarget::Relative => glob_set.is_match(pattern_cache::slash_path(&relative_path(entry))),
+                MatchTarget::Name => path.file_name().is_some_and(|name| glob_set.is_match(name)),
+            },
+        }
+    }
+}
+
+/// Parse the `regex_target` option into the part of the path the regex sees
+fn parse_regex_target(target: &str) -> PyResult<MatchTarget> {
+    match target {
+        "path" => Ok(MatchTarget::Path),
+        "name" => Ok(MatchTarget::Name),
+        _ => Err(PyValueError::new_err(format!("Invalid regex_target: {}. Use 'path' or 'name'", target))),
+    }
+}
+
+/// Combine absolute mtime bounds with windows relative to the current time
+///
+/// `changed_within` keeps entries modified in the last N seconds and
+/// `changed_before` keeps entries modified more than N seconds ago. When both an
+/// absolute and a relative bound are given, the stricter one wins.
+///
+/// `newer_than` is a reference mtime entries must be strictly newer than.
+fn resolve_changed_bounds(
+    mtime_after: Option<f64>,
+    mtime_before: Option<f64>,
+    changed_within: Option<f64>,
+    changed_before: Option<f64>,
+    newer_than: Option<f64>,
+) -> (Option<f64>, Option<f64>) {
+    // The mtime_after bound is inclusive, so start just past the reference time
+    let mtime_after = match (mtime_after, newer_than.map(f64::next_up)) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+    if changed_within.is_none() && changed_before.is_none() {
+        return (mtime_after, mtime_before);
+    }
+    
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0);
+    
+    let after = match (mtime_after, changed_within.map(|secs| now - secs)) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+    let before = match (mtime_before, changed_before.map(|secs| now - secs)) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    };
+    (after, before)
+}
+
+/// Modification time of the `newer_than_file` reference, as a Unix timestamp
+fn reference_mtime(path: &str) -> PyResult<f64> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map(|modified| modified.duration_since(SystemTime::UNIX_EPOCH).map_or(0.0, |d| d.as_secs_f64()))
+        .map_err(|e| PyValueError::new_err(format!("Cannot read newer_than_file {}: {}", path, e)))
+}
+
+/// Rough number of entries a walk of `roots` visits, for progress updates
+///
+/// Counts each root, its children and the children of its top-level
+/// directories. That is cheap but blind to anything deeper, so deep trees are
+/// underestimated.
+fn estimate_walk_size(roots: &[String]) -> u64 {
+    roots.iter().map(|root| {
+        let Ok(children) = std::fs::read_dir(root) else {
+            return 1;
+        };
+        let mut count = 1;
+        for child in children.flatten() {
+            count += 1;
+            if child.file_type().is_ok_and(|t| t.is_dir()) {
+                count += std::fs::read_dir(child.path()).map_or(0, |grandchildren| grandchildren.count() as u64);
+            }
+        }
+        count
+    }).sum()
+}
+
+/// Make `path` absolute against the current directory without touching the filesystem
+fn absolute_path(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Walker settings that decide which entries a walk enters
+///
+/// Shared by the walk itself and by [`normalize_roots`], whose probe walks
+/// must hide exactly what the real walk hides.
+struct WalkRules<'a> {
+    hidden: bool,
+    no_ignore: bool,
+    no_vcs: bool,
+    no_global_ignore: bool,
+    /// Git picks the files, so its ignore rules are moot
+    git_decides: bool,
+    parents: bool,
+    require_git: bool,
+    /// Case sensitivity of each root's filesystem, for matching ignore files
+    cases: &'a RootCases,
+    follow_dir_symlinks: bool,
+    same_file_system: bool,
+    max_depth: Option<usize>,
+    custom_ignore_files: Option<&'a [String]>,
+    /// An entry filter prunes directories (git selection, shallow dirs,
+    /// filesystem types or symlink hops)
+    filtered: bool,
+}
+
+impl WalkRules<'_> {
+    /// Walkers over `roots`, one per run of consecutive roots whose filesystems
+    /// agree on case, since a walker matches all ignore files one way
+    fn builders(&self, roots: &[String]) -> Vec<WalkBuilder> {
+        let mut builders: Vec<(bool, WalkBuilder)> = Vec::new();
+        for root in roots {
+            let case_sensitive = self.cases.for_path(Path::new(root));
+            match builders.last_mut() {
+                Some((last, builder)) if *last == case_sensitive => {
+                    builder.add(root);
+                }
+                _ => {
+                    let mut builder = WalkBuilder::new(root);
+                    self.apply(&mut builder, roots, case_sensitive);
+                    builders.push((case_sensitive, builder));
+                }
+            }
+        }
+        builders.into_iter().map(|(_, builder)| builder).collect()
+    }
+    
+    /// Configure `builder` to walk `roots` under these rules, matching ignore
+    /// files with or without case
+    fn apply(&self, builder: &mut WalkBuilder, roots: &[String], case_sensitive: bool) {
+        builder
+            .hidden(!self.hidden)
+            .ignore(!self.no_ignore)  // respect .ignore files
+            .git_ignore(!self.no_ignore && !self.no_vcs && !self.git_decides)  // respect .gitignore files, unless the index decides
+            .git_global(!self.no_global_ignore && !self.no_vcs)  // respect global gitignore
+            .parents(self.parents)  // read ignore files in ancestor directories
+            .require_git(self.require_git)  // only apply .gitignore inside a git repository
+            .git_exclude(!self.no_ignore && !self.no_vcs && !self.git_decides)  // respect .git/info/exclude
+            .ignore_case_insensitive(!case_sensitive)  // match ignore files like the filesystem does
+            .follow_links(self.follow_dir_symlinks)  // descend into symlinked directories
+            .same_file_system(self.same_file_system)  // don't cross filesystem boundaries
+            .max_depth(self.max_depth);
+        
+        // Add custom ignore files
+        for ignore_file in self.custom_ignore_files.unwrap_or_default() {
+            if Path::new(ignore_file).exists() {
+                builder.add_ignore(ignore_file);
+            }
+        }
+        
+        // Automatically add .fdignore files if they exist and no_ignore is false
+        if !self.no_ignore {
+            for root in roots {
+                let fdignore_path = Path::new(root).join(".fdignore");
+                if fdignore_path.exists() {
+                    builder.add_ignore(&fdignore_path);
+                }
+            }
+        }
+    }
+}
+
+/// Drop roots that repeat another root, or that another root's walk covers
+///
+/// Roots with the same canonical path are walked once, keeping the first
+/// spelling. A root inside another root is only dropped when the outer walk
+/// is sure to enter it and everything below it: no depth limit or entry
+/// filter applies, the root brings no `.fdignore` of its own, and a probe
+/// walk under the same hidden, ignore and symlink rules reaches it as a
+/// directory. Roots that cannot be resolved, e.g. missing ones, are kept for
+/// the walker to report.
+fn normalize_roots(paths: Vec<String>, rules: &WalkRules) -> Vec<String> {
+    let canonical: Vec<Option<PathBuf>> = paths.iter().map(|path| std::fs::canonicalize(path).ok()).collect();
+    let nested_droppable = rules.max_depth.is_none() && !rules.filtered;
+    let covered = |i: usize| {
+        let Some(ref path) = canonical[i] else {
+            return false;
+        };
+        canonical.iter().enumerate().any(|(j, other)| {
+            let Some(other) = other.as_ref().filter(|_| j != i) else {
+                return false;
+            };
+            // Of two equal roots the first is kept
+            if path == other {
+                return j < i;
+            }
+            nested_droppable
+                && path.starts_with(other)
+                && (rules.no_ignore || !Path::new(&paths[i]).join(".fdignore").exists())
+                && walk_reaches(&paths[j], &paths[i], rules, &paths)
+        })
+    };
+    let kept: Vec<bool> = (0..paths.len()).map(|i| !covered(i)).collect();
+    paths.into_iter().zip(kept).filter(|&(_, kept)| kept).map(|(path, _)| path).collect()
+}
+
+/// Whether walking `outer` enters the directory `inner` under `rules`
+///
+/// `inner` must be spelled as a plain descent from `outer` (no `..`), and
+/// every directory on the way must pass the walk's hidden, ignore and
+/// symlink rules; only those directories are visited.
+fn walk_reaches(outer: &str, inner: &str, rules: &WalkRules, roots: &[String]) -> bool {
+    let Ok(rel) = absolute_path(Path::new(inner)).strip_prefix(absolute_path(Path::new(outer))).map(Path::to_path_buf) else {
+        return false;
+    };
+    if !rel.components().all(|c| matches!(c, std::path::Component::Normal(_))) {
+        return false;
+    }
+    let target = Path::new(outer).join(&rel);
+    let mut probe = WalkBuilder::new(outer);
+    rules.apply(&mut probe, roots, rules.cases.for_path(Path::new(outer)));
+    probe.max_depth(Some(rel.components().count()));
+    let ancestors = target.clone();
+    probe.filter_entry(move |entry| ancestors.starts_with(entry.path()));
+    probe
+        .build()
+        .flatten()
+        .any(|entry| entry.path() == target && entry.file_type().is_some_and(|ft| ft.is_dir()))
+}
+
+/// Render `path` relative to `base`, falling back to the absolute path
+///
+/// Paths outside `base` (e.g. from another root in a multi-root search) are
+/// reported absolute rather than with `..` components. `base` itself is `.`.
+fn relative_display(path: &Path, base: &Path) -> String {
+    relative_display_path(path, base).to_string_lossy().into_owned()
+}
+
+/// `path` relative to `base` as a path, as [`relative_display`] spells it
+fn relative_display_path(path: &Path, base: &Path) -> PathBuf {
+    let path = absolute_path(path);
+    match path.strip_prefix(base) {
+        Ok(rel) if rel.as_os_str().is_empty() => PathBuf::from("."),
+        Ok(rel) => rel.to_path_buf(),
+        Err(_) => path,
+    }
+}
+
+/// Path of an entry relative to the search root it was found under
+///
+/// The walker reports paths prefixed with their root, so the relative part is
+/// the last `depth` components. The root itself yields an empty path.
+fn relative_path(entry: &DirEntry) -> PathBuf {
+    let components: Vec<_> = entry.path().components().collect();
+    let start = components.len().saturating_sub(entry.depth());
+    components[start..].iter().collect()
+}
+
+/// Paths the walk is limited to by `git_tracked_only` and `git_status`
+///
+/// With both set, only paths in both sets are kept. `None` when neither is set.
+fn git_selected_paths(roots: &[String], tracked_only: bool, status: Option<&[String]>) -> PyResult<Option<HashSet<PathBuf>>> {
+    let tracked = tracked_only
+        .then(|| git_listed_paths(roots, &[&["ls-files", "-z"]], "git_tracked_only"))
+        .transpose()
+        .map_err(PyValueError::new_err)?;
+    let Some(categories) = status else {
+        return Ok(tracked);
+    };
+    let commands = categories
+        .iter()
+        .map(|category| git_status_command(category))
+        .collect::<PyResult<Vec<_>>>()?;
+    let changed = git_listed_paths(roots, &commands, "git_status").map_err(PyValueError::new_err)?;
+    Ok(Some(match tracked {
+        Some(tracked) => tracked.intersection(&changed).cloned().collect(),
+        None => changed,
+    }))
+}
+
+/// Git command listing the files in a `git_status` category below the current directory
+fn git_status_command(category: &str) -> PyResult<&'static [&'static str]> {
+    match category {
+        // Work tree differs from the index
+        "modified" => Ok(&["ls-files", "-z", "--modified"]),
+        // Index differs from HEAD
+        "staged" => Ok(&["diff", "--cached", "--name-only", "--relative", "-z"]),
+        // Not in the index and not ignored
+        "untracked" => Ok(&["ls-files", "-z", "--others", "--exclude-standard"]),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid git_status: {}. Use 'modified', 'staged' or 'untracked'",
+            category
+        ))),
+    }
+}
+
+/// Paths listed by git under each root, plus the directories containing them
+///
+/// Runs every command in every root; each lists NUL-separated paths below it
+/// relative to that root, so joined paths match what the walker yields.
+/// Fails when git is not installed or a root is not inside a git repository,
+/// naming `option` in the message.
+fn git_listed_paths(roots: &[String], commands: &[&[&str]], option: &str) -> std::result::Result<HashSet<PathBuf>, String> {
+    let mut listed = HashSet::new();
+    for root in roots {
+        listed.insert(PathBuf::from(root));
+        for args in commands {
+            let output = std::process::Command::new("git")
+                .args(*args)
+                .current_dir(root)
+                .output()
+                .map_err(|e| format!("{} requires git: {}", option, e))?;
+            if !output.status.success() {
+                return Err(format!(
+                    "{}: {} is not inside a git repository ({})",
+                    option,
+                    root,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ));
+            }
+            let root = Path::new(root);
+            for name in output.stdout.split(|&b| b == 0).filter(|name| !name.is_empty()) {
+                let path = root.join(String::from_utf8_lossy(name).as_ref());
+                // Directories are kept so the walker can descend to listed files
+                for ancestor in path.ancestors() {
+                    if ancestor == root || !listed.insert(ancestor.to_path_buf()) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    Ok(listed)
+}
+
+/// Case sensitivity of the filesystem under each search root
+///
+/// Roots on one device share a probe. A path found by the walk belongs to the
+/// deepest root it lies under, spelled as the walker spells it.
+struct RootCases {
+    roots: Vec<(PathBuf, bool)>,
+}
+
+impl RootCases {
+    fn probe(paths: &[String]) -> Self {
+        let mut by_device: HashMap<u64, bool> = HashMap::new();
+        let roots = paths
+            .iter()
+            .map(|root| {
+                let root = Path::new(root);
+                let device = std::fs::metadata(root).ok().as_ref().and_then(file_identity).map(|(device, _)| device);
+                let case_sensitive = match device {
+                    Some(device) => *by_device.entry(device).or_insert_with(|| filesystem_is_case_sensitive(root)),
+                    None => filesystem_is_case_sensitive(root),
+                };
+                (root.to_path_buf(), case_sensitive)
+            })
+            .collect();
+        Self { roots }
+    }
+    
+    /// Whether any root's filesystem has this case sensitivity
+    fn any(&self, case_sensitive: bool) -> bool {
+        self.roots.iter().any(|&(_, sensitive)| sensitive == case_sensitive)
+    }
+    
+    /// Case sensitivity for a path under the roots; other paths follow the first root
+    fn for_path(&self, path: &Path) -> bool {
+        self.roots
+            .iter()
+            .filter(|(root, _)| path.starts_with(root))
+            .max_by_key(|(root, _)| root.components().count())
+            .or(self.roots.first())
+            .is_none_or(|&(_, sensitive)| sensitive)
+    }
+}
+
+/// A matcher built for each case sensitivity the search roots need
+///
+/// Unless the caller fixes the case sensitivity, each root follows its own
+/// filesystem, so roots on different filesystems need both variants.
+struct CaseMatchers<T> {
+    sensitive: Option<T>,
+    insensitive: Option<T>,
+    cases: Arc<RootCases>,
+}
+
+impl<T> CaseMatchers<T> {
+    fn build<E>(cases: &Arc<RootCases>, case_sensitive: Option<bool>, build: impl Fn(bool) -> Result<T, E>) -> Result<Self, E> {
+        let needed = |sensitive: bool| case_sensitive.map_or_else(|| cases.any(sensitive), |fixed| fixed == sensitive);
+        Ok(Self {
+            sensitive: needed(true).then(|| build(true)).transpose()?,
+            insensitive: needed(false).then(|| build(false)).transpose()?,
+            cases: Arc::clone(cases),
+        })
+    }
+    
+    /// The variant matching `path` the way its root's filesystem does
+    fn for_path(&self, path: &Path) -> &T {
+        match (&self.sensitive, &self.insensitive) {
+            (Some(sensitive), Some(insensitive)) => if self.cases.for_path(path) { sensitive } else { insensitive },
+            (Some(only), None) | (None, Some(only)) => only,
+            (None, None) => unreachable!("every root needs one of the variants"),
+        }
+    }
+}
+
+/// Detect whether the filesystem holding `root` distinguishes case in names
+///
+/// Probes the nearest path component (the root or one of its ancestors) whose
+/// name contains letters by looking up the same path with that component's
+/// case swapped. If the swapped name is missing, the filesystem is case
+/// sensitive; if it names the same file (device and inode), the filesystem
+/// folds case. A case-sensitive filesystem can hold both spellings as two
+/// files, which is told apart on Unix; elsewhere no file identity is
+/// available, so that case reads as case-insensitive. Only ASCII letters are
+/// swapped. When no component has letters, or the root cannot be resolved,
+/// falls back to the platform default (case-insensitive on macOS and Windows).
+fn filesystem_is_case_sensitive(root: &Path) -> bool {
+    let platform_default = !cfg!(any(target_os = "macos", target_os = "windows"));
+    let Ok(root) = root.canonicalize() else {
+        return platform_default;
+    };
+    
+    for candidate in root.ancestors() {
+        let Some(name) = candidate.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let swapped: String = name
+            .chars()
+            .map(|c| if c.is_lowercase() { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() })
+            .collect();
+        if swapped == name {
+            continue;
+        }
+        let Ok(swapped) = std::fs::metadata(candidate.with_file_name(swapped)) else {
+            return true;
+        };
+        let original = std::fs::metadata(candidate).ok();
+        return match (original.as_ref().and_then(file_identity), file_identity(&swapped)) {
+            (Some(original), Some(swapped)) => original != swapped,
+            _ => false,
+        };
+    }
+    
+    platform_default
+}
+
+/// Walk filter deciding which entries are yielded and descended into
+type EntryFilter = Arc<dyn Fn(&DirEntry) -> bool + Send + Sync>;
+
+/// Build a glob set matched against file names only
+fn build_name_set(patterns: &[String], case_sensitive: bool) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(globset::GlobBuilder::new(&pattern_cache::expand_posix_classes(pattern)?)
+            .case_insensitive(!case_sensitive)
+            .literal_separator(true)
+            .build()?);
+    }
+    Ok(builder.build()?)
+}
+
+/// Compile the `shallow_dirs` name patterns
+fn shallow_dir_set(
+    patterns: Option<Vec<String>>,
+    cases: &Arc<RootCases>,
+    case_sensitive: Option<bool>,
+) -> PyResult<Option<CaseMatchers<GlobSet>>> {
+    match patterns {
+        Some(patterns) if !patterns.is_empty() => CaseMatchers::build(cases, case_sensitive, |case_sensitive| build_name_set(&patterns, case_sensitive))
+            .map(Some)
+            .map_err(|e| PyValueError::new_err(format!("Invalid shallow_dirs pattern: {}", e))),
+        _ => Ok(None),
+    }
+}
+
+/// Whether `entry` lies below a direct child of a directory named by `shallow`
+///
+/// Shallow directories are listed one level deep: their children are walked,
+/// but nothing inside those children is.
+fn below_shallow_dir(entry: &DirEntry, shallow: &GlobSet) -> bool {
+    entry.depth() >= 2
+        && entry.path().parent().and_then(Path::parent).and_then(Path::file_name).is_some_and(|name| shallow.is_match(name))
+}
+
+/// Filesystem types whose directories `skip_fs_types` keeps the walk out of
+///
+/// Each directory is statted for its device, and a device's type is looked up
+/// with `statfs` the first time the walk reaches it, so the extra cost is one
+/// `stat` per directory and one `statfs` per mount.
+struct FsTypeFilter {
+    /// Lowercase type names, as `filesystem_type` reports them
+    skip: HashSet<String>,
+    /// Whether each device seen so far is skipped
+    devices: Mutex<HashMap<u64, bool>>,
+}
+
+impl FsTypeFilter {
+    fn new(types: Vec<String>) -> Self {
+        Self {
+            skip: types.iter().map(|name| name.to_lowercase()).collect(),
+            devices: Mutex::new(HashMap::new()),
         }
-        
-        // Convert to Python list
-        Python::with_gil(|py| {
-            let py_list = pyo3::types::PyList::empty(py);
-            for search_result in results {
-                let result_dict = PyDict::new(py);
-                
-                let path_obj: PyObject = if as_path_objects {
-                    let pathlib = py.import("pathlib")?;
-                    let path_class = pathlib.getattr("Path")?;
-                    path_class.call1((&search_result.path,))?.into()
+    }
+
+    /// Whether `entry` is a directory on a skipped filesystem type
+    fn skips(&self, entry: &DirEntry) -> bool {
+        if !entry.file_type().is_some_and(|ft| ft.is_dir()) {
+            return false;
+        }
+        let Some(device) = entry_device(entry) else {
+            return false;
+        };
+        if let Some(&skip) = self.devices.lock().unwrap().get(&device) {
+            return skip;
+        }
+        let skip = filesystem_type(entry.path()).is_some_and(|name| self.skip.contains(&name));
+        self.devices.lock().unwrap().insert(device, skip);
+        skip
+    }
+}
+
+/// Append the raw bytes of `path` in `style` to `out`
+///
+/// Unix names are bytes, so a name that is not valid UTF-8 is passed on
+/// unchanged rather than with replacement characters.
+#[cfg(unix)]
+fn push_path_bytes(out: &mut Vec<u8>, path: &Path, style: PathStyle) {
+    use std::os::unix::ffi::OsStrExt;
+    let bytes = path.as_os_str().as_bytes();
+    match style {
+        PathStyle::Separator(separator) if separator != '/' => {
+            let mut buf = [0; 4];
+            let separator = separator.encode_utf8(&mut buf).as_bytes();
+            for &byte in bytes {
+                if byte == b'/' {
+                    out.extend_from_slice(separator);
                 } else {
-                    search_result.path.clone().into_pyobject(py)?.into()
-                };
-                
-                result_dict.set_item("path", path_obj)?;
-                result_dict.set_item("line_number", search_result.line_number)?;
-                result_dict.set_item("line_text", search_result.line_text)?;
-                result_dict.set_item("matches", search_result.matches)?;
-                
-                py_list.append(result_dict)?;
+                    out.push(byte);
+                }
             }
-            Ok(py_list.into())
-        })
+        }
+        _ => out.extend_from_slice(bytes),
     }
 }
 
-// Helper types and functions
+#[cfg(not(unix))]
+fn push_path_bytes(out: &mut Vec<u8>, path: &Path, style: PathStyle) {
+    out.extend_from_slice(style.display(path).as_bytes());
+}
 
-#[derive(Debug, Clone, Copy)]
-enum FileType {
-    File,
-    Dir,
-    Symlink,
+/// Device holding `entry`
+#[cfg(unix)]
+fn entry_device(entry: &DirEntry) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    entry.metadata().ok().map(|metadata| metadata.dev())
 }
 
-/// Pattern matcher that optimizes for literal patterns
-#[derive(Debug)]
-enum PatternMatcher {
-    /// Literal pattern - direct string comparison
-    Literal { pattern: String, case_sensitive: bool },
-    /// Glob pattern - uses GlobSet
-    Glob(GlobSet),
+#[cfg(not(unix))]
+fn entry_device(_entry: &DirEntry) -> Option<u64> {
+    None
 }
 
-impl PatternMatcher {
-    /// Create a new pattern matcher using cached compilation, optimizing for literal patterns
-    fn new(pattern: &str, case_sensitive: bool) -> Result<Self> {
-        if pattern_cache::is_literal_pattern(pattern) {
-            Ok(PatternMatcher::Literal { 
-                pattern: pattern.to_string(), 
-                case_sensitive 
-            })
+/// Type of the filesystem holding `path`, e.g. `ext4` or `nfs`
+///
+/// Linux only reports a magic number, so types missing from the table below
+/// are unknown and never skipped; ext2 and ext3 share the `ext4` number.
+#[cfg(target_os = "linux")]
+fn filesystem_type(path: &Path) -> Option<String> {
+    use std::os::unix::ffi::OsStrExt;
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    // SAFETY: `path` is NUL-terminated and `stat` is a valid out-pointer
+    let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    let name = match stat.f_type as u32 {
+        0x6969 => "nfs",
+        0xFF53_4D42 => "cifs",
+        0xFE53_4D42 => "smb2",
+        0x517B => "smb",
+        0x6573_5546 => "fuse",
+        0x5346_414F => "afs",
+        0x00C3_6400 => "ceph",
+        0x0102_1997 => "9p",
+        0xEF53 => "ext4",
+        0x5846_5342 => "xfs",
+        0x9123_683E => "btrfs",
+        0x2FC1_2FC1 => "zfs",
+        0x0102_1994 => "tmpfs",
+        0x794C_7630 => "overlay",
+        0x7371_7368 => "squashfs",
+        0x4D44 => "vfat",
+        0x2011_BAB0 => "exfat",
+        0x5346_544E => "ntfs",
+        0x9660 => "iso9660",
+        0x9FA0 => "proc",
+        0x6265_6572 => "sysfs",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd", target_os = "openbsd", target_os = "dragonfly"))]
+fn filesystem_type(path: &Path) -> Option<String> {
+    use std::os::unix::ffi::OsStrExt;
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    // SAFETY: `path` is NUL-terminated and `stat` is a valid out-pointer
+    let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    // SAFETY: the kernel fills `f_fstypename` with a NUL-terminated name
+    let name = unsafe { std::ffi::CStr::from_ptr(stat.f_fstypename.as_ptr()) };
+    Some(name.to_string_lossy().to_lowercase())
+}
+
+/// Filesystem types are not looked up on this platform
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "ios", target_os = "freebsd", target_os = "openbsd", target_os = "dragonfly")))]
+fn filesystem_type(_path: &Path) -> Option<String> {
+    None
+}
+
+/// Ordered exclude patterns where a `!` prefix re-includes, as in `.gitignore`
+///
+/// The last pattern matching a path decides: `["build/**", "!build/keep.txt"]`
+/// excludes everything under `build` except `keep.txt`. A leading `\!` matches
+/// a literal `!`.
+struct ExcludeMatcher {
+    glob_set: GlobSet,
+    /// Whether the pattern at each GlobSet index is a re-include
+    negated: Vec<bool>,
+    has_negations: bool,
+    /// Patterns matched against the file name only (`exclude_name`)
+    name_set: Option<GlobSet>,
+    /// The patterns as given, for reporting which one matched
+    patterns: Vec<String>,
+    name_patterns: Vec<String>,
+}
+
+impl ExcludeMatcher {
+    fn new(patterns: &[String], name_patterns: &[String], case_sensitive: bool) -> Result<Self> {
+        let mut globs = Vec::with_capacity(patterns.len());
+        let mut negated = Vec::with_capacity(patterns.len());
+        for pattern in patterns {
+            if let Some(rest) = pattern.strip_prefix('!') {
+                globs.push(rest.to_string());
+                negated.push(true);
+            } else {
+                globs.push(pattern.strip_prefix('\\').filter(|p| p.starts_with('!')).unwrap_or(pattern).to_string());
+                negated.push(false);
+            }
+        }
+        let has_negations = negated.contains(&true);
+        let name_set = if name_patterns.is_empty() {
+            None
         } else {
-            // Use cached pattern compilation for performance
-            let cached_entry = pattern_cache::PATTERN_CACHE.get_or_compile(pattern, case_sensitive)?;
-            Ok(PatternMatcher::Glob((*cached_entry.glob_set).clone()))
+            Some(build_name_set(name_patterns, case_sensitive)?)
+        };
+        Ok(Self {
+            glob_set: build_glob_set(&globs, case_sensitive)?,
+            negated,
+            has_negations,
+            name_set,
+            patterns: patterns.to_vec(),
+            name_patterns: name_patterns.to_vec(),
+        })
+    }
+    
+    /// Check if a path is excluded
+    ///
+    /// A name exclude always wins; `!` re-includes only apply to path excludes.
+    /// Path excludes see the forward-slash form of the path.
+    fn is_excluded(&self, path: &Path) -> bool {
+        if let Some(ref name_set) = self.name_set {
+            if path.file_name().is_some_and(|name| name_set.is_match(name)) {
+                return true;
+            }
+        }
+        let path = pattern_cache::slash_path(path);
+        // Plain excludes are the common case: any match excludes
+        if !self.has_negations {
+            return self.glob_set.is_match(path);
         }
+        self.glob_set
+            .matches(path)
+            .into_iter()
+            .max()
+            .is_some_and(|last| !self.negated[last])
     }
     
-    /// Check if a path matches the pattern
-    fn is_match(&self, path: &Path) -> bool {
-        match self {
-            PatternMatcher::Literal { pattern, case_sensitive } => {
-                // For literal patterns, we need to check if the pattern contains a path separator
-                // If it does, match against the full path; otherwise match against the filename
-                if pattern.contains('/') || pattern.contains('\\') {
-                    let path_str = path.to_string_lossy();
-                    if *case_sensitive {
-                        path_str.ends_with(pattern)
-                    } else {
-                        path_str.to_lowercase().ends_with(&pattern.to_lowercase())
-                    }
-                } else {
-                    // Match against filename only
-                    if let Some(filename) = path.file_name() {
-                        let filename_str = filename.to_string_lossy();
-                        if *case_sensitive {
-                            filename_str.as_ref() == pattern
-                        } else {
-                            filename_str.to_lowercase() == pattern.to_lowercase()
-                        }
-                    } else {
-                        false
-                    }
+    /// Which option and pattern exclude a path, following `is_excluded`
+    fn excluded_by(&self, path: &Path) -> Option<(&'static str, String)> {
+        if let Some(ref name_set) = self.name_set {
+            let name_match = path.file_name().and_then(|name| name_set.matches(name).into_iter().next());
+            if let Some(index) = name_match {
+                return Some(("exclude_name", self.name_patterns[index].clone()));
+            }
+        }
+        let last = self.glob_set.matches(pattern_cache::slash_path(path)).into_iter().max()?;
+        (!self.negated[last]).then(|| ("exclude", self.patterns[last].clone()))
+    }
+}
+
+/// Finds the ignore file rule that keeps a path out of a walk
+///
+/// Mirrors the walker's precedence: every `.ignore` file beats every
+/// `.gitignore`, which beats `.git/info/exclude`, with deeper directories
+/// winning within each kind; explicit ignore files come last. `.gitignore`
+/// rules only apply inside a git repository unless `require_git` is off, and
+/// not at all with `no_vcs`. The global gitignore is not consulted. Loaded
+/// files are cached per directory.
+struct IgnoreExplainer {
+    root: PathBuf,
+    parents: bool,
+    require_git: bool,
+    /// Whether `.gitignore` and `.git/info/exclude` apply at all
+    use_vcs: bool,
+    case_insensitive: bool,
+    explicit: Vec<Gitignore>,
+    /// `.ignore`, `.gitignore` and `.git/info/exclude` matchers for each directory
+    dirs: HashMap<PathBuf, [Option<Gitignore>; 3]>,
+}
+
+impl IgnoreExplainer {
+    fn new(root: &Path, explicit: &[PathBuf], parents: bool, require_git: bool, use_vcs: bool, case_insensitive: bool) -> Self {
+        let explicit = explicit
+            .iter()
+            .filter_map(|file| load_ignore_file(&absolute_path(file), case_insensitive))
+            .collect();
+        Self {
+            root: absolute_path(root),
+            parents,
+            require_git,
+            use_vcs,
+            case_insensitive,
+            explicit,
+            dirs: HashMap::new(),
+        }
+    }
+    
+    /// The ignore file and line excluding `path`, or `None` when no rule does
+    /// or the last matching rule re-includes it with `!`
+    fn explain(&mut self, path: &Path, is_dir: bool) -> Option<(String, String)> {
+        let path = absolute_path(path);
+        let dirs: Vec<PathBuf> = path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| self.parents || dir.starts_with(&self.root))
+            .map(Path::to_path_buf)
+            .collect();
+        let in_repo = self.use_vcs && (!self.require_git || dirs.iter().any(|dir| dir.join(".git").exists()));
+        for dir in &dirs {
+            if !self.dirs.contains_key(dir) {
+                let matchers = [
+                    load_ignore_file(&dir.join(".ignore"), self.case_insensitive),
+                    load_ignore_file(&dir.join(".gitignore"), self.case_insensitive),
+                    load_ignore_file(&dir.join(".git/info/exclude"), self.case_insensitive),
+                ];
+                self.dirs.insert(dir.clone(), matchers);
+            }
+        }
+        let kinds = if in_repo { 0..3 } else { 0..1 };
+        let cached = &self.dirs;
+        let by_kind = kinds.flat_map(|kind| dirs.iter().filter_map(move |dir| cached[dir][kind].as_ref()));
+        for matcher in by_kind.chain(self.explicit.iter().filter(|matcher| path.starts_with(matcher.path()))) {
+            match matcher.matched_path_or_any_parents(&path, is_dir) {
+                ignore::Match::Ignore(glob) => {
+                    let source = glob.from().unwrap_or(matcher.path());
+                    return Some((source.to_string_lossy().into_owned(), glob.original().to_string()));
                 }
+                ignore::Match::Whitelist(_) => return None,
+                ignore::Match::None => {}
             }
-            PatternMatcher::Glob(glob_set) => glob_set.is_match(path),
         }
+        None
+    }
+}
+
+/// Load one ignore file, or `None` if it is missing or has no rules
+///
+/// `.git/info/exclude` is rooted at the repository, two levels above the file.
+fn load_ignore_file(file: &Path, case_insensitive: bool) -> Option<Gitignore> {
+    if !file.is_file() {
+        return None;
     }
+    let root = if file.ends_with(".git/info/exclude") { file.ancestors().nth(3)? } else { file.parent()? };
+    let mut builder = GitignoreBuilder::new(root);
+    builder.case_insensitive(case_insensitive).ok()?;
+    builder.add(file);
+    builder.build().ok().filter(|matcher| !matcher.is_empty())
 }
 
+/// The first hidden (dot-prefixed) component of `path` below `root`
+fn hidden_component(path: &Path, root: &Path) -> Option<String> {
+    path.strip_prefix(root)
+        .ok()?
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .find(|name| name.starts_with('.') && name != "." && name != "..")
+        .map(|name| name.into_owned())
+}
 
 /// Build a GlobSet from patterns using cached compilation
 fn build_glob_set(patterns: &[String], case_sensitive: bool) -> Result<GlobSet> {
@@ -814,7 +5114,7 @@ fn build_glob_set(patterns: &[String], case_sensitive: bool) -> Result<GlobSet>
             pattern.clone()
         };
         
-        let glob = globset::GlobBuilder::new(&adjusted_pattern)
+        let glob = globset::GlobBuilder::new(&pattern_cache::expand_posix_classes(&adjusted_pattern)?)
             .case_insensitive(!case_sensitive)
             .build()?;
         builder.add(glob);
@@ -824,15 +5124,27 @@ fn build_glob_set(patterns: &[String], case_sensitive: bool) -> Result<GlobSet>
 }
 
 /// Check if a directory entry should be included based on filters
+#[allow(clippy::too_many_arguments)]
 fn should_include_entry(
     entry: &DirEntry,
     pattern_matcher: &Option<PatternMatcher>,
-    exclude_set: &Option<GlobSet>,
+    name_filter: &Option<GlobSet>,
+    exclude_set: &Option<CaseMatchers<ExcludeMatcher>>,
     regex_matcher: &Option<regex::Regex>,
-    file_type_filter: Option<FileType>,
-    extensions: &Option<Vec<String>>,
+    regex_target: MatchTarget,
+    file_type_filter: Option<FileTypeFilter>,
+    symlink_target_filter: Option<FileType>,
+    symlink_broken: Option<bool>,
+    content_type_filter: Option<ContentType>,
+    hidden_only: bool,
+    skip_empty_dirs: bool,
+    follow_file_symlinks: bool,
+    metadata_follow: Option<bool>,
+    extensions: &Option<ExtensionFilter>,
     min_size: Option<u64>,
     max_size: Option<u64>,
+    min_lines: Option<u64>,
+    max_lines: Option<u64>,
     mtime_after: Option<f64>,
     mtime_before: Option<f64>,
     atime_after: Option<f64>,
@@ -842,24 +5154,54 @@ fn should_include_entry(
 ) -> bool {
     let path = entry.path();
     
+    // Dotfile audits only want entries whose own name is hidden
+    if hidden_only && !entry.file_name().as_encoded_bytes().starts_with(b".") {
+        return false;
+    }
+    
+    // Symlinks to files take their type and metadata from the policy, not the walker
+    let link_metadata = file_symlink_metadata(entry, follow_file_symlinks);
+    let entry_type = link_metadata.as_ref().map(|m| m.file_type()).or_else(|| entry.file_type());
+    // `metadata_follow` picks the link itself (lstat) or its target (stat) for
+    // the size and time filters, whatever the walk does with the link
+    let filter_metadata = metadata_follow.filter(|_| entry.path_is_symlink()).map(|follow| {
+        if follow { std::fs::metadata(path) } else { std::fs::symlink_metadata(path) }.ok()
+    });
+    let metadata = || match (&filter_metadata, &link_metadata) {
+        (Some(metadata), _) => metadata.clone(),
+        (None, Some(metadata)) => Some(metadata.clone()),
+        (None, None) => entry.metadata().ok(),
+    };
+    
     // Check glob pattern
     if let Some(ref matcher) = pattern_matcher {
-        if !matcher.is_match(path) {
+        if !matcher.is_match(entry) {
+            return false;
+        }
+    }
+    
+    // Check the file name filter
+    if let Some(ref name_filter) = name_filter {
+        if !name_filter.is_match(entry.file_name()) {
             return false;
         }
     }
     
     // Check exclude patterns
     if let Some(ref excludes) = exclude_set {
-        if excludes.is_match(path) {
+        if excludes.for_path(path).is_excluded(path) {
             return false;
         }
     }
     
     // Check regex pattern
     if let Some(ref regex) = regex_matcher {
-        if let Some(path_str) = path.to_str() {
-            if !regex.is_match(path_str) {
+        let subject = match regex_target {
+            MatchTarget::Name => path.file_name().and_then(|n| n.to_str()),
+            _ => path.to_str(),
+        };
+        if let Some(subject) = subject {
+            if !regex.is_match(subject) {
                 return false;
             }
         }
@@ -867,51 +5209,67 @@ fn should_include_entry(
     
     // Check file type
     if let Some(filter) = file_type_filter {
-        let file_type = entry.file_type();
-        let matches = match filter {
-            FileType::File => file_type.map_or(false, |ft| ft.is_file()),
-            FileType::Dir => file_type.map_or(false, |ft| ft.is_dir()),
-            FileType::Symlink => file_type.map_or(false, |ft| ft.is_symlink()),
-        };
-        if !matches {
+        if !filter.matches(entry_type) {
             return false;
         }
     }
     
+    // Symlinks can be filtered by what they point to; the link itself is returned
+    if (symlink_target_filter.is_some() || symlink_broken.is_some()) && entry.path_is_symlink() {
+        match std::fs::metadata(path) {
+            // Dangling links have no target type, so a target filter drops them by default
+            Err(_) => {
+                if !symlink_broken.unwrap_or(symlink_target_filter.is_none()) {
+                    return false;
+                }
+            }
+            Ok(target) => {
+                let matches = match symlink_target_filter {
+                    Some(FileType::File) => target.is_file(),
+                    Some(FileType::Dir) => target.is_dir(),
+                    _ => true,
+                };
+                if !matches {
+                    return false;
+                }
+            }
+        }
+    }
+    
     // Check extensions
-    if let Some(ref exts) = extensions {
-        if !exts.is_empty() {
-            if let Some(ext) = path.extension() {
-                if let Some(ext_str) = ext.to_str() {
-                    if !exts.iter().any(|e| e == ext_str) {
-                        return false;
-                    }
+    if let Some(ref extensions) = extensions {
+        if let Some(ext) = path.extension() {
+            if let Some(ext_str) = ext.to_str() {
+                if !extensions.matches(ext_str) {
+                    return false;
                 }
-            } else {
-                // No extension, don't include
-                return false;
             }
+        } else {
+            // No extension, don't include
+            return false;
         }
     }
     
     // Check file size
     if min_size.is_some() || max_size.is_some() {
-        // Only check size for files
-        if let Some(file_type) = entry.file_type() {
-            if file_type.is_file() {
-                if let Ok(metadata) = entry.metadata() {
-                    let size = metadata.len();
-                    
-                    if let Some(min) = min_size {
-                        if size < min {
-                            return false;
-                        }
+        // Only check size for files, and with metadata_follow for links to files
+        let sized = match filter_metadata {
+            Some(_) => std::fs::metadata(path).is_ok_and(|target| target.is_file()),
+            None => entry_type.is_some_and(|file_type| file_type.is_file()),
+        };
+        if sized {
+            if let Some(metadata) = metadata() {
+                let size = metadata.len();
+                
+                if let Some(min) = min_size {
+                    if size < min {
+                        return false;
                     }
-                    
-                    if let Some(max) = max_size {
-                        if size > max {
-                            return false;
-                        }
+                }
+                
+                if let Some(max) = max_size {
+                    if size > max {
+                        return false;
                     }
                 }
             }
@@ -920,7 +5278,7 @@ fn should_include_entry(
     
     // Check modification time
     if mtime_after.is_some() || mtime_before.is_some() {
-        if let Ok(metadata) = entry.metadata() {
+        if let Some(metadata) = metadata() {
             if let Ok(modified) = metadata.modified() {
                 if let Ok(duration) = modified.duration_since(SystemTime::UNIX_EPOCH) {
                     let mtime = duration.as_secs_f64();
@@ -943,7 +5301,7 @@ fn should_include_entry(
     
     // Check access time
     if atime_after.is_some() || atime_before.is_some() {
-        if let Ok(metadata) = entry.metadata() {
+        if let Some(metadata) = metadata() {
             if let Ok(accessed) = metadata.accessed() {
                 if let Ok(duration) = accessed.duration_since(SystemTime::UNIX_EPOCH) {
                     let atime = duration.as_secs_f64();
@@ -966,7 +5324,7 @@ fn should_include_entry(
     
     // Check creation time
     if ctime_after.is_some() || ctime_before.is_some() {
-        if let Ok(metadata) = entry.metadata() {
+        if let Some(metadata) = metadata() {
             if let Ok(created) = metadata.created() {
                 if let Ok(duration) = created.duration_since(SystemTime::UNIX_EPOCH) {
                     let ctime = duration.as_secs_f64();
@@ -987,18 +5345,280 @@ fn should_include_entry(
         }
     }
     
+    // Check for empty directories, reading at most one child and only for directories
+    if skip_empty_dirs && entry_type.is_some_and(|ft| ft.is_dir()) {
+        // Unreadable directories are kept, since their contents are unknown
+        let is_empty = std::fs::read_dir(path).is_ok_and(|mut children| children.next().is_none());
+        if is_empty {
+            return false;
+        }
+    }
+    
+    // Check text vs binary content last, since it has to read the file
+    if let Some(wanted) = content_type_filter {
+        if !entry_type.is_some_and(|ft| ft.is_file()) {
+            return false;
+        }
+        match sniff_content_type(path) {
+            Some(found) if found == wanted => {}
+            _ => return false,
+        }
+    }
+    
+    // Line counts read the whole file, up to max_lines; entries without a
+    // count, such as directories and binary files, never match
+    if min_lines.is_some() || max_lines.is_some() {
+        if !entry_type.is_some_and(|ft| ft.is_file()) {
+            return false;
+        }
+        match count_lines(path, max_lines) {
+            Some(lines) if min_lines.is_none_or(|min| lines >= min) && max_lines.is_none_or(|max| lines <= max) => {}
+            _ => return false,
+        }
+    }
+    
     true
 }
 
+/// Metadata overriding the walker's view of a symlink to a file
+///
+/// The walker follows links only when `follow_dir_symlinks` is set, so links to
+/// files are resolved here when the two options disagree: followed links are
+/// reported as links again, and unfollowed ones are resolved to their target.
+/// Returns `None` when the walker's own type and metadata already apply.
+fn file_symlink_metadata(entry: &DirEntry, follow_file_symlinks: bool) -> Option<std::fs::Metadata> {
+    let file_type = entry.file_type()?;
+    if follow_file_symlinks && file_type.is_symlink() {
+        std::fs::metadata(entry.path()).ok().filter(|metadata| metadata.is_file())
+    } else if !follow_file_symlinks && entry.path_is_symlink() && file_type.is_file() {
+        std::fs::symlink_metadata(entry.path()).ok()
+    } else {
+        None
+    }
+}
+
+/// Size of a regular file, following file symlinks per the policy
+///
+/// Directories and other entries have no size here, so `None`.
+fn regular_file_size(entry: &DirEntry, follow_file_symlinks: bool) -> Option<u64> {
+    let metadata = match file_symlink_metadata(entry, follow_file_symlinks) {
+        Some(metadata) => metadata,
+        None if entry.file_type()?.is_file() => entry.metadata().ok()?,
+        None => return None,
+    };
+    metadata.is_file().then_some(metadata.len())
+}
+
+/// Size of `entry` when it is a file larger than `limit`
+///
+/// Symlinks to files follow the same policy as the size filters.
+fn oversized_file_size(entry: &DirEntry, limit: u64, follow_file_symlinks: bool) -> Option<u64> {
+    let metadata = match file_symlink_metadata(entry, follow_file_symlinks) {
+        Some(metadata) => metadata,
+        None if entry.file_type()?.is_file() => entry.metadata().ok()?,
+        None => return None,
+    };
+    Some(metadata.len()).filter(|&size| metadata.is_file() && size > limit)
+}
+
+/// Stat error for an entry the size or time filters could not check
+///
+/// Size filters only apply to files, time filters to every entry. Returns
+/// `None` when no such filter is set or the metadata could be read.
+fn unchecked_metadata_error(entry: &DirEntry, follow_file_symlinks: bool, size_filtered: bool, time_filtered: bool) -> Option<String> {
+    let filtered = time_filtered || (size_filtered && entry.file_type().is_some_and(|ft| ft.is_file() || ft.is_symlink()));
+    if !filtered || file_symlink_metadata(entry, follow_file_symlinks).is_some() {
+        return None;
+    }
+    let err = entry.metadata().err()?;
+    Some(format!("Failed to stat {}: {}", entry.path().display(), err))
+}
+
+/// Error for a symlink whose chain takes more than `limit` hops to resolve
+///
+/// Each link in the chain is read in turn, so loops are caught once they
+/// exceed the limit too. Returns `None` for chains within the limit, including
+/// ones ending in a missing target.
+fn symlink_hops_error(path: &Path, limit: usize) -> Option<String> {
+    let mut current = path.to_path_buf();
+    let mut hops = 0;
+    while let Ok(target) = std::fs::read_link(&current) {
+        hops += 1;
+        if hops > limit {
+            return Some(format!("Too many symlink hops resolving {} (limit {})", path.display(), limit));
+        }
+        current = match current.parent() {
+            Some(parent) => parent.join(target),
+            None => target,
+        };
+    }
+    None
+}
+
+/// Error for a path `strict_utf8` refuses to convert lossily
+///
+/// The lossy form is included so the offending entry can still be located.
+fn non_utf8_path_error(path: &Path) -> String {
+    format!("Path is not valid UTF-8: {}", path.to_string_lossy())
+}
+
+/// Number of leading bytes inspected when classifying text vs binary files
+const CONTENT_SNIFF_BYTES: u64 = 8 * 1024;
+
+/// Read up to `limit` bytes from the start of a file
+fn read_prefix(path: &Path, limit: u64) -> Option<Vec<u8>> {
+    let file = File::open(path).ok()?;
+    let mut prefix = Vec::with_capacity(limit.min(64 * 1024) as usize);
+    file.take(limit).read_to_end(&mut prefix).ok()?;
+    Some(prefix)
+}
+
+/// Classify a file as text or binary from its first bytes
+///
+/// Uses the same heuristic as grep: a NUL byte in the prefix means binary.
+/// Returns `None` if the file cannot be read.
+fn sniff_content_type(path: &std::path::Path) -> Option<ContentType> {
+    let prefix = read_prefix(path, CONTENT_SNIFF_BYTES)?;
+    if prefix.contains(&0) {
+        Some(ContentType::Binary)
+    } else {
+        Some(ContentType::Text)
+    }
+}
+
+/// Cache of user and group names looked up by `resolve_owner`
+#[cfg(unix)]
+type OwnerNames = Mutex<users::UsersCache>;
+
+/// Owner names are not resolved on this platform
+#[cfg(not(unix))]
+type OwnerNames = ();
+
+/// User and group names owning the entry with `metadata`
+///
+/// Ids without a passwd or group entry resolve to `None`.
+#[cfg(unix)]
+fn resolve_owner_names(metadata: Option<std::fs::Metadata>, names: &OwnerNames) -> (Option<String>, Option<String>) {
+    use std::os::unix::fs::MetadataExt;
+    use users::{Groups, Users};
+    let Some(metadata) = metadata else {
+        return (None, None);
+    };
+    let names = names.lock().unwrap();
+    let owner = names.get_user_by_uid(metadata.uid()).map(|user| user.name().to_string_lossy().into_owned());
+    let group = names.get_group_by_gid(metadata.gid()).map(|group| group.name().to_string_lossy().into_owned());
+    (owner, group)
+}
+
+#[cfg(not(unix))]
+fn resolve_owner_names(_metadata: Option<std::fs::Metadata>, _names: &OwnerNames) -> (Option<String>, Option<String>) {
+    (None, None)
+}
+
+/// Line count of `entry` when it is a regular text file
+///
+/// Symlinks to files follow the same policy as the size filters.
+fn regular_file_line_count(entry: &DirEntry, follow_file_symlinks: bool) -> Option<u64> {
+    let is_file = match file_symlink_metadata(entry, follow_file_symlinks) {
+        Some(metadata) => metadata.is_file(),
+        None => entry.file_type()?.is_file(),
+    };
+    if is_file {
+        count_lines(entry.path(), None)
+    } else {
+        None
+    }
+}
+
+/// First `limit` bytes of `entry` as lossy UTF-8 when it is a regular text file
+///
+/// At least `CONTENT_SNIFF_BYTES` are read so binary files are recognised
+/// like `content_type` does; a character cut off at the limit is dropped.
+fn regular_file_preview(entry: &DirEntry, follow_file_symlinks: bool, limit: usize) -> Option<String> {
+    let is_file = match file_symlink_metadata(entry, follow_file_symlinks) {
+        Some(metadata) => metadata.is_file(),
+        None => entry.file_type()?.is_file(),
+    };
+    if !is_file {
+        return None;
+    }
+    let prefix = read_prefix(entry.path(), (limit as u64).max(CONTENT_SNIFF_BYTES))?;
+    if prefix.contains(&0) {
+        return None;
+    }
+    let mut preview = &prefix[..limit.min(prefix.len())];
+    if let Err(err) = std::str::from_utf8(preview) {
+        if err.error_len().is_none() && preview.len() < prefix.len() {
+            preview = &preview[..err.valid_up_to()];
+        }
+    }
+    Some(String::from_utf8_lossy(preview).into_owned())
+}
+
+/// Digest of `entry`'s contents with `algorithm` when it is a regular file
+///
+/// Symlinks to files follow the same policy as the size filters; files that
+/// cannot be read get `None`.
+fn regular_file_hash(entry: &DirEntry, follow_file_symlinks: bool, algorithm: HashAlgorithm) -> Option<String> {
+    let is_file = match file_symlink_metadata(entry, follow_file_symlinks) {
+        Some(metadata) => metadata.is_file(),
+        None => entry.file_type()?.is_file(),
+    };
+    if is_file {
+        algorithm.digest_file(entry.path()).ok()
+    } else {
+        None
+    }
+}
+
+/// Count the lines of a text file, including an unterminated last line
+///
+/// Reads the whole file, or stops early with a partial count once it exceeds
+/// `stop_above`. Returns `None` for binary files, detected like `content_type`
+/// by a NUL byte in the first bytes, and for unreadable files.
+fn count_lines(path: &Path, stop_above: Option<u64>) -> Option<u64> {
+    let mut file = File::open(path).ok()?;
+    let mut buffer = vec![0; 64 * 1024];
+    let mut read = 0u64;
+    let mut lines = 0u64;
+    let mut last_byte = b'\n';
+    loop {
+        let n = match file.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(_) => return None,
+        };
+        let chunk = &buffer[..n];
+        if read < CONTENT_SNIFF_BYTES {
+            let prefix = (CONTENT_SNIFF_BYTES - read).min(n as u64) as usize;
+            if memchr::memchr(0, &chunk[..prefix]).is_some() {
+                return None;
+            }
+        }
+        read += n as u64;
+        lines += memchr::memchr_iter(b'\n', chunk).count() as u64;
+        last_byte = chunk[n - 1];
+        if stop_above.is_some_and(|limit| lines > limit) {
+            return Some(lines);
+        }
+    }
+    Some(lines + u64::from(last_byte != b'\n'))
+}
+
 /// Search file content using grep functionality
+///
+/// `metadata` is the walker's metadata for the file, used for
+/// `search_metadata`; without it the opened file is statted instead.
 fn search_file_content(
     tx: &crossbeam_channel::Sender<FindResult>,
-    entry: &DirEntry,
-    content_matcher: &RegexMatcher,
+    path: &Path,
+    metadata: Option<&std::fs::Metadata>,
+    options: &SearchOptions,
+    stats: Option<&ScanStats>,
 ) -> Result<()> {
-    let path = entry.path();
-    
-    // Open the file
+    // Open the file, waiting for a slot when open files are capped
+    let _slot = options.open_files.as_ref().map(OpenFileLimiter::acquire);
     let file = match File::open(path) {
         Ok(f) => f,
         Err(e) => {
@@ -1007,17 +5627,55 @@ fn search_file_content(
         }
     };
     
+    // Search the members of tar archives instead of the raw archive bytes
+    if options.archives {
+        if let Some(kind) = ArchiveKind::for_path(path) {
+            return search_archive(tx, path, file, kind, options, stats);
+        }
+    }
+    
     // Create searcher (buffer size optimization deferred - API doesn't support it directly)
-    let mut searcher = Searcher::new();
+    let mut searcher = options.searcher();
     
     // Create sink for collecting results (zero-copy: convert path to string once)
-    let mut sink = SearchSink::new(path.to_string_lossy().into_owned());
+    let mut sink = SearchSink::new(path.to_string_lossy().into_owned(), options);
     
     // Search the file content
-    match searcher.search_file(content_matcher, &file, &mut sink) {
+    match searcher.search_file(&options.content_matcher, &file, &mut sink) {
         Ok(_) => {
+            // Only what was read counts, so head_lines and quiet stop the tally early
+            if let Some(stats) = stats {
+                stats.bytes_searched.fetch_add(sink.bytes_read, Ordering::Relaxed);
+            }
+            let mut results = sink.into_results();
+            
+            if options.search_metadata && !results.is_empty() {
+                let metadata = metadata.cloned().or_else(|| file.metadata().ok());
+                let size = metadata.as_ref().map(|m| m.len());
+                let mtime = metadata.and_then(|m| m.modified().ok()).and_then(unix_seconds);
+                for result in &mut results {
+                    result.size = size;
+                    result.mtime = mtime;
+                }
+            }
+            
+            // Rewrite the file in place once we know it has matches
+            if let Some(replacer) = options.replacer.as_ref().filter(|r| r.write && !results.is_empty()) {
+                drop(file);
+                let written = match replacer.rewrite_file(path) {
+                    Ok(count) => count,
+                    Err(e) => {
+                        let _ = tx.send(FindResult::Error(format!("Failed to rewrite {}: {}", path.display(), e)));
+                        0
+                    }
+                };
+                for result in &mut results {
+                    result.replacements = Some(written);
+                }
+            }
+            
             // Send all collected results
-            for result in sink.into_results() {
+            for result in results {
                 let _ = tx.send(FindResult::Search(result));
             }
         }
@@ -1027,4 +5685,100 @@ fn search_file_content(
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Archive formats whose members are searched when `archives` is enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Tar,
+    TarGz,
+}
+
+impl ArchiveKind {
+    /// Detect the archive format from the file name
+    fn for_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveKind::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveKind::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// Search each regular file inside a tar archive
+///
+/// Results are reported with paths like `archive.tar::inner/file.rs`.
+/// Directories, links and other special members are skipped, and members are
+/// never rewritten by `write`.
+fn search_archive(
+    tx: &crossbeam_channel::Sender<FindResult>,
+    path: &Path,
+    file: File,
+    kind: ArchiveKind,
+    options: &SearchOptions,
+    stats: Option<&ScanStats>,
+) -> Result<()> {
+    let reader: Box<dyn Read> = match kind {
+        ArchiveKind::Tar => Box::new(file),
+        ArchiveKind::TarGz => Box::new(flate2::read::GzDecoder::new(file)),
+    };
+    let mut archive = tar::Archive::new(reader);
+    let mut searcher = options.searcher();
+    let archive_path = path.to_string_lossy();
+    
+    let entries = match archive.entries() {
+        Ok(entries) => entries,
+        Err(e) => {
+            let _ = tx.send(FindResult::Error(format!("Failed to read archive {}: {}", path.display(), e)));
+            return Ok(());
+        }
+    };
+    for entry in entries {
+        // A corrupt member leaves the rest of the stream unreadable
+        let mut entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let _ = tx.send(FindResult::Error(format!("Failed to read archive {}: {}", path.display(), e)));
+                break;
+            }
+        };
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        
+        let member_path = format!("{}::{}", archive_path, entry.path()?.to_string_lossy());
+        let size = entry.size();
+        let mut sink = SearchSink::new(member_path.clone(), options);
+        match searcher.search_reader(&options.content_matcher, &mut entry, &mut sink) {
+            Ok(_) => {
+                if let Some(stats) = stats {
+                    stats.bytes_searched.fetch_add(sink.bytes_read, Ordering::Relaxed);
+                }
+                let mut results = sink.into_results();
+                let found = !results.is_empty();
+                if options.search_metadata {
+                    // Members report their own size and mtime from the tar header
+                    let mtime = entry.header().mtime().ok().map(|t| t as f64);
+                    for result in &mut results {
+                        result.size = Some(size);
+                        result.mtime = mtime;
+                    }
+                }
+                for result in results {
+                    let _ = tx.send(FindResult::Search(result));
+                }
+                if found && options.quiet {
+                    break;
+                }
+            }
+            Err(e) => {
+                let _ = tx.send(FindResult::Error(format!("Search error in {}: {}", member_path, e)));
+            }
+        }
+    }
+    
+    Ok(())
+}
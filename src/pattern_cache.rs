@@ -1,5 +1,7 @@
 // this_file: src/pattern_cache.rs
 
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::collections::HashMap;
 use anyhow::Result;
@@ -137,6 +139,15 @@ impl PatternCache {
         Ok(entry)
     }
     
+    /// Check whether a pattern is already compiled, without touching its LRU position
+    pub fn contains(&self, pattern: &str, case_sensitive: bool) -> bool {
+        let key = CacheKey {
+            pattern: pattern.to_string(),
+            case_sensitive,
+        };
+        self.cache.read().unwrap().contains_key(&key)
+    }
+    
     /// Update access order for LRU tracking
     fn update_access_order(&self, key: &CacheKey) {
         let mut access_order = self.access_order.write().unwrap();
@@ -177,7 +188,7 @@ fn compile_pattern(pattern: &str, case_sensitive: bool) -> Result<GlobSet> {
         pattern.to_string()
     };
     
-    let glob = globset::GlobBuilder::new(&adjusted_pattern)
+    let glob = globset::GlobBuilder::new(&expand_posix_classes(&adjusted_pattern)?)
         .case_insensitive(!case_sensitive)
         .build()?;
     
@@ -186,11 +197,170 @@ fn compile_pattern(pattern: &str, case_sensitive: bool) -> Result<GlobSet> {
     Ok(builder.build()?)
 }
 
+/// Characters of each POSIX class, as class items globset understands
+///
+/// No set starts with `!`, `^` or `]`, so it can open a bracket expression.
+const POSIX_CLASSES: &[(&str, &str)] = &[
+    ("alnum", "0-9A-Za-z"),
+    ("alpha", "A-Za-z"),
+    ("blank", " \t"),
+    ("cntrl", "\x00-\x1f\x7f"),
+    ("digit", "0-9"),
+    ("graph", "\"-~!"),
+    ("lower", "a-z"),
+    ("print", " -~"),
+    ("punct", ":-@!-/[-`{-~"),
+    ("space", " \t\n\r\x0b\x0c"),
+    ("upper", "A-Z"),
+    ("xdigit", "0-9A-Fa-f"),
+];
+
+/// Rewrite POSIX classes such as `[[:digit:]]` as the ranges they stand for
+///
+/// globset has no POSIX classes and would read `[[:digit:]]` as a set of the
+/// characters `[:digt` followed by a literal `]`, silently matching nothing
+/// useful. Classes are only recognized inside bracket expressions; unknown
+/// class names are an error.
+pub fn expand_posix_classes(pattern: &str) -> Result<Cow<'_, str>> {
+    if !pattern.contains("[:") {
+        return Ok(Cow::Borrowed(pattern));
+    }
+    let mut out = String::with_capacity(pattern.len() * 2);
+    let mut rest = pattern;
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\\' => {
+                let escaped = rest.chars().nth(1).map_or(1, |next| 1 + next.len_utf8());
+                out.push_str(&rest[..escaped]);
+                rest = &rest[escaped..];
+            }
+            '[' => {
+                // Copy the opening bracket, a negation and a leading literal `]`
+                let mut body_start = 1;
+                if rest[body_start..].starts_with(['!', '^']) {
+                    body_start += 1;
+                }
+                if rest[body_start..].starts_with(']') {
+                    body_start += 1;
+                }
+                out.push_str(&rest[..body_start]);
+                rest = &rest[body_start..];
+                loop {
+                    if let Some((name, after)) = rest.strip_prefix("[:").and_then(|class| class.split_once(":]")) {
+                        let (_, set) = POSIX_CLASSES
+                            .iter()
+                            .find(|(known, _)| *known == name)
+                            .ok_or_else(|| anyhow::anyhow!("unknown POSIX class '[:{}:]' in '{}'", name, pattern))?;
+                        out.push_str(set);
+                        rest = after;
+                    } else if let Some(c) = rest.chars().next() {
+                        out.push(c);
+                        rest = &rest[c.len_utf8()..];
+                        if c == ']' {
+                            break;
+                        }
+                    } else {
+                        break;
+                    }
+                }
+            }
+            _ => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    Ok(Cow::Owned(out))
+}
+
+/// The path in the forward-slash form patterns are written in
+///
+/// Globs and literal patterns use `/` on every platform, so paths are matched
+/// against this form to give the same results on Windows as elsewhere. On
+/// platforms whose separator already is `/` the path is returned untouched,
+/// keeping non-UTF-8 names intact.
+pub fn slash_path(path: &Path) -> Cow<'_, Path> {
+    if std::path::MAIN_SEPARATOR == '/' {
+        return Cow::Borrowed(path);
+    }
+    match with_slashes(&path.to_string_lossy(), std::path::MAIN_SEPARATOR) {
+        Cow::Borrowed(_) => Cow::Borrowed(path),
+        Cow::Owned(converted) => Cow::Owned(PathBuf::from(converted)),
+    }
+}
+
+/// Replace `separator` with `/`, borrowing when there is nothing to replace
+fn with_slashes(path: &str, separator: char) -> Cow<'_, str> {
+    if separator != '/' && path.contains(separator) {
+        Cow::Owned(path.replace(separator, "/"))
+    } else {
+        Cow::Borrowed(path)
+    }
+}
+
 /// Check if a pattern is literal (no wildcards)
 pub fn is_literal_pattern(pattern: &str) -> bool {
     !pattern.chars().any(|c| matches!(c, '*' | '?' | '[' | ']' | '{' | '}'))
 }
 
+/// Detect regular expression syntax that a glob would silently treat differently
+///
+/// Returns a description of the first likely regex-ism found outside character
+/// classes and escapes, such as `\d`, a `^`/`$` anchor, `|` alternation, a
+/// `{n}` / `{n,}` repetition or a `+` quantifier. Brace alternation with
+/// several items (`file{1,3}.txt`) is valid glob syntax and is not reported.
+pub fn regex_syntax_hint(pattern: &str) -> Option<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => {
+                if let Some(&class @ ('d' | 'D' | 'w' | 'W' | 's' | 'S' | 'b' | 'B')) = chars.get(i + 1) {
+                    return Some(format!("'\\{}' is a regex character class", class));
+                }
+                i += 2;
+                continue;
+            }
+            '[' => {
+                // Skip the class body; a leading `!`, `^` or `]` is part of it
+                let mut j = i + 1;
+                if matches!(chars.get(j), Some('!' | '^')) {
+                    j += 1;
+                }
+                if chars.get(j) == Some(&']') {
+                    j += 1;
+                }
+                while j < chars.len() && chars[j] != ']' {
+                    j += 1;
+                }
+                if chars.get(j + 1) == Some(&'+') {
+                    return Some("'+' after a character class is a regex quantifier".to_string());
+                }
+                i = j + 1;
+                continue;
+            }
+            '^' if i == 0 => return Some("a leading '^' is a regex anchor".to_string()),
+            '$' if i == chars.len() - 1 => return Some("a trailing '$' is a regex anchor".to_string()),
+            '|' => return Some("'|' is regex alternation; globs use '{a,b}'".to_string()),
+            '+' if i > 0 && matches!(chars[i - 1], ')' | '.') => {
+                return Some(format!("'{}+' is a regex quantifier", chars[i - 1]));
+            }
+            '{' => {
+                if let Some(len) = chars[i + 1..].iter().position(|&c| c == '}') {
+                    let body: String = chars[i + 1..i + 1 + len].iter().collect();
+                    let count = body.strip_suffix(',').unwrap_or(&body);
+                    if !count.is_empty() && count.chars().all(|c| c.is_ascii_digit()) {
+                        return Some(format!("'{{{}}}' is a regex repetition count", body));
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +384,53 @@ mod tests {
         assert!(entry.is_literal);
     }
     
+    #[test]
+    fn test_regex_syntax_hint() {
+        for pattern in ["a{2}", "x{3,}.log", "^foo", "foo$", "(foo|bar).txt", r"\d+.txt", "[a-z]+", "(ab)+", ".+"] {
+            assert!(regex_syntax_hint(pattern).is_some(), "{pattern}");
+        }
+        for pattern in ["*.py", "file{1,3}.txt", "*.{rs,toml}", "[^a]*", "[!$]", r"\$HOME", r"a\|b", ".*rc", "[|]"] {
+            assert!(regex_syntax_hint(pattern).is_none(), "{pattern}");
+        }
+    }
+    
+    #[test]
+    fn test_backslash_paths_match_slash_globs() {
+        let glob_set = compile_pattern("**/src/**/*.rs", true).unwrap();
+        let windows_path = with_slashes(r"C:\proj\src\foo\bar.rs", '\\');
+        assert_eq!(windows_path, "C:/proj/src/foo/bar.rs");
+        assert!(glob_set.is_match(windows_path.as_ref()));
+        assert!(!glob_set.is_match(r"C:\proj\src\foo\bar.rs"));
+        assert!(matches!(with_slashes("src/main.rs", '\\'), Cow::Borrowed(_)));
+        assert!(matches!(slash_path(Path::new("src/main.rs")), Cow::Borrowed(_)));
+    }
+    
+    #[test]
+    fn test_posix_classes() {
+        assert_eq!(expand_posix_classes("[[:digit:]]*.log").unwrap(), "[0-9]*.log");
+        assert_eq!(expand_posix_classes("[![:alpha:]_]*").unwrap(), "[!A-Za-z_]*");
+        assert_eq!(expand_posix_classes("[]:]x[:y").unwrap(), "[]:]x[:y");
+        assert_eq!(expand_posix_classes("[[:]").unwrap(), "[[:]");
+        assert!(matches!(expand_posix_classes("[a-z]*.txt").unwrap(), Cow::Borrowed(_)));
+        assert!(expand_posix_classes("[[:digits:]]").is_err());
+        
+        let glob_set = compile_pattern("[[:upper:]][[:punct:]]*", true).unwrap();
+        assert!(glob_set.is_match("A-b"));
+        assert!(glob_set.is_match("Z]"));
+        assert!(!glob_set.is_match("a-b"));
+        assert!(!glob_set.is_match("AB"));
+    }
+    
+    #[test]
+    fn test_contains() {
+        let cache = PatternCache::new();
+        assert!(cache.contains("*.py", true));
+        assert!(!cache.contains("*.warm", false));
+        cache.get_or_compile("*.warm", false).unwrap();
+        assert!(cache.contains("*.warm", false));
+        assert!(!cache.contains("*.warm", true));
+    }
+    
     #[test]
     fn test_cache_stats() {
         let stats = PATTERN_CACHE.stats();
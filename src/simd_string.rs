@@ -6,24 +6,50 @@
 //! Future work will include SIMD optimizations for even better performance.
 //!
 //! Note: This module contains SIMD optimization infrastructure prepared for v3.0.0
-//! All functions are intentionally unused in the current version
+//! Only `FastPatternMatch` is used so far, for literal glob patterns
 #![allow(dead_code)]
 
+use unicase::UniCase;
+
 /// High-performance string comparison operations
 pub struct FastStringOps;
 
 impl FastStringOps {
     /// Fast case-insensitive string equality check
+    ///
+    /// ASCII strings are compared without allocating; anything else uses full
+    /// Unicode case folding, so `"STRASSE"` equals `"straße"`.
     pub fn eq_ignore_case(a: &str, b: &str) -> bool {
-        a.eq_ignore_ascii_case(b)
+        if a.is_ascii() && b.is_ascii() {
+            a.eq_ignore_ascii_case(b)
+        } else {
+            UniCase::unicode(a) == UniCase::unicode(b)
+        }
     }
     
     /// Fast case-insensitive ends_with check
+    ///
+    /// Non-ASCII input is case folded like `eq_ignore_case`. Folding can
+    /// change the length, so every suffix starting on a character boundary that
+    /// could fold to the needle is tried.
     pub fn ends_with_ignore_case(haystack: &str, needle: &str) -> bool {
-        if needle.len() > haystack.len() {
-            return false;
+        if haystack.is_ascii() && needle.is_ascii() {
+            if needle.len() > haystack.len() {
+                return false;
+            }
+            return haystack[haystack.len() - needle.len()..].eq_ignore_ascii_case(needle);
+        }
+        if needle.is_empty() {
+            return true;
         }
-        haystack[haystack.len() - needle.len()..].eq_ignore_ascii_case(needle)
+        // A character folds to at most three characters
+        let max_chars = needle.chars().count() * 3;
+        let needle = UniCase::unicode(needle);
+        haystack
+            .char_indices()
+            .rev()
+            .take(max_chars)
+            .any(|(start, _)| UniCase::unicode(&haystack[start..]) == needle)
     }
     
     /// Fast case-sensitive ends_with check
@@ -89,6 +115,23 @@ mod tests {
         assert!(FastStringOps::ends_with_ignore_case(path, pattern));
     }
     
+    #[test]
+    fn test_unicode_case_folding() {
+        assert!(FastStringOps::eq_ignore_case("Café.txt", "CAFÉ.TXT"));
+        assert!(FastStringOps::eq_ignore_case("straße.md", "STRASSE.MD"));
+        assert!(FastStringOps::eq_ignore_case("İstanbul", "i\u{307}stanbul"));
+        assert!(FastStringOps::eq_ignore_case("ΣΊΣΥΦΟΣ", "σίσυφος"));
+        assert!(!FastStringOps::eq_ignore_case("café", "cafe"));
+        
+        assert!(FastStringOps::ends_with_ignore_case("docs/Straße", "STRASSE"));
+        assert!(FastStringOps::ends_with_ignore_case("docs/STRASSE", "straße"));
+        assert!(FastStringOps::ends_with_ignore_case("/tmp/Ünïcode.PY", "ünïcode.py"));
+        assert!(FastStringOps::ends_with_ignore_case("ñandú/x", ""));
+        assert!(!FastStringOps::ends_with_ignore_case("Ünïcode.py", "xünïcode.py"));
+        // Multi-byte haystack with an ASCII needle must not split a character
+        assert!(!FastStringOps::ends_with_ignore_case("é", "x"));
+    }
+    
     #[test]
     fn test_fast_pattern_match() {
         // Case sensitive matching
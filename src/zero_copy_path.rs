@@ -166,7 +166,7 @@ impl PathBufPool {
 
     /// Get a PathBuf from the pool or create a new one
     pub fn get(&self) -> PathBuf {
-        self.pool.borrow_mut().pop().unwrap_or_else(PathBuf::new)
+        self.pool.borrow_mut().pop().unwrap_or_default()
     }
 
     /// Return a PathBuf to the pool for reuse
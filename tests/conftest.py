@@ -0,0 +1,38 @@
+# this_file: tests/conftest.py
+"""Shared fixtures for the test suite."""
+
+from pathlib import Path
+import pytest
+
+
+@pytest.fixture
+def make_tree():
+    """Return a factory that creates a directory tree from relative paths.
+
+    ``make_tree(root, paths, content="x", links=None)`` writes each path in
+    ``paths`` under ``root`` with ``content``; a dict maps each path to its own
+    str or bytes content instead. Paths ending in "/" become empty directories,
+    and ``links`` maps link paths to the targets they point at. Parent
+    directories are created as needed and ``root`` is returned as a Path.
+    """
+
+    def make(root, paths, content="x", links=None):
+        root = Path(root)
+        items = paths.items() if isinstance(paths, dict) else ((rel, content) for rel in paths)
+        for rel, data in items:
+            path = root / rel
+            if rel.endswith("/"):
+                path.mkdir(parents=True, exist_ok=True)
+                continue
+            path.parent.mkdir(parents=True, exist_ok=True)
+            if isinstance(data, bytes):
+                path.write_bytes(data)
+            else:
+                path.write_text(data)
+        for rel, target in (links or {}).items():
+            path = root / rel
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.symlink_to(target, target_is_directory=(path.parent / target).is_dir())
+        return root
+
+    return make
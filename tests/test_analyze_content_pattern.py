@@ -0,0 +1,38 @@
+# this_file: tests/test_analyze_content_pattern.py
+"""Test the literal prefix analysis of content regexes."""
+
+import pytest
+import vexy_glob
+
+
+def test_literal_prefix_extracted():
+    """A shared leading literal is reported."""
+    info = vexy_glob.analyze_content_pattern(r"ERROR \d+")
+    assert info == {"has_literal_prefix": True, "literal": "ERROR ", "is_anchored": False}
+
+
+def test_common_prefix_of_alternation():
+    """Alternatives contribute only the text they all start with."""
+    assert vexy_glob.analyze_content_pattern(r"foo(bar|baz)")["literal"] == "fooba"
+    assert vexy_glob.analyze_content_pattern(r"cat|dog")["has_literal_prefix"] is False
+
+
+def test_no_literal_prefix():
+    """Patterns starting with a class have no literal."""
+    info = vexy_glob.analyze_content_pattern(r"\w+: failed")
+    assert info["has_literal_prefix"] is False
+    assert info["literal"] is None
+
+
+def test_anchored_pattern():
+    """Start anchors are detected."""
+    info = vexy_glob.analyze_content_pattern(r"^#!/usr/bin/env")
+    assert info["is_anchored"] is True
+    assert info["literal"] == "#!/usr/bin/env"
+    assert vexy_glob.analyze_content_pattern(r"\Aimport")["is_anchored"] is True
+
+
+def test_invalid_pattern():
+    """Invalid regexes raise PatternError."""
+    with pytest.raises(vexy_glob.PatternError):
+        vexy_glob.analyze_content_pattern(r"(unclosed")
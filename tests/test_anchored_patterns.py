@@ -0,0 +1,50 @@
+# this_file: tests/test_anchored_patterns.py
+"""Test root-anchored glob patterns (leading '/')."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = ["src/main.rs", "src/util/mod.rs", "vendor/src/lib.rs", "README.md"]
+
+
+def test_anchored_pattern_matches_only_root_directory(make_tree):
+    """/src/*.rs matches files directly under the root's src directory."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "fn main() {}")
+
+        results = list(vexy_glob.find("/src/*.rs", root=tmpdir))
+        assert len(results) == 1
+        assert results[0].endswith(str(Path("src", "main.rs")))
+
+
+def test_unanchored_pattern_still_matches_nested(make_tree):
+    """Without the leading slash the pattern matches any src directory."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "fn main() {}")
+
+        results = list(vexy_glob.find("**/src/*.rs", root=tmpdir))
+        names = {Path(r).name for r in results}
+        assert {"lib.rs", "main.rs"} <= names
+
+
+def test_anchored_globstar(make_tree):
+    """Anchored patterns can still descend with **."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "fn main() {}")
+
+        results = list(vexy_glob.find("/src/**/*.rs", root=tmpdir))
+        names = sorted(Path(r).name for r in results)
+        assert names == ["main.rs", "mod.rs"]
+
+
+def test_anchored_literal_pattern(make_tree):
+    """An anchored literal matches only the top-level entry."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "fn main() {}")
+        Path(tmpdir, "src", "README.md").write_text("nested")
+
+        results = list(vexy_glob.find("/README.md", root=tmpdir))
+        assert len(results) == 1
+        assert Path(results[0]).parent == Path(tmpdir)
@@ -0,0 +1,68 @@
+# this_file: tests/test_archives.py
+"""Test content search inside tar archives."""
+
+import io
+import tarfile
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def _make_archive(path, mode):
+    with tarfile.open(path, mode) as archive:
+        for name, data in [("src/main.rs", b"fn main() { todo!() }\n"), ("README", b"no match here\n")]:
+            info = tarfile.TarInfo(name)
+            info.size = len(data)
+            archive.addfile(info, io.BytesIO(data))
+        directory = tarfile.TarInfo("src")
+        directory.type = tarfile.DIRTYPE
+        archive.addfile(directory)
+        link = tarfile.TarInfo("link")
+        link.type = tarfile.SYMTYPE
+        link.linkname = "src/main.rs"
+        archive.addfile(link)
+
+
+def test_search_inside_tar_and_tar_gz():
+    """Members of plain and gzip-compressed tar files are searched."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_archive(Path(tmpdir, "plain.tar"), "w")
+        _make_archive(Path(tmpdir, "packed.tar.gz"), "w:gz")
+        _make_archive(Path(tmpdir, "packed.tgz"), "w:gz")
+
+        results = vexy_glob.search("todo", root=tmpdir, archives=True, as_list=True)
+        paths = sorted(r["path"] for r in results)
+        assert paths == sorted(
+            str(Path(tmpdir, name)) + "::src/main.rs" for name in ["plain.tar", "packed.tar.gz", "packed.tgz"]
+        )
+        assert all(r["line_number"] == 1 for r in results)
+        assert all(r["matches"] == ["todo"] for r in results)
+
+
+def test_archives_disabled_by_default():
+    """Without archives=True, compressed archives are not looked into."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_archive(Path(tmpdir, "packed.tar.gz"), "w:gz")
+
+        assert vexy_glob.search("todo", root=tmpdir, as_list=True) == []
+
+
+def test_archives_with_regular_files():
+    """Regular files are searched alongside archives."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_archive(Path(tmpdir, "plain.tar"), "w")
+        Path(tmpdir, "notes.txt").write_text("todo: ship it\n")
+
+        results = vexy_glob.search("todo", root=tmpdir, archives=True, as_list=True)
+        assert sorted(Path(r["path"].split("::")[0]).name for r in results) == ["notes.txt", "plain.tar"]
+
+
+def test_corrupt_archive_reports_error():
+    """A corrupt archive is reported as an error without aborting the search."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "broken.tar.gz").write_bytes(b"not gzip at all")
+        Path(tmpdir, "notes.txt").write_text("todo\n")
+
+        results, stats = vexy_glob.search("todo", root=tmpdir, archives=True, as_list=True, with_stats=True)
+        assert [Path(r["path"]).name for r in results] == ["notes.txt"]
+        assert stats["errors"] == 1
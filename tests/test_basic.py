@@ -60,6 +60,15 @@ def test_pattern_error():
         list(vexy_glob.find("[invalid"))
 
 
+def test_pattern_error_suggests_regex():
+    """Regex syntax in a glob is rejected with a pointer to regex=."""
+    for pattern in ["a{2}", "^main", r"\d+.log", "(foo|bar).txt"]:
+        with pytest.raises(vexy_glob.PatternError, match="regex="):
+            list(vexy_glob.find(pattern))
+    # Brace alternation stays valid glob syntax
+    list(vexy_glob.find("file{1,3}.txt", root=os.path.dirname(__file__)))
+
+
 def test_find_with_file_type():
     """Test filtering by file type."""
     # Find only directories
@@ -0,0 +1,41 @@
+# this_file: tests/test_batch_size.py
+"""Test yielding results in batches from the iterator."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_batches_are_full_except_the_last():
+    """Seven results in batches of three come as 3, 3 and 1."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for i in range(7):
+            Path(tmpdir, f"f{i}.txt").write_text("x")
+
+        batches = list(vexy_glob.find("*.txt", root=tmpdir, batch_size=3))
+        assert [len(b) for b in batches] == [3, 3, 1]
+        assert sorted(Path(p).name for b in batches for p in b) == [f"f{i}.txt" for i in range(7)]
+
+
+def test_batches_of_search_results():
+    """Content search results are batched as dicts."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("hit\nhit\nmiss\nhit\n")
+
+        batches = list(vexy_glob.search("hit", "*.txt", root=tmpdir, batch_size=2))
+        assert [len(b) for b in batches] == [2, 1]
+        assert all(isinstance(r, dict) for b in batches for r in b)
+
+
+def test_batch_size_with_no_results():
+    """An empty walk yields no batches at all."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        assert list(vexy_glob.find("*.nothing", root=tmpdir, batch_size=10)) == []
+
+
+def test_batch_size_must_be_positive():
+    """A zero batch size is rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, batch_size=0)
@@ -0,0 +1,32 @@
+# this_file: tests/test_canonicalize_dedup.py
+"""Test suppressing results that resolve to an already yielded path."""
+
+import os
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = {"real/a.txt": "a", "real/b.txt": "b"}
+LINKS = {"alias": "real"}
+
+
+def test_symlinked_directory_is_deduplicated(make_tree):
+    """Files reachable through a symlinked directory are yielded once."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+        plain = list(vexy_glob.find("**/*.txt", root=tmpdir, follow_symlinks=True))
+        assert len(plain) == 4
+
+        deduped = list(vexy_glob.find("**/*.txt", root=tmpdir, follow_symlinks=True, canonicalize_dedup=True))
+        assert sorted(os.path.basename(p) for p in deduped) == ["a.txt", "b.txt"]
+        assert len({os.path.realpath(p) for p in deduped}) == 2
+
+
+def test_dedup_off_by_default_and_distinct_files_kept():
+    """Without duplicates every result is kept."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for name in ("x.txt", "y.txt", "z.txt"):
+            Path(tmpdir, name).write_text(name)
+        results = vexy_glob.find("*.txt", root=tmpdir, canonicalize_dedup=True, as_list=True)
+        assert len(results) == 3
@@ -0,0 +1,61 @@
+# this_file: tests/test_changed_within.py
+"""Test relative modification-time windows (changed_within / changed_before)."""
+
+import os
+import tempfile
+import time
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+FILES = {"recent.txt": "recent", "old.txt": "old"}
+
+
+def test_changed_within_seconds(make_tree):
+    """changed_within keeps files modified in the last N seconds."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        old = make_tree(tmpdir, FILES) / "old.txt"
+        os.utime(old, (time.time() - 7200,) * 2)  # two hours ago
+
+        results = list(vexy_glob.find("*.txt", root=tmpdir, changed_within=3600))
+        assert [Path(r).name for r in results] == ["recent.txt"]
+
+
+def test_changed_before_duration_string(make_tree):
+    """changed_before keeps files modified more than N ago."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        old = make_tree(tmpdir, FILES) / "old.txt"
+        os.utime(old, (time.time() - 7200,) * 2)  # two hours ago
+
+        results = list(vexy_glob.find("*.txt", root=tmpdir, changed_before="1h"))
+        assert [Path(r).name for r in results] == ["old.txt"]
+
+
+def test_changed_within_combines_with_absolute_bounds(make_tree):
+    """The stricter of the absolute and relative bounds wins."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        old = make_tree(tmpdir, FILES) / "old.txt"
+        os.utime(old, (time.time() - 7200,) * 2)  # two hours ago
+
+        # changed_within alone would include old.txt; mtime_after excludes it
+        results = list(vexy_glob.find(
+            "*.txt", root=tmpdir, changed_within="1d", mtime_after=time.time() - 60
+        ))
+        assert [Path(r).name for r in results] == ["recent.txt"]
+
+
+def test_changed_within_content_search(make_tree):
+    """Relative windows also apply to content search."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        old = make_tree(tmpdir, FILES) / "old.txt"
+        os.utime(old, (time.time() - 7200,) * 2)  # two hours ago
+
+        results = list(vexy_glob.search("old|recent", "*.txt", root=tmpdir, changed_within="30m"))
+        assert [Path(r["path"]).name for r in results] == ["recent.txt"]
+
+
+def test_invalid_duration():
+    """Malformed durations raise ValueError."""
+    with pytest.raises(ValueError, match="Invalid duration format"):
+        vexy_glob.find(changed_within="soon")
@@ -0,0 +1,60 @@
+# this_file: tests/test_char_classes.py
+"""Test character ranges, negated sets and POSIX classes in globs."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def make_files(tmpdir, names):
+    for name in names:
+        Path(tmpdir, name).write_text("x")
+
+
+def names(results):
+    return sorted(Path(p).name for p in results)
+
+
+def test_character_ranges():
+    """[a-z] matches one character from the range."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_files(tmpdir, ["abc.txt", "Xyz.txt", "1st.txt"])
+
+        assert names(vexy_glob.find("[a-z]*.txt", root=tmpdir, case_sensitive=True)) == ["abc.txt"]
+        assert names(vexy_glob.find("[a-zA-Z]*.txt", root=tmpdir, case_sensitive=True)) == ["Xyz.txt", "abc.txt"]
+
+
+def test_negated_sets():
+    """[!0-9] and [^0-9] match any character outside the set."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_files(tmpdir, ["abc.txt", "1st.txt"])
+
+        assert names(vexy_glob.find("[!0-9]*.txt", root=tmpdir)) == ["abc.txt"]
+        assert names(vexy_glob.find("[^0-9]*.txt", root=tmpdir)) == ["abc.txt"]
+
+
+def test_posix_classes():
+    """POSIX classes match like their ASCII ranges."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_files(tmpdir, ["2024.log", "app.log", "_tmp.log", "Main.log"])
+
+        assert names(vexy_glob.find("[[:digit:]]*.log", root=tmpdir)) == ["2024.log"]
+        assert names(vexy_glob.find("[[:upper:]]*.log", root=tmpdir, case_sensitive=True)) == ["Main.log"]
+        assert names(vexy_glob.find("[![:alnum:]]*.log", root=tmpdir)) == ["_tmp.log"]
+        assert names(vexy_glob.find("[[:alpha:]_]*.log", root=tmpdir)) == ["Main.log", "_tmp.log", "app.log"]
+
+
+def test_posix_classes_in_excludes():
+    """Excludes understand POSIX classes too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_files(tmpdir, ["2024.log", "app.log"])
+
+        assert names(vexy_glob.find("*.log", root=tmpdir, exclude="[[:digit:]]*")) == ["app.log"]
+
+
+def test_unknown_posix_class():
+    """A misspelled class name is an error rather than a silent non-match."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.PatternError):
+            list(vexy_glob.find("[[:digits:]]*.log", root=tmpdir))
@@ -0,0 +1,72 @@
+# this_file: tests/test_classify.py
+"""Test tagging results with the first matching named glob group."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+GROUPS = {"tests": "**/test_*.py", "src": "src/**", "docs": "*.md"}
+
+
+TREE = ["src/app.py", "src/test_app.py", "tests/test_util.py", "README.md", "setup.cfg"]
+
+
+def _groups(results, tmpdir):
+    return {Path(r["path"]).relative_to(tmpdir).as_posix(): r["group"] for r in results}
+
+
+def test_first_matching_group_wins(make_tree):
+    """Each result names the first group, in dict order, whose glob matches."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        results = vexy_glob.find("**/*", root=tmpdir, file_type="f", classify=GROUPS, as_list=True)
+        assert _groups(results, tmpdir) == {
+            "src/app.py": "src",
+            "src/test_app.py": "tests",
+            "tests/test_util.py": "tests",
+            "README.md": "docs",
+            "setup.cfg": None,
+        }
+
+
+def test_group_order_matters(make_tree):
+    """Reordering the groups changes which one claims an overlapping file."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        groups = {"src": "src/**", "tests": "**/test_*.py"}
+        results = vexy_glob.find("**/*.py", root=tmpdir, classify=groups, as_list=True)
+        assert _groups(results, tmpdir)["src/test_app.py"] == "src"
+
+
+def test_exclude_unclassified(make_tree):
+    """Entries matching no group are left out on request."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        results = list(vexy_glob.find("**/*", root=tmpdir, classify=GROUPS, exclude_unclassified=True))
+        assert "setup.cfg" not in _groups(results, tmpdir)
+        assert None not in _groups(results, tmpdir).values()
+        assert len(results) == 4
+
+
+def test_combines_with_other_fields(make_tree):
+    """The group sits alongside other requested fields."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        [result] = vexy_glob.find("*.md", root=tmpdir, classify=GROUPS, with_depth=True, as_list=True)
+        assert result == {"path": os.path.join(tmpdir, "README.md"), "depth": 1, "group": "docs"}
+
+
+def test_invalid_options(make_tree):
+    """Bad globs and conflicting options are rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        with pytest.raises(vexy_glob.PatternError):
+            vexy_glob.find("**/*", root=tmpdir, classify={"bad": "src/[a"}, as_list=True)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("**/*", root=tmpdir, classify=GROUPS, resolve_owner=True, as_list=True)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("**/*", root=tmpdir, exclude_unclassified=True, as_list=True)
+        with pytest.raises(ValueError):
+            vexy_glob.find("**/*", root=tmpdir, content="x", classify=GROUPS)
@@ -0,0 +1,65 @@
+# this_file: tests/test_compiled_pattern.py
+"""Test reusable compiled patterns."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = ["a.py", "b.txt", "sub/c.py", "sub/Test_d.py"]
+
+
+def test_compiled_pattern_reused_across_roots(make_tree):
+    """One compiled pattern can search several roots."""
+    with tempfile.TemporaryDirectory() as first, tempfile.TemporaryDirectory() as second:
+        make_tree(first, TREE, "content")
+        Path(second, "e.py").write_text("content")
+
+        compiled = vexy_glob.compile("*.py")
+        assert compiled.pattern == "*.py"
+
+        results = sorted(Path(p).name for p in compiled.find([first], file_type="f"))
+        assert results == ["Test_d.py", "a.py", "c.py"]
+
+        results = [Path(p).name for p in compiled.find([second], file_type="f", yield_results=False)]
+        assert results == ["e.py"]
+
+
+def test_compiled_pattern_matches_find(make_tree):
+    """CompiledPattern.find agrees with the string-based find()."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        compiled = vexy_glob.compile("*.py", regex="sub", regex_target="path")
+        expected = sorted(vexy_glob.find("*.py", root=tmpdir, regex="sub"))
+        assert sorted(compiled.find([tmpdir])) == expected
+        assert len(expected) == 2
+
+
+def test_compiled_pattern_smart_case(make_tree):
+    """Smart case is resolved when compiling."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        assert len(list(vexy_glob.compile("test_*.py").find([tmpdir]))) == 1
+        assert list(vexy_glob.compile("Test_*.PY").find([tmpdir])) == []
+        assert len(list(vexy_glob.compile("TEST_*.py", case_sensitive=False).find([tmpdir]))) == 1
+
+
+def test_compiled_pattern_as_path_and_sort(make_tree):
+    """Output options of find() are available on the compiled form."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = vexy_glob.compile("*.py").find([tmpdir], as_path_objects=True, sort="name")
+        assert all(isinstance(p, Path) for p in results)
+        assert [p.name for p in results] == ["Test_d.py", "a.py", "c.py"]
+
+
+def test_compiled_pattern_invalid():
+    """Invalid patterns fail at compile time."""
+    with pytest.raises(vexy_glob.PatternError):
+        vexy_glob.compile("[invalid")
+    with pytest.raises(vexy_glob.PatternError):
+        vexy_glob.compile(None, regex="(unclosed")
@@ -0,0 +1,122 @@
+# this_file: tests/test_content_literals.py
+"""Test literal-set content search."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_content_literals_reports_matched_literal():
+    """Each result lists the literals found on the line."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("alpha beta\ngamma\ndelta.epsilon\n")
+        Path(tmpdir, "b.txt").write_text("nothing here\n")
+
+        results = vexy_glob.find(
+            "*.txt", root=tmpdir, content_literals=["beta", "delta.epsilon", "zeta"], as_list=True
+        )
+        assert sorted((r["line_number"], r["matches"]) for r in results) == [
+            (1, ["beta"]),
+            (3, ["delta.epsilon"]),
+        ]
+
+
+def test_content_literals_are_not_regex():
+    """Regex metacharacters in literals match literally."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("price: $5.00\nprice: 5x00\n")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, content_literals=["5.00"], as_list=True)
+        assert [r["line_number"] for r in results] == [1]
+
+
+def test_content_literals_large_set():
+    """Thousands of literals are handled without building a regex."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("id-04242 found\nid-99999 missing\n")
+        literals = [f"id-{i:05d}" for i in range(10000) if i != 99999]
+
+        results = vexy_glob.find("*.txt", root=tmpdir, content_literals=literals, as_list=True)
+        assert [r["matches"] for r in results] == [["id-04242"]]
+
+
+def test_content_literals_smart_case():
+    """Lowercase literals match case-insensitively, like the content regex."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("TODO: one\ntodo: two\n")
+
+        assert len(vexy_glob.find("*.txt", root=tmpdir, content_literals=["todo"], as_list=True)) == 2
+        assert len(vexy_glob.find("*.txt", root=tmpdir, content_literals=["TODO"], as_list=True)) == 1
+
+
+def test_content_literals_exclusive_with_content():
+    """content and content_literals cannot be combined."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.find("*.txt", root=tmpdir, content="a", content_literals=["b"])
+
+
+def test_pattern_histogram_counts_each_literal():
+    """pattern_histogram totals the matches of every literal across files."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.py").write_text("old_api(); old_api()\nlegacy_call()\n")
+        Path(tmpdir, "b.py").write_text("old_api()\n")
+
+        histogram = vexy_glob.search(
+            None, "*.py", root=tmpdir,
+            content_literals=["old_api", "legacy_call", "unused"], pattern_histogram=True,
+        )
+        assert histogram == {"old_api": 3, "legacy_call": 1, "unused": 0}
+        assert list(histogram) == ["old_api", "legacy_call", "unused"]
+
+
+def test_pattern_histogram_ignores_case_with_lowercase_literals():
+    """Case-insensitive matches are attributed to the literal they spell."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("TODO todo Fixme\n")
+
+        histogram = vexy_glob.search(None, "*.txt", root=tmpdir, content_literals=["todo", "fixme"], pattern_histogram=True)
+        assert histogram == {"todo": 2, "fixme": 1}
+
+
+def test_pattern_histogram_with_regex():
+    """A regex is a single pattern counting all of its matches."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("x1 x2\nx3\n")
+
+        assert vexy_glob.search(r"x\d", "*.txt", root=tmpdir, pattern_histogram=True) == {r"x\d": 3}
+
+
+def test_pattern_histogram_counts_repeats_with_unique_matches():
+    """unique_matches only shortens the per-line lists; the histogram still counts every match."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.py").write_text("old_api(); old_api()\nold_api()\n")
+
+        histogram = vexy_glob.search(
+            None, "*.py", root=tmpdir,
+            content_literals=["old_api"], unique_matches=True, pattern_histogram=True,
+        )
+        assert histogram == {"old_api": 3}
+        assert vexy_glob.search(r"old_\w+", "*.py", root=tmpdir, unique_matches=True, pattern_histogram=True) == {r"old_\w+": 3}
+
+
+def test_replace_uses_the_longest_literal():
+    """The replacement rewrites the same, longest match that is reported."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("foobar here\n")
+
+        [result] = vexy_glob.search(None, "*.txt", root=tmpdir, content_literals=["foo", "foobar"], replace="X", as_list=True)
+        assert result["matches"] == ["foobar"]
+        assert result["replacement"] == "X here\n"
+
+
+def test_non_ascii_literals_ignore_case():
+    """Case-insensitive literals fold non-ASCII letters, like the content regex and replace."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("ÉCOLE ouverte\nécole fermée\n")
+
+        results = vexy_glob.search(None, "*.txt", root=tmpdir, content_literals=["école"], replace="X", as_list=True)
+        assert [r["replacement"] for r in results] == ["X ouverte\n", "X fermée\n"]
+        histogram = vexy_glob.search(None, "*.txt", root=tmpdir, content_literals=["école"], pattern_histogram=True)
+        assert histogram == {"école": 2}
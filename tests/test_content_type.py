@@ -0,0 +1,51 @@
+# this_file: tests/test_content_type.py
+"""Test text vs binary content classification."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+FILES = {
+    "notes.txt": "plain text\n",
+    "script.py": "print('hi')\n",
+    "image.bin": b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR",
+    # NUL beyond the sniffed prefix does not make a file binary
+    "late_nul.txt": b"a" * 10000 + b"\x00",
+    "subdir/": "",
+}
+
+
+def test_content_type_text(make_tree):
+    """Only files without NUL bytes in their prefix are returned."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        results = list(vexy_glob.find(root=tmpdir, content_type="text"))
+        names = sorted(Path(r).name for r in results)
+        assert names == ["late_nul.txt", "notes.txt", "script.py"]
+
+
+def test_content_type_binary(make_tree):
+    """Binary classification excludes text files and directories."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        results = list(vexy_glob.find(root=tmpdir, content_type="binary"))
+        assert [Path(r).name for r in results] == ["image.bin"]
+
+
+def test_empty_file_is_text():
+    """Empty files contain no NUL and count as text."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "empty").write_bytes(b"")
+
+        results = list(vexy_glob.find(root=tmpdir, content_type="text"))
+        assert [Path(r).name for r in results] == ["empty"]
+
+
+def test_invalid_content_type():
+    """Unknown content types are rejected."""
+    with pytest.raises(vexy_glob.VexyGlobError):
+        vexy_glob.find(content_type="image")
@@ -0,0 +1,58 @@
+# this_file: tests/test_count_by_extension.py
+"""Test counting matching entries grouped by extension."""
+
+import tempfile
+import pytest
+import vexy_glob
+
+
+TREE = ["a.py", "b.py", "sub/c.py", "sub/d.md", "Makefile", "sub/e.PY", "archive.tar.gz", "pkg.d/"]
+
+
+def test_counts_by_extension(make_tree):
+    """Counts match what find() returns, keyed like the extension filter."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        counts = vexy_glob.count_by_extension(root=tmpdir)
+        assert counts == {"py": 3, "": 1, "PY": 1, "gz": 1, "md": 1}
+        # Most common first, ties broken by extension
+        assert list(counts) == ["py", "", "PY", "gz", "md"]
+
+
+def test_directories_not_counted(make_tree):
+    """Directories, even ones with a dot in their name, are left out."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        counts = vexy_glob.count_by_extension(root=tmpdir)
+        assert "d" not in counts
+        assert sum(counts.values()) == len(vexy_glob.find("**/*", root=tmpdir, file_type="f", as_list=True))
+
+
+def test_shares_find_filters(make_tree):
+    """Patterns and filters narrow the counts as they narrow find()."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        assert vexy_glob.count_by_extension("sub/*", root=tmpdir) == {"PY": 1, "md": 1, "py": 1}
+        assert vexy_glob.count_by_extension(root=tmpdir, exclude=["**/sub/**"]) == {"py": 2, "": 1, "gz": 1}
+        assert vexy_glob.count_by_extension("*.rs", root=tmpdir) == {}
+
+
+def test_with_stats(make_tree):
+    """With stats the counts come back with the scan statistics."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        counts, stats = vexy_glob.count_by_extension(root=tmpdir, with_stats=True)
+        assert counts["py"] == 3
+        assert stats["entries_visited"] > 0
+
+
+def test_incompatible_options(make_tree):
+    """Options that reshape results or search content are rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        with pytest.raises(ValueError):
+            vexy_glob.find(root=tmpdir, content="x", count_by_extension=True)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.count_by_extension(root=tmpdir, total_size=True)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.count_by_extension(root=tmpdir, group_by_dir=True)
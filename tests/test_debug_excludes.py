@@ -0,0 +1,90 @@
+# this_file: tests/test_debug_excludes.py
+"""Test explaining why matching paths were left out of find results."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def _by_name(diagnostics):
+    return {Path(d["path"]).name: d for d in diagnostics}
+
+
+def test_reports_gitignore_line():
+    """A gitignored file names the .gitignore and the rule that matched."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, ".git").mkdir()
+        Path(tmpdir, ".gitignore").write_text("# build output\n*.log\n")
+        Path(tmpdir, "app.log").write_text("x")
+        Path(tmpdir, "app.txt").write_text("x")
+
+        diagnostics = _by_name(vexy_glob.find("app.*", root=tmpdir, debug_excludes=True))
+        assert set(diagnostics) == {"app.log"}
+        assert diagnostics["app.log"]["excluded_by"] == "ignore_file"
+        assert diagnostics["app.log"]["source"] == str(Path(tmpdir, ".gitignore"))
+        assert diagnostics["app.log"]["pattern"] == "*.log"
+
+
+def test_reports_ignored_parent_directory():
+    """Files below an ignored directory are attributed to the directory rule."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, ".ignore").write_text("vendor/\n")
+        Path(tmpdir, "vendor", "lib").mkdir(parents=True)
+        Path(tmpdir, "vendor", "lib", "a.py").write_text("x")
+
+        diagnostics = _by_name(vexy_glob.find("*.py", root=tmpdir, debug_excludes=True))
+        assert diagnostics["a.py"]["excluded_by"] == "ignore_file"
+        assert diagnostics["a.py"]["pattern"] == "vendor/"
+
+
+def test_reports_hidden_exclude_and_filter():
+    """Hidden paths, exclude patterns and other filters each get their own reason."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, ".cache").mkdir()
+        Path(tmpdir, ".cache", "a.txt").write_text("x")
+        Path(tmpdir, "b.txt").write_text("x")
+        Path(tmpdir, "c.txt").write_text("x")
+        Path(tmpdir, "d.txt").write_text("x" * 100)
+        Path(tmpdir, "e.txt").write_text("x")
+
+        diagnostics = _by_name(
+            vexy_glob.find(
+                "*.txt",
+                root=tmpdir,
+                exclude="**/b.txt",
+                exclude_name="c.*",
+                max_size=10,
+                debug_excludes=True,
+            )
+        )
+        assert diagnostics["a.txt"]["excluded_by"] == "hidden"
+        assert diagnostics["a.txt"]["pattern"] == ".cache"
+        assert diagnostics["b.txt"]["excluded_by"] == "exclude"
+        assert diagnostics["b.txt"]["pattern"] == "**/b.txt"
+        assert diagnostics["c.txt"]["excluded_by"] == "exclude_name"
+        assert diagnostics["c.txt"]["pattern"] == "c.*"
+        assert diagnostics["d.txt"]["excluded_by"] == "filter"
+        assert diagnostics["d.txt"]["source"] is None
+        assert "e.txt" not in diagnostics
+
+
+def test_whitelisted_file_is_not_reported():
+    """A "!" rule re-including a file keeps it out of the diagnostics."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, ".ignore").write_text("*.log\n!keep.log\n")
+        Path(tmpdir, "keep.log").write_text("x")
+        Path(tmpdir, "drop.log").write_text("x")
+
+        diagnostics = _by_name(vexy_glob.find("*.log", root=tmpdir, debug_excludes=True))
+        assert set(diagnostics) == {"drop.log"}
+
+
+def test_debug_excludes_rejects_reshaped_results():
+    """Options that change the result shape cannot be combined."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.find("*", root=tmpdir, debug_excludes=True, as_path=True)
+        with pytest.raises(ValueError):
+            vexy_glob.find("*", root=tmpdir, debug_excludes=True, content="x")
@@ -0,0 +1,66 @@
+# this_file: tests/test_dedupe_content.py
+"""Test grouping files with identical contents."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_dedupe_content_groups_duplicates():
+    """Only groups of two or more identical files are returned."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sub").mkdir()
+        Path(tmpdir, "a.txt").write_text("same content\n")
+        Path(tmpdir, "sub", "b.txt").write_text("same content\n")
+        # Same size, different content
+        Path(tmpdir, "c.txt").write_text("diff content\n")
+        Path(tmpdir, "unique.txt").write_text("only one of these\n")
+        Path(tmpdir, "empty1.txt").write_text("")
+        Path(tmpdir, "empty2.txt").write_text("")
+
+        groups = vexy_glob.find("**/*.txt", root=tmpdir, sort="path", dedupe_content=True)
+        assert isinstance(groups, dict)
+        found = sorted(sorted(Path(p).name for p in paths) for paths in groups.values())
+        assert found == [["a.txt", "b.txt"], ["empty1.txt", "empty2.txt"]]
+        assert all(len(key) == 16 for key in groups)
+
+
+def test_dedupe_content_ignores_directories():
+    """Directories never form duplicate groups."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "d1").mkdir()
+        Path(tmpdir, "d2").mkdir()
+
+        assert vexy_glob.find("*", root=tmpdir, dedupe_content=True) == {}
+
+
+def test_dedupe_content_conflicts_with_group_by_dir():
+    """dedupe_content cannot be combined with other collected shapes."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, dedupe_content=True, group_by_dir=True)
+
+
+def test_dedupe_content_skips_unfollowed_symlinks():
+    """An unfollowed link is not a duplicate of the file it points to."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a").write_text("content\n")
+        Path(tmpdir, "link").symlink_to("a")
+
+        assert vexy_glob.find("*", root=tmpdir, dedupe_content=True) == {}
+        assert vexy_glob.find("*", root=tmpdir, follow_symlinks=True, dedupe_content=True) == {}
+
+
+def test_dedupe_content_collapses_hard_links():
+    """Hard links share one file, so only real copies form a group."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a").write_text("content\n")
+        os.link(Path(tmpdir, "a"), Path(tmpdir, "b"))
+
+        assert vexy_glob.find("*", root=tmpdir, dedupe_content=True) == {}
+
+        Path(tmpdir, "c").write_text("content\n")
+        groups = vexy_glob.find("*", root=tmpdir, sort="name", dedupe_content=True)
+        assert [[Path(p).name for p in paths] for paths in groups.values()] == [["a", "c"]]
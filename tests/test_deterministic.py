@@ -0,0 +1,45 @@
+# this_file: tests/test_deterministic.py
+"""Test reproducible result order for content search."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = [f"{d}/{name}" for d in ["b", "a", "c/z", "c/y"] for name in ["2.txt", "10.txt", "1.txt"]]
+
+
+def test_results_follow_sorted_walk_order(make_tree):
+    """Files come out in sorted name order, matches in line order within each."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle one\nhay\nneedle two\n")
+
+        results = vexy_glob.search("needle", "*.txt", root=tmpdir, deterministic=True, as_list=True)
+        paths = [Path(r["path"]).relative_to(tmpdir).as_posix() for r in results]
+        expected = []
+        for d in ["a", "b", "c/y", "c/z"]:
+            for name in ["1.txt", "10.txt", "2.txt"]:
+                expected += [f"{d}/{name}"] * 2
+        assert paths == expected
+        assert [r["line_number"] for r in results[:2]] == [1, 3]
+
+
+def test_repeated_searches_are_identical(make_tree):
+    """Streaming the same search twice yields the same sequence."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle one\nhay\nneedle two\n")
+
+        first = [(r["path"], r["line_number"]) for r in vexy_glob.search("needle", root=tmpdir, deterministic=True)]
+        second = [(r["path"], r["line_number"]) for r in vexy_glob.search("needle", root=tmpdir, deterministic=True)]
+        assert first == second
+        assert len(first) == 24
+
+
+def test_file_list_keeps_given_order(make_tree):
+    """An explicit file list is searched in the order it was given."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle one\nhay\nneedle two\n")
+        files = [Path(tmpdir, "c", "z", "2.txt"), Path(tmpdir, "a", "1.txt"), Path(tmpdir, "b", "10.txt")]
+
+        results = vexy_glob.search("needle", root=tmpdir, file_list=files, deterministic=True, as_list=True)
+        assert [r["path"] for r in results[::2]] == [str(f) for f in files]
@@ -0,0 +1,78 @@
+# this_file: tests/test_dir_events.py
+"""Test directory enter/leave events in the result stream."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = ["a/one.py", "a/b/two.py", "c/", "top.txt"]
+
+
+def _describe(results, root):
+    out = []
+    for item in results:
+        if isinstance(item, vexy_glob.DirEvent):
+            out.append((item.kind, str(Path(item.path).relative_to(root))))
+        else:
+            out.append(("match", str(Path(item).relative_to(root))))
+    return out
+
+
+def test_events_bracket_directory_contents(make_tree):
+    """Every walked directory is entered before and left after its contents."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.find("*.py", root=tmpdir, emit_dir_events=True)
+        assert _describe(results, tmpdir) == [
+            ("enter", "."),
+            ("enter", "a"),
+            ("enter", "a/b"),
+            ("match", "a/b/two.py"),
+            ("leave", "a/b"),
+            ("match", "a/one.py"),
+            ("leave", "a"),
+            ("enter", "c"),
+            ("leave", "c"),
+            ("leave", "."),
+        ]
+
+
+def test_events_with_post_order(make_tree):
+    """A post-order directory result follows its own leave event."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.find(
+            "a*", root=tmpdir, file_type="d", dir_order="post", emit_dir_events=True
+        )
+        described = _describe(results, tmpdir)
+        assert described.index(("leave", "a")) < described.index(("match", "a"))
+        assert described[-1] == ("leave", ".")
+
+
+def test_event_objects(make_tree):
+    """Events expose kind and path and work as paths."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        events = [
+            item
+            for item in vexy_glob.find("*", root=tmpdir, emit_dir_events=True)
+            if isinstance(item, vexy_glob.DirEvent)
+        ]
+        assert {event.kind for event in events} == {"enter", "leave"}
+        assert len(events) == 8
+        assert Path(events[0]) == Path(tmpdir)
+        assert "enter" in repr(events[0])
+
+
+def test_events_need_streaming():
+    """Collected results have nowhere to put the events."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, emit_dir_events=True, as_list=True)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, emit_dir_events=True, sort="name")
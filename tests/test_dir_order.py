@@ -0,0 +1,69 @@
+# this_file: tests/test_dir_order.py
+"""Test pre- and post-order directory emission."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = {"a/one.txt": "1", "a/b/two.txt": "2", "c/three.txt": "3"}
+
+
+def _relative(results, root):
+    return [str(Path(p).relative_to(root)) for p in results if Path(p) != root]
+
+
+def test_pre_order_emits_parents_first(make_tree):
+    """Every directory precedes the entries inside it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        root = make_tree(tmpdir, TREE)
+
+        order = _relative(vexy_glob.find("*", root=tmpdir, dir_order="pre", as_list=True), root)
+        assert order.index("a") < order.index("a/one.txt")
+        assert order.index("a") < order.index("a/b")
+        assert order.index("a/b") < order.index("a/b/two.txt")
+        assert order.index("c") < order.index("c/three.txt")
+
+
+def test_post_order_emits_children_first(make_tree):
+    """Every directory follows the entries inside it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        root = make_tree(tmpdir, TREE)
+
+        results = list(vexy_glob.find("*", root=tmpdir, dir_order="post"))
+        assert results[-1] == tmpdir
+        order = _relative(results, root)
+        assert order.index("a/one.txt") < order.index("a")
+        assert order.index("a/b/two.txt") < order.index("a/b") < order.index("a")
+        assert order.index("c/three.txt") < order.index("c")
+
+
+def test_post_order_allows_bottom_up_removal(make_tree):
+    """Removing entries in post-order never hits a non-empty directory."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        target = Path(tmpdir, "a")
+
+        for path in vexy_glob.find("*", root=target, dir_order="post"):
+            p = Path(path)
+            p.rmdir() if p.is_dir() else p.unlink()
+        assert not target.exists()
+
+
+def test_dir_order_is_deterministic(make_tree):
+    """Repeated walks return the same order."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        first = vexy_glob.find("*", root=tmpdir, dir_order="pre", as_list=True)
+        assert first == vexy_glob.find("*", root=tmpdir, dir_order="pre", as_list=True)
+
+
+def test_dir_order_rejects_sort_and_unknown_values():
+    """dir_order conflicts with sort and only accepts known orders."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, dir_order="post", sort="path")
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, dir_order="sideways")
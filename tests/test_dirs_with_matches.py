@@ -0,0 +1,42 @@
+# this_file: tests/test_dirs_with_matches.py
+"""Test yielding the directories that contain matching entries."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def make_tree(tmpdir):
+    for rel in ["a/x.rs", "a/y.rs", "a/b/z.rs", "c/readme.md", "root.rs"]:
+        path = Path(tmpdir, rel)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text("fn main() {}\n")
+
+
+def test_each_directory_once():
+    """Every directory holding a match is yielded exactly once."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir)
+
+        results = vexy_glob.find("*.rs", root=tmpdir, dirs_with_matches=True, as_list=True)
+        rel = sorted(str(Path(p).relative_to(tmpdir)) for p in results)
+        assert rel == [".", "a", str(Path("a/b"))]
+
+
+def test_dirs_with_matches_as_entries():
+    """Entries describe the directory, not the matched file."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir)
+
+        results = list(vexy_glob.find("*.md", root=tmpdir, dirs_with_matches=True, as_entry=True))
+        assert len(results) == 1
+        assert results[0].name == "c"
+        assert results[0].is_dir and not results[0].is_file
+
+
+def test_dirs_with_matches_rejects_content_search():
+    """There is no directory form of content search results."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.find("*.rs", root=tmpdir, content="main", dirs_with_matches=True)
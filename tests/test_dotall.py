@@ -0,0 +1,36 @@
+# this_file: tests/test_dotall.py
+"""Test the dotall content search flag."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_dotall_spans_newlines():
+    """With dotall, foo.*bar matches across a line break."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sample.txt").write_text("start foo\nmiddle\nbar end\n")
+
+        results = list(vexy_glob.search("foo.*bar", "*.txt", root=tmpdir, dotall=True))
+        assert len(results) == 1
+        assert results[0]["line_number"] == 1
+        assert "foo" in results[0]["line_text"]
+        assert "bar" in results[0]["line_text"]
+
+
+def test_without_dotall_no_cross_line_match():
+    """Without dotall, . does not match a newline."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sample.txt").write_text("start foo\nmiddle\nbar end\n")
+
+        results = list(vexy_glob.search("foo.*bar", "*.txt", root=tmpdir))
+        assert results == []
+
+
+def test_dotall_single_line_matches_unchanged():
+    """Matches within one line are still reported per line."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sample.txt").write_text("one foo bar\ntwo\n")
+
+        results = list(vexy_glob.search("foo", "*.txt", root=tmpdir, dotall=True))
+        assert [r["line_number"] for r in results] == [1]
@@ -0,0 +1,37 @@
+# this_file: tests/test_encoding.py
+"""Test content search in non-UTF-8 files."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+TEXT = "hello\nneedle here ü\nbye\n"
+
+
+def test_utf16_with_bom_is_decoded():
+    """Files with a UTF-16 BOM are transcoded without an explicit encoding."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_bytes(TEXT.encode("utf-16"))
+
+        results = vexy_glob.find("*.txt", root=tmpdir, content="needle", as_list=True)
+        assert len(results) == 1
+        assert results[0]["line_number"] == 2
+        assert results[0]["line_text"].rstrip("\n") == "needle here ü"
+
+
+def test_utf16le_without_bom_needs_encoding():
+    """BOM-less UTF-16LE is only searchable with encoding set."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_bytes(TEXT.encode("utf-16-le"))
+
+        assert vexy_glob.find("*.txt", root=tmpdir, content="needle", as_list=True) == []
+        results = vexy_glob.find("*.txt", root=tmpdir, content="needle", encoding="utf-16le", as_list=True)
+        assert [(r["line_text"].rstrip("\n"), r["matches"]) for r in results] == [("needle here ü", ["needle"])]
+
+
+def test_invalid_encoding():
+    """Unknown encoding labels are rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*.txt", root=tmpdir, content="x", encoding="no-such-encoding")
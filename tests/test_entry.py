@@ -0,0 +1,65 @@
+# this_file: tests/test_entry.py
+"""Test Entry results with metadata cached from the walk."""
+
+import os
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = {"sub/file.txt": "hello"}
+LINKS = {"link.txt": "sub/file.txt"}
+
+
+def test_entry_attributes(make_tree):
+    """Entries expose type, size and mtime captured during the walk."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        entries = {e.name: e for e in vexy_glob.find("*", root=tmpdir, as_entry=True)}
+        file_entry = entries["file.txt"]
+        assert isinstance(file_entry, vexy_glob.Entry)
+        assert file_entry.is_file and not file_entry.is_dir and not file_entry.is_symlink
+        assert file_entry.size == 5
+        assert abs(file_entry.mtime - os.stat(file_entry.path).st_mtime) < 1
+
+        assert entries["sub"].is_dir
+        assert entries["link.txt"].is_symlink
+        assert not entries["link.txt"].is_file
+
+
+def test_entry_is_path_like(make_tree):
+    """Entries work wherever a path is expected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        entry = vexy_glob.find("file.txt", root=tmpdir, as_entry=True, as_list=True)[0]
+        assert os.fspath(entry) == entry.path
+        assert str(entry) == entry.path
+        assert Path(entry).read_text() == "hello"
+        with open(entry) as f:
+            assert f.read() == "hello"
+        assert repr(entry).startswith("<Entry ")
+
+
+def test_entry_with_followed_file_symlinks(make_tree):
+    """A resolved file symlink reports the target's type and size."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        entry = vexy_glob.find("link.txt", root=tmpdir, as_entry=True, as_list=True, follow_file_symlinks=True)[0]
+        assert entry.is_symlink and entry.is_file
+        assert entry.size == 5
+
+
+def test_entry_collection_modes(make_tree):
+    """Sorting and grouping keep Entry objects."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        entries = vexy_glob.find("*", root=tmpdir, as_entry=True, sort="name")
+        assert [e.name for e in entries] == sorted(e.name for e in entries)
+        assert all(isinstance(e, vexy_glob.Entry) for e in entries)
+
+        groups = vexy_glob.find("*.txt", root=tmpdir, as_entry=True, group_by_dir=True)
+        assert all(isinstance(e, vexy_glob.Entry) for group in groups.values() for e in group)
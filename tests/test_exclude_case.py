@@ -0,0 +1,78 @@
+# this_file: tests/test_exclude_case.py
+"""Test case sensitivity control for exclude patterns."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+from vexy_glob import _vexy_glob
+
+
+FILES = ["keep.txt", "DEBUG.LOG", "trace.log"]
+
+
+def test_exclude_case_insensitive_override(make_tree):
+    """case_sensitive_exclude=False matches excludes regardless of case."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        results = list(vexy_glob.find(
+            "*", root=tmpdir, file_type="f", case_sensitive=True,
+            exclude="*.log", case_sensitive_exclude=False,
+        ))
+        assert [Path(r).name for r in results] == ["keep.txt"]
+
+
+def test_exclude_case_sensitive_override(make_tree):
+    """case_sensitive_exclude=True only excludes exact-case matches."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        results = list(vexy_glob.find(
+            "*", root=tmpdir, file_type="f", exclude="*.log", case_sensitive_exclude=True,
+        ))
+        assert sorted(Path(r).name for r in results) == ["DEBUG.LOG", "keep.txt"]
+
+
+def test_exclude_default_follows_glob_on_case_sensitive_fs(make_tree):
+    """By default excludes use the glob's case sensitivity (smart case here)."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+        case_sensitive_fs = not Path(tmpdir, "KEEP.TXT").exists()
+
+        results = list(vexy_glob.find("*", root=tmpdir, file_type="f", exclude="*.log"))
+        # Lowercase "*" pattern is case-insensitive under smart case
+        assert [Path(r).name for r in results] == ["keep.txt"]
+
+        results = list(vexy_glob.find(
+            "*", root=tmpdir, file_type="f", case_sensitive=True, exclude="*.log",
+        ))
+        expected = ["DEBUG.LOG", "keep.txt"] if case_sensitive_fs else ["keep.txt"]
+        assert sorted(Path(r).name for r in results) == expected
+
+
+def test_swapped_case_sibling_keeps_case_sensitive_defaults(make_tree):
+    """A root next to a directory differing only in case is still on a case-sensitive filesystem."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        root = Path(tmpdir, "proj")
+        root.mkdir()
+        if Path(tmpdir, "PROJ").exists():
+            pytest.skip("needs a case-sensitive filesystem")
+        Path(tmpdir, "PROJ").mkdir()
+        make_tree(root, FILES)
+        Path(root, ".gitignore").write_text("*.TXT\n")
+
+        results = vexy_glob.find(
+            "*", root=root, file_type="f", case_sensitive=True, exclude="*.log", require_git=False,
+        )
+        assert sorted(Path(r).name for r in results) == ["DEBUG.LOG", "keep.txt"]
+
+
+def test_empty_roots_are_rejected():
+    """Checking the first root's filesystem needs at least one root."""
+    with pytest.raises(ValueError, match="at least one root path is required"):
+        _vexy_glob.find([], glob="*.txt")
+    with pytest.raises(ValueError, match="at least one root path is required"):
+        _vexy_glob.search("x", [], glob="*.txt")
+    with pytest.raises(ValueError, match="at least one root path is required"):
+        vexy_glob.compile("*.txt").find([])
@@ -270,3 +270,73 @@ def test_complex_exclude_patterns():
         assert "project/src/__pycache__/app.pyc" not in rel_paths
         assert "project/.git/config" not in rel_paths
         assert "project/build/dist/app.whl" not in rel_paths
+
+
+def test_exclude_negation_reincludes():
+    """A "!" pattern re-includes paths excluded by an earlier pattern."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        os.makedirs(Path(tmpdir) / "build")
+        (Path(tmpdir) / "build" / "output.js").write_text("output")
+        (Path(tmpdir) / "build" / "keep.txt").write_text("keep")
+        (Path(tmpdir) / "main.py").write_text("main")
+
+        results = vexy_glob.find(
+            "*", root=tmpdir, file_type="f", exclude=["**/build/**", "!**/build/keep.txt"]
+        )
+        relpaths = sorted(os.path.relpath(p, tmpdir) for p in results)
+        assert relpaths == ["build/keep.txt", "main.py"]
+
+
+def test_exclude_negation_last_match_wins():
+    """Later patterns override earlier ones, in both directions."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for name in ["a.log", "keep.log", "b.txt"]:
+            (Path(tmpdir) / name).write_text("data")
+
+        results = vexy_glob.find("*", root=tmpdir, file_type="f", exclude=["*.log", "!keep.log"])
+        assert sorted(os.path.basename(p) for p in results) == ["b.txt", "keep.log"]
+
+        # Excluding again after the re-include wins
+        results = vexy_glob.find("*", root=tmpdir, file_type="f", exclude=["*.log", "!keep.log", "keep.*"])
+        assert [os.path.basename(p) for p in results] == ["b.txt"]
+
+        # A re-include without an earlier exclude has no effect
+        results = list(vexy_glob.find("*", root=tmpdir, file_type="f", exclude=["!*.txt"]))
+        assert len(results) == 3
+
+
+def test_exclude_literal_bang():
+    """A leading backslash matches file names that start with "!"."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        (Path(tmpdir) / "!important.txt").write_text("data")
+        (Path(tmpdir) / "normal.txt").write_text("data")
+
+        results = vexy_glob.find("*", root=tmpdir, file_type="f", exclude=["\\!*"])
+        assert [os.path.basename(p) for p in results] == ["normal.txt"]
+
+
+def test_exclude_negation_in_content_search():
+    """Re-includes apply to content search as well."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for name in ["a.log", "keep.log"]:
+            (Path(tmpdir) / name).write_text("needle")
+
+        results = vexy_glob.search("needle", root=tmpdir, exclude=["*.log", "!keep.log"], as_list=True)
+        assert [os.path.basename(r["path"]) for r in results] == ["keep.log"]
+
+
+def test_exclude_name_matches_basename_only():
+    """exclude_name matches the entry's own name, not directories above it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        (Path(tmpdir) / "pkg" / "test_utils").mkdir(parents=True)
+        (Path(tmpdir) / "pkg" / "__init__.py").write_text("")
+        (Path(tmpdir) / "pkg" / "test_core.py").write_text("")
+        (Path(tmpdir) / "pkg" / "core.py").write_text("")
+        (Path(tmpdir) / "pkg" / "test_utils" / "helpers.py").write_text("")
+
+        results = vexy_glob.find("**/*.py", root=tmpdir, exclude_name=["__init__.py", "test_*"], as_list=True)
+        assert sorted(os.path.basename(p) for p in results) == ["core.py", "helpers.py"]
+
+        # The path-based exclude also drops files below a matching directory
+        results = vexy_glob.find("**/*.py", root=tmpdir, exclude=["__init__.py", "test_*"], as_list=True)
+        assert sorted(os.path.basename(p) for p in results) == ["core.py"]
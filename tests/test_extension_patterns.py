@@ -0,0 +1,45 @@
+# this_file: tests/test_extension_patterns.py
+"""Test glob patterns inside the extension filter."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def _names(results):
+    return sorted(Path(r).name for r in results)
+
+
+def test_extension_glob_matches_family(make_tree):
+    """c* keeps .c, .cc and .cpp but not other extensions."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, ["a.c", "b.cc", "c.cpp", "d.h", "e.py", "Makefile"])
+
+        results = vexy_glob.find("*", root=tmpdir, extension="c*")
+        assert _names(results) == ["a.c", "b.cc", "c.cpp"]
+
+
+def test_extension_glob_ignores_case(make_tree):
+    """Glob entries match the extension regardless of case."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, ["a.CPP", "b.Hpp", "c.txt"])
+
+        results = vexy_glob.find("*", root=tmpdir, extension=["c*", "h*"])
+        assert _names(results) == ["a.CPP", "b.Hpp"]
+
+
+def test_plain_and_glob_entries_combine(make_tree):
+    """Plain entries still match exactly alongside patterns."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, ["a.c", "b.py", "c.PY", "d.rs"])
+
+        results = vexy_glob.find("*", root=tmpdir, extension=["py", "[c]"])
+        assert _names(results) == ["a.c", "b.py"]
+
+
+def test_invalid_extension_pattern():
+    """A malformed pattern raises PatternError."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.PatternError):
+            vexy_glob.find("*", root=tmpdir, extension="c[")
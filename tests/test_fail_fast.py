@@ -0,0 +1,53 @@
+# this_file: tests/test_fail_fast.py
+"""Test stopping the walk at the first traversal error."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = {"valid.txt": "content"}
+LINKS = {"broken_link": "nonexistent.txt"}
+
+
+def test_fail_fast_raises_while_streaming(make_tree):
+    """The iterator raises the first error and records it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        it = vexy_glob.find("*.txt", root=tmpdir, follow_symlinks=True, fail_fast=True)
+        with pytest.raises(OSError, match="broken_link"):
+            list(it)
+        assert len(it.errors()) == 1
+        # The walk is over once it has failed
+        assert list(it) == []
+
+
+def test_fail_fast_raises_when_collecting(make_tree):
+    """Collected results are discarded in favour of the error."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        with pytest.raises(vexy_glob.SearchError, match="broken_link"):
+            vexy_glob.find("*.txt", root=tmpdir, follow_symlinks=True, fail_fast=True, as_list=True)
+        with pytest.raises(OSError):
+            vexy_glob.find("*.txt", root=tmpdir, follow_symlinks=True, fail_fast=True, sort="path")
+
+
+def test_fail_fast_with_dir_order(make_tree):
+    """The sequential walk used for dir_order fails fast too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        with pytest.raises(OSError):
+            list(vexy_glob.find("*", root=tmpdir, follow_symlinks=True, fail_fast=True, dir_order="post"))
+
+
+def test_fail_fast_clean_scan():
+    """Without errors fail_fast changes nothing."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("content")
+
+        assert len(vexy_glob.find("*.txt", root=tmpdir, fail_fast=True, as_list=True)) == 1
+        assert len(list(vexy_glob.find("*.txt", root=tmpdir, fail_fast=True))) == 1
@@ -0,0 +1,45 @@
+# this_file: tests/test_file_list.py
+"""Test content search over an explicit file list."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+FILES = {name: f"# TODO in {name}\n" for name in ["a.py", "b.py", "c.py"]}
+
+
+def test_file_list_searches_only_listed_files(make_tree):
+    """Only the given files are searched, no directory walk happens."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+        files = [Path(tmpdir, "a.py"), str(Path(tmpdir, "c.py"))]
+
+        results = list(vexy_glob.search("TODO", root=tmpdir, file_list=files))
+        names = sorted(Path(r["path"]).name for r in results)
+        assert names == ["a.py", "c.py"]
+
+
+def test_file_list_ignores_path_filters(make_tree):
+    """Globs and other path filters do not apply to explicit files."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        results = vexy_glob.search(
+            "TODO", "*.rs", root=tmpdir, file_list=[Path(tmpdir, "b.py")], as_list=True
+        )
+        assert [Path(r["path"]).name for r in results] == ["b.py"]
+
+
+def test_file_list_missing_file_is_error_not_abort(make_tree):
+    """Missing files are reported as errors while the rest is still searched."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+        files = [Path(tmpdir, "missing.py"), Path(tmpdir, "a.py")]
+
+        results, stats = vexy_glob.search(
+            "TODO", root=tmpdir, file_list=files, as_list=True, with_stats=True
+        )
+        assert [Path(r["path"]).name for r in results] == ["a.py"]
+        assert stats["errors"] == 1
+        assert stats["entries_visited"] == 2
@@ -0,0 +1,53 @@
+# this_file: tests/test_file_type_sets.py
+"""Test file_type lists and negated file types."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = ["file.txt", "dir/"]
+LINKS = {"link": "file.txt"}
+
+
+def _names(results, tmpdir):
+    return sorted(Path(p).name for p in results if Path(p) != Path(tmpdir))
+
+
+def test_list_of_types_is_ored(make_tree):
+    """A list keeps entries of any listed type."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+        assert _names(vexy_glob.find("*", root=tmpdir, file_type=["f", "l"]), tmpdir) == ["file.txt", "link"]
+        assert _names(vexy_glob.find("*", root=tmpdir, file_type=["d"]), tmpdir) == ["dir"]
+
+
+def test_negated_type(make_tree):
+    """A '!' prefix leaves that type out and keeps everything else."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+        assert _names(vexy_glob.find("*", root=tmpdir, file_type="!d"), tmpdir) == ["file.txt", "link"]
+        assert _names(vexy_glob.find("*", root=tmpdir, file_type=["!d", "!l"]), tmpdir) == ["file.txt"]
+
+
+def test_negation_narrows_listed_types(make_tree):
+    """Exclusions apply on top of listed types."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+        assert _names(vexy_glob.find("*", root=tmpdir, file_type=["f", "l", "!l"]), tmpdir) == ["file.txt"]
+
+
+def test_content_search_accepts_type_list(make_tree):
+    """Content search takes the same file_type forms."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+        results = vexy_glob.search("x", "*", root=tmpdir, file_type=["!l"], as_list=True)
+        assert [Path(r["path"]).name for r in results] == ["file.txt"]
+
+
+def test_invalid_type_rejected():
+    """Unknown types raise instead of being ignored."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            list(vexy_glob.find("*", root=tmpdir, file_type="x"))
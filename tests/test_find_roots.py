@@ -0,0 +1,104 @@
+# this_file: tests/test_find_roots.py
+"""Test walking several roots with their own options."""
+
+import os
+import sys
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = ["backend/app.py", "backend/app.ts", "frontend/ui.ts", "frontend/tool.py"]
+
+
+def test_each_root_uses_its_own_pattern(make_tree):
+    """Results are merged root by root, each filtered by its own options."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        backend = Path(tmpdir, "backend")
+        frontend = Path(tmpdir, "frontend")
+
+        results = vexy_glob.find_roots(
+            [(backend, {"pattern": "*.py"}), (frontend, {"pattern": "*.ts"})],
+            as_list=True,
+        )
+        assert [Path(r).relative_to(tmpdir).as_posix() for r in results] == [
+            "backend/app.py",
+            "frontend/ui.ts",
+        ]
+
+
+def test_shared_kwargs_and_overrides(make_tree):
+    """Shared options apply to every root unless a root overrides them."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = list(
+            vexy_glob.find_roots(
+                [(Path(tmpdir, "backend"), {}), (Path(tmpdir, "frontend"), {"extension": "py"})],
+                extension="ts",
+            )
+        )
+        assert [Path(r).name for r in results] == ["app.ts", "tool.py"]
+
+
+def test_reshaping_options_rejected():
+    """Options that change the result shape cannot be merged."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.find_roots([(tmpdir, {"group_by_dir": True})])
+        for option in ({"top_k": 3}, {"report_oversized": 10}, {"emit_dir_events": True}):
+            with pytest.raises(ValueError):
+                vexy_glob.find_roots([(tmpdir, option)])
+        with pytest.raises(ValueError):
+            vexy_glob.find_roots([(tmpdir, {})], with_stats=True, sort="name")
+
+
+def test_errors_of_every_root_are_kept(make_tree):
+    """errors() reports the failures of each root's walk."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        Path(tmpdir, "frontend", "broken_link").symlink_to(Path(tmpdir, "missing.ts"))
+
+        it = vexy_glob.find_roots(
+            [(Path(tmpdir, "backend"), {"pattern": "*.py"}), (Path(tmpdir, "frontend"), {"pattern": "*.ts"})],
+            follow_symlinks=True,
+        )
+        assert [Path(r).name for r in it] == ["app.py", "ui.ts"]
+        assert len(it.errors()) == 1
+        assert "broken_link" in it.errors()[0]
+
+
+@pytest.mark.skipif(sys.platform == "win32" or os.geteuid() == 0, reason="needs POSIX permissions that root bypasses")
+def test_unreadable_directory_under_one_root_is_reported(make_tree):
+    """A directory one root cannot read shows up in errors() instead of vanishing."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        locked = Path(tmpdir, "frontend", "locked")
+        locked.mkdir()
+        locked.chmod(0)
+        try:
+            it = vexy_glob.find_roots([(Path(tmpdir, "backend"), {}), (Path(tmpdir, "frontend"), {})], pattern="*.ts")
+            assert sorted(Path(r).name for r in it) == ["app.ts", "ui.ts"]
+            assert len(it.errors()) == 1
+            assert "locked" in it.errors()[0]
+        finally:
+            locked.chmod(0o755)
+
+
+def test_stats_are_summed_across_roots(make_tree):
+    """with_stats sums every root's counters once all walks are exhausted."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        roots = [(Path(tmpdir, "backend"), {"pattern": "*.py"}), (Path(tmpdir, "frontend"), {"pattern": "*.ts"})]
+
+        it = vexy_glob.find_roots(roots, with_stats=True)
+        assert it.stats is None
+        assert len(list(it)) == 2
+        assert it.stats["matched"] == 2
+        assert it.stats["entries_visited"] == 6
+
+        results, stats = vexy_glob.find_roots(roots, with_stats=True, as_list=True)
+        assert len(results) == 2
+        assert stats["matched"] == 2
@@ -0,0 +1,78 @@
+# this_file: tests/test_full_path.py
+"""Test basename vs relative-path glob matching."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = ["a.txt", "notes.txt/readme.md", "sub/b.txt", "sub/deep/c.txt"]
+
+
+def test_basename_matching_is_default(make_tree):
+    """Patterns without a separator match the file name only."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = list(vexy_glob.find("*.txt", root=tmpdir, file_type="f"))
+        names = sorted(Path(r).name for r in results)
+        # readme.md lives in a directory called notes.txt but must not match
+        assert names == ["a.txt", "b.txt", "c.txt"]
+
+
+def test_full_path_matches_relative_path(make_tree):
+    """With full_path=True the pattern is matched against the relative path."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = list(vexy_glob.find("*.txt", root=tmpdir, file_type="f", full_path=True))
+        assert [Path(r).name for r in results] == ["a.txt"]
+
+        results = list(vexy_glob.find("sub/*.txt", root=tmpdir, full_path=True))
+        assert [Path(r).name for r in results] == ["b.txt"]
+
+        results = list(vexy_glob.find("sub/**/*.txt", root=tmpdir, full_path=True))
+        assert sorted(Path(r).name for r in results) == ["b.txt", "c.txt"]
+
+
+def test_full_path_literal_pattern(make_tree):
+    """Literal patterns compare the whole relative path in full_path mode."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = list(vexy_glob.find("sub/b.txt", root=tmpdir, full_path=True))
+        assert len(results) == 1
+
+        results = list(vexy_glob.find("b.txt", root=tmpdir, full_path=True))
+        assert results == []
+
+
+def test_full_path_with_content_search(make_tree):
+    """full_path also applies to the file filter of content search."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = list(vexy_glob.search("content", "sub/*.txt", root=tmpdir, full_path=True))
+        assert len(results) == 1
+        assert Path(results[0]["path"]).name == "b.txt"
+
+
+def test_full_path_is_relative_to_each_root():
+    """With several roots the pattern applies below each root separately."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        first = Path(tmpdir, "src")
+        second = Path(tmpdir, "other")
+        for rel in [first / "src/a.rs", first / "lib.rs", second / "src/b.rs", second / "nested/src/c.rs"]:
+            rel.parent.mkdir(parents=True, exist_ok=True)
+            rel.write_text("content")
+
+        compiled = vexy_glob.compile("src/*.rs", full_path=True)
+        results = sorted(Path(p).name for p in compiled.find([str(first), str(second)]))
+        # first's own name "src" is part of the root, not of the relative path
+        assert results == ["a.rs", "b.rs"]
+
+        # Matched against the whole path, "src/" can also match in a root's own
+        # name or deeper down
+        compiled = vexy_glob.compile("**/src/*.rs")
+        results = sorted(Path(p).name for p in compiled.find([str(first), str(second)]))
+        assert results == ["a.rs", "b.rs", "c.rs", "lib.rs"]
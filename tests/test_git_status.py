@@ -0,0 +1,78 @@
+# this_file: tests/test_git_status.py
+"""Test restricting the walk to files in given git status categories."""
+
+import subprocess
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def _git(tmpdir, *args):
+    subprocess.run(
+        ["git", "-C", tmpdir, "-c", "user.name=t", "-c", "user.email=t@t", *args],
+        check=True,
+        capture_output=True,
+    )
+
+
+def _make_repo(make_tree, tmpdir):
+    _git(tmpdir, "init", "-q")
+    make_tree(tmpdir, ["src/clean.py", "src/modified.py", "src/staged.py"], "needle\n")
+    make_tree(tmpdir, {".gitignore": "ignored.py\n"})
+    _git(tmpdir, "add", ".")
+    _git(tmpdir, "commit", "-q", "-m", "init")
+    make_tree(tmpdir, {"src/modified.py": "needle changed\n", "src/staged.py": "needle staged\n"})
+    _git(tmpdir, "add", "src/staged.py")
+    make_tree(tmpdir, ["src/new.py", "ignored.py"], "needle\n")
+
+
+def _found(results, tmpdir):
+    return sorted(Path(p).relative_to(tmpdir).as_posix() for p in results)
+
+
+def test_each_category(make_tree):
+    """Each category selects the files git reports in it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_repo(make_tree, tmpdir)
+
+        for category, expected in [
+            ("modified", ["src/modified.py"]),
+            ("staged", ["src/staged.py"]),
+            ("untracked", ["src/new.py"]),
+        ]:
+            results = vexy_glob.find("**/*.py", root=tmpdir, git_status=category, as_list=True)
+            assert _found(results, tmpdir) == expected
+
+
+def test_categories_combine(make_tree):
+    """Several categories select the union, and search honors them too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_repo(make_tree, tmpdir)
+
+        results = vexy_glob.find("**/*.py", root=tmpdir, git_status=["modified", "staged"], as_list=True)
+        assert _found(results, tmpdir) == ["src/modified.py", "src/staged.py"]
+
+        results = vexy_glob.search("needle", "*.py", root=tmpdir, git_status=["untracked", "staged"], as_list=True)
+        assert _found((r["path"] for r in results), tmpdir) == ["src/new.py", "src/staged.py"]
+
+
+def test_with_git_tracked_only(make_tree):
+    """Both filters must hold, so untracked files drop out."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_repo(make_tree, tmpdir)
+
+        results = vexy_glob.find(
+            "**/*.py", root=tmpdir, git_status=["staged", "untracked"], git_tracked_only=True, as_list=True
+        )
+        assert _found(results, tmpdir) == ["src/staged.py"]
+
+
+def test_errors(make_tree):
+    """Unknown categories and roots outside a repository are errors."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError, match="git repository"):
+            vexy_glob.find("*.py", root=tmpdir, git_status="modified")
+        _make_repo(make_tree, tmpdir)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*.py", root=tmpdir, git_status="deleted")
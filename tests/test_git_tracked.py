@@ -0,0 +1,45 @@
+# this_file: tests/test_git_tracked.py
+"""Test restricting the walk to files tracked by git."""
+
+import subprocess
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def _make_repo(make_tree, tmpdir):
+    subprocess.run(["git", "init", "-q", tmpdir], check=True)
+    make_tree(tmpdir, ["src/tracked.py", "src/untracked.py", "scratch/notes.py", "forced.py"], "needle\n")
+    make_tree(tmpdir, {".gitignore": "forced.py\n"})
+    subprocess.run(["git", "-C", tmpdir, "add", "src/tracked.py", ".gitignore"], check=True)
+    subprocess.run(["git", "-C", tmpdir, "add", "-f", "forced.py"], check=True)
+
+
+def test_git_tracked_only_find(make_tree):
+    """Untracked files are skipped; force-added ignored files are kept."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_repo(make_tree, tmpdir)
+
+        results = vexy_glob.find("**/*.py", root=tmpdir, git_tracked_only=True, as_list=True)
+        assert sorted(Path(p).relative_to(tmpdir).as_posix() for p in results) == ["forced.py", "src/tracked.py"]
+
+        dirs = vexy_glob.find("*", root=tmpdir, file_type="d", git_tracked_only=True, as_list=True)
+        assert [Path(p).name for p in dirs if Path(p) != Path(tmpdir)] == ["src"]
+
+
+def test_git_tracked_only_search_from_subdirectory(make_tree):
+    """Content search honors the index when the root is inside the repository."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _make_repo(make_tree, tmpdir)
+
+        results = vexy_glob.search("needle", "*.py", root=Path(tmpdir, "src"), git_tracked_only=True, as_list=True)
+        assert [Path(r["path"]).name for r in results] == ["tracked.py"]
+
+
+def test_git_tracked_only_outside_repository():
+    """A root outside any repository is an error."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.py").write_text("")
+        with pytest.raises(vexy_glob.VexyGlobError, match="git repository"):
+            vexy_glob.find("*.py", root=tmpdir, git_tracked_only=True)
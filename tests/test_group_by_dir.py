@@ -0,0 +1,56 @@
+# this_file: tests/test_group_by_dir.py
+"""Test grouping find() results by parent directory."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = {rel: "x" * len(rel) for rel in ["b.txt", "a.txt", "sub/d.txt", "sub/c.txt", "sub/deep/e.txt"]}
+
+
+def test_group_by_dir_returns_dict(make_tree):
+    """Results are bucketed by their parent directory."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        groups = vexy_glob.find("*.txt", root=tmpdir, group_by_dir=True)
+        assert isinstance(groups, dict)
+        assert set(groups) == {
+            tmpdir,
+            str(Path(tmpdir, "sub")),
+            str(Path(tmpdir, "sub", "deep")),
+        }
+        assert sorted(Path(p).name for p in groups[tmpdir]) == ["a.txt", "b.txt"]
+        assert [Path(p).name for p in groups[str(Path(tmpdir, "sub", "deep"))]] == ["e.txt"]
+
+
+def test_group_by_dir_follows_sort(make_tree):
+    """Ordering inside each group follows the requested sort."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        groups = vexy_glob.find("*.txt", root=tmpdir, sort="name", group_by_dir=True)
+        assert [Path(p).name for p in groups[str(Path(tmpdir, "sub"))]] == ["c.txt", "d.txt"]
+
+
+def test_group_by_dir_with_path_objects(make_tree):
+    """Group values honour as_path."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        groups = vexy_glob.find("*.txt", root=tmpdir, group_by_dir=True, as_path=True)
+        assert all(isinstance(p, Path) for paths in groups.values() for p in paths)
+
+
+def test_collect_more_results_than_channel_capacity():
+    """Collecting a large result set does not stall on the bounded channel."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for i in range(2500):
+            Path(tmpdir, f"f{i}.txt").touch()
+
+        results = vexy_glob.find("*.txt", root=tmpdir, as_list=True, threads=1)
+        assert len(results) == 2500
+
+        groups = vexy_glob.find("*.txt", root=tmpdir, group_by_dir=True, threads=1)
+        assert len(groups[tmpdir]) == 2500
@@ -0,0 +1,42 @@
+# this_file: tests/test_head_lines.py
+"""Test limiting content search to the head of each file."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_matches_below_head_are_skipped():
+    """Only matches within the first head_lines lines are reported."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("match one\nother\nmatch three\nmatch four\n")
+
+        results = vexy_glob.search("match", "*.txt", root=tmpdir, head_lines=3, as_list=True)
+        assert [r["line_number"] for r in results] == [1, 3]
+
+
+def test_shebang_detection():
+    """head_lines=1 finds files by their first line only."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "script").write_text("#!/usr/bin/env python\nprint('hi')\n")
+        Path(tmpdir, "notes.txt").write_text("see below\n#!/usr/bin/env python\n")
+
+        results = vexy_glob.search(r"^#!.*python", root=tmpdir, head_lines=1, as_list=True)
+        assert [Path(r["path"]).name for r in results] == ["script"]
+
+
+def test_head_lines_with_dotall():
+    """Multi-line searches respect the head limit as well."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("x\ny\nstart\nend\n")
+
+        assert vexy_glob.search("start.end", "*.txt", root=tmpdir, dotall=True, head_lines=2, as_list=True) == []
+        assert len(vexy_glob.search("start.end", "*.txt", root=tmpdir, dotall=True, head_lines=3, as_list=True)) == 1
+
+
+def test_head_lines_rejects_write():
+    """Rewriting files would touch lines outside the searched head."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.search("a", root=tmpdir, head_lines=1, replace="b", write=True)
@@ -0,0 +1,27 @@
+# this_file: tests/test_hidden_only.py
+"""Test restricting results to hidden entries."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = [".config", "config", "src/.env", "src/env"]
+
+
+def test_hidden_only_find(make_tree):
+    """Only dotfiles are yielded, including those inside visible directories."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "SECRET=1\n")
+
+        results = vexy_glob.find("**/*", root=tmpdir, file_type="f", hidden_only=True, as_list=True)
+        assert sorted(Path(p).name for p in results) == [".config", ".env"]
+
+
+def test_hidden_only_search(make_tree):
+    """Content search only looks inside dotfiles."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "SECRET=1\n")
+
+        results = vexy_glob.search("SECRET", "**/*", root=tmpdir, hidden_only=True, as_list=True)
+        assert sorted(Path(r["path"]).name for r in results) == [".config", ".env"]
@@ -0,0 +1,42 @@
+# this_file: tests/test_highlight.py
+"""Test match highlighting markers in content search results."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_highlight_wraps_each_match():
+    """Every match on the line is bracketed by the prefix and suffix."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("foo bar foo\n")
+
+        results = vexy_glob.search("foo", root=tmpdir, highlight=("\x1b[31m", "\x1b[0m"), as_list=True)
+        assert results[0]["highlighted_line"] == "\x1b[31mfoo\x1b[0m bar \x1b[31mfoo\x1b[0m\n"
+        assert results[0]["line_text"] == "foo bar foo\n"
+
+
+def test_adjacent_matches_are_merged():
+    """Touching matches share one pair of markers instead of nesting."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("abab x ab\n")
+
+        results = vexy_glob.search("ab", root=tmpdir, highlight=("[", "]"), as_list=True)
+        assert results[0]["highlighted_line"] == "[abab] x [ab]\n"
+
+
+def test_no_highlight_by_default():
+    """Results carry no highlighted_line unless asked for."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("foo\n")
+
+        results = vexy_glob.search("foo", root=tmpdir, as_list=True)
+        assert "highlighted_line" not in results[0]
+
+
+def test_highlight_rejects_bad_markers():
+    """highlight must be a pair of strings."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.search("foo", root=tmpdir, highlight=("[",))
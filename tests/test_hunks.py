@@ -0,0 +1,72 @@
+# this_file: tests/test_hunks.py
+"""Test grouping content search matches into diff-style hunks."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def _write_lines(path, count, matches):
+    path.write_text("".join(f"{'hit' if i in matches else 'line'} {i}\n" for i in range(1, count + 1)))
+
+
+def test_hunks_merge_nearby_matches():
+    """Matches whose context windows touch share a hunk; distant ones do not."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _write_lines(Path(tmpdir, "a.txt"), 30, {5, 9, 25})
+
+        results = vexy_glob.search("hit", "*.txt", root=tmpdir, format="hunks", context=2, as_list=True)
+        assert len(results) == 1
+        hunks = results[0]["hunks"]
+        assert [(h["start_line"], len(h["lines"]), h["match_lines"]) for h in hunks] == [
+            (3, 9, [5, 9]),
+            (23, 5, [25]),
+        ]
+        assert hunks[0]["lines"][0] == "line 3"
+        assert hunks[1]["lines"][2] == "hit 25"
+        assert "line_number" not in results[0]
+
+
+def test_hunks_split_beyond_gap():
+    """One line more than 2 * context between matches starts a new hunk."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _write_lines(Path(tmpdir, "a.txt"), 20, {5, 11})
+
+        results = vexy_glob.search("hit", "*.txt", root=tmpdir, format="hunks", context=2, as_list=True)
+        assert [h["match_lines"] for h in results[0]["hunks"]] == [[5], [11]]
+        merged = vexy_glob.search("hit", "*.txt", root=tmpdir, format="hunks", context=3, as_list=True)
+        assert [h["match_lines"] for h in merged[0]["hunks"]] == [[5, 11]]
+
+
+def test_hunks_clip_at_file_edges():
+    """Context stops at the start and end of the file."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _write_lines(Path(tmpdir, "a.txt"), 3, {1, 3})
+
+        results = list(vexy_glob.search("hit", "*.txt", root=tmpdir, format="hunks"))
+        assert results[0]["hunks"] == [
+            {"start_line": 1, "lines": ["hit 1", "line 2", "hit 3"], "match_lines": [1, 3]}
+        ]
+
+
+def test_hunks_only_for_matching_files():
+    """Files without matches yield nothing."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        _write_lines(Path(tmpdir, "a.txt"), 3, {2})
+        _write_lines(Path(tmpdir, "b.txt"), 3, set())
+
+        results = vexy_glob.search("hit", "*.txt", root=tmpdir, format="hunks", context=0, as_list=True)
+        assert [Path(r["path"]).name for r in results] == ["a.txt"]
+        assert results[0]["hunks"] == [{"start_line": 2, "lines": ["hit 2"], "match_lines": [2]}]
+
+
+def test_hunks_rejects_invalid_options():
+    """Unknown formats and incompatible options are refused."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.search("x", root=tmpdir, format="diff")
+        with pytest.raises(ValueError):
+            vexy_glob.search("x", root=tmpdir, format="hunks", context=-1)
+        with pytest.raises(Exception):
+            vexy_glob.search("x", root=tmpdir, format="hunks", replace="y")
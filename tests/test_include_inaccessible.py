@@ -0,0 +1,58 @@
+# this_file: tests/test_include_inaccessible.py
+"""Test entries whose metadata cannot be read under size and time filters."""
+
+import os
+import sys
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def make_unsearchable_dir(tmpdir):
+    """A directory that can be listed but whose entries cannot be stat'ed."""
+    if sys.platform == "win32" or os.geteuid() == 0:
+        pytest.skip("needs POSIX permissions that root bypasses")
+    locked = Path(tmpdir, "locked")
+    locked.mkdir()
+    Path(locked, "hidden.txt").write_text("content")
+    Path(tmpdir, "visible.txt").write_text("content")
+    locked.chmod(0o444)
+    return locked
+
+
+def test_unstatable_entries_reported_as_errors():
+    """By default a failed stat leaves the entry out and reports it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        locked = make_unsearchable_dir(tmpdir)
+        try:
+            it = vexy_glob.find("*.txt", root=tmpdir, min_size=1)
+            assert [Path(p).name for p in it] == ["visible.txt"]
+            assert len(it.errors()) == 1
+            assert "hidden.txt" in it.errors()[0]
+        finally:
+            locked.chmod(0o755)
+
+
+def test_include_inaccessible_keeps_entries():
+    """include_inaccessible=True emits the entry without checking the filter."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        locked = make_unsearchable_dir(tmpdir)
+        try:
+            it = vexy_glob.find("*.txt", root=tmpdir, min_size=1, include_inaccessible=True)
+            assert sorted(Path(p).name for p in it) == ["hidden.txt", "visible.txt"]
+            assert it.errors() == []
+        finally:
+            locked.chmod(0o755)
+
+
+def test_no_errors_without_metadata_filters():
+    """Without size or time filters nothing needs a stat, so nothing is reported."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        locked = make_unsearchable_dir(tmpdir)
+        try:
+            it = vexy_glob.find("*.txt", root=tmpdir)
+            assert sorted(Path(p).name for p in it) == ["hidden.txt", "visible.txt"]
+            assert it.errors() == []
+        finally:
+            locked.chmod(0o755)
@@ -0,0 +1,31 @@
+# this_file: tests/test_iterator_errors.py
+"""Test collecting traversal errors on the streaming iterator."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_errors_collected_while_streaming():
+    """Results keep streaming and failures are available from errors()."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "valid.txt").write_text("content")
+        Path(tmpdir, "broken_link").symlink_to(Path(tmpdir, "nonexistent.txt"))
+
+        it = vexy_glob.find("*.txt", root=tmpdir, follow_symlinks=True)
+        results = list(it)
+        assert [Path(r).name for r in results] == ["valid.txt"]
+        errors = it.errors()
+        assert len(errors) == 1
+        assert "broken_link" in errors[0]
+
+
+def test_errors_empty_on_clean_scan():
+    """A scan without failures reports no errors."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("content")
+
+        it = vexy_glob.find("*.txt", root=tmpdir)
+        assert it.errors() == []
+        assert len(list(it)) == 1
+        assert it.errors() == []
@@ -0,0 +1,42 @@
+# this_file: tests/test_line_count.py
+"""Test line counts attached to find() results."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_line_counts_for_text_files():
+    """Each text file reports its lines, counting an unterminated last line."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "three.txt").write_text("a\nb\nc\n")
+        Path(tmpdir, "partial.txt").write_text("a\nb")
+        Path(tmpdir, "empty.txt").write_text("")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, with_line_count=True, as_list=True)
+        counts = {Path(r["path"]).name: r["line_count"] for r in results}
+        assert counts == {"three.txt": 3, "partial.txt": 2, "empty.txt": 0}
+
+
+def test_no_count_for_binaries_and_directories():
+    """Binary files and directories get a line_count of None."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "data.bin").write_bytes(b"a\n\x00b\n")
+        Path(tmpdir, "sub").mkdir()
+
+        results = list(vexy_glob.find("*", root=tmpdir, with_line_count=True))
+        assert {"data.bin", "sub"} <= {Path(r["path"]).name for r in results}
+        assert all(r["line_count"] is None for r in results)
+
+
+def test_line_count_with_depth_and_sort():
+    """Line counts combine with depth and survive collection and sorting."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "b.txt").write_text("1\n")
+        Path(tmpdir, "a.txt").write_text("1\n2\n")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, with_line_count=True, with_depth=True, sort="name")
+        assert [(Path(r["path"]).name, r["depth"], r["line_count"]) for r in results] == [
+            ("a.txt", 1, 2),
+            ("b.txt", 1, 1),
+        ]
@@ -0,0 +1,48 @@
+# this_file: tests/test_line_filters.py
+"""Test filtering files by line count."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+FILES = {
+    "stub.py": "pass\n",
+    "medium.py": "x = 1\n" * 10,
+    "long.py": "x = 1\n" * 200000,
+    "data.bin": b"\x00" + b"\n" * 20,
+    "pkg/": "",
+}
+
+
+def _names(results):
+    return sorted(Path(p).name for p in results)
+
+
+def test_min_lines(make_tree):
+    """min_lines keeps long text files only."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+        assert _names(vexy_glob.find("*", root=tmpdir, min_lines=10)) == ["long.py", "medium.py"]
+
+
+def test_max_lines(make_tree):
+    """max_lines keeps short text files; directories and binaries never match."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+        assert _names(vexy_glob.find("*", root=tmpdir, max_lines=10)) == ["medium.py", "stub.py"]
+
+
+def test_line_range(make_tree):
+    """Both bounds together are inclusive."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+        assert _names(vexy_glob.find("*.py", root=tmpdir, min_lines=2, max_lines=10)) == ["medium.py"]
+
+
+def test_line_filters_in_content_search(make_tree):
+    """Content search only searches files in the line range."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+        results = vexy_glob.search("x = 1", "*.py", root=tmpdir, max_lines=10, as_list=True)
+        assert {Path(r["path"]).name for r in results} == {"medium.py"}
@@ -0,0 +1,41 @@
+# this_file: tests/test_line_terminator.py
+"""Test content search with a custom line terminator."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_nul_separated_records():
+    """Records split on NUL and line numbers count records."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "paths.list0").write_bytes(b"/home/a\x00/tmp/b\nwith newline\x00/tmp/c\x00")
+
+        results = vexy_glob.search(r"^/tmp/", "*.list0", root=tmpdir, line_terminator="\0", as_list=True)
+        assert [r["line_number"] for r in results] == [2, 3]
+        assert [r["line_text"] for r in results] == ["/tmp/b\nwith newline\x00", "/tmp/c\x00"]
+
+
+def test_regex_does_not_cross_records():
+    """"." never matches the terminator, so a match stays within one record."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "data.bin").write_bytes(b"foo\x00bar\x00")
+
+        assert vexy_glob.search(r"foo.bar", "*.bin", root=tmpdir, line_terminator=b"\0", as_list=True) == []
+
+
+def test_literals_with_cr_terminator():
+    """Literal search splits CR-only files into lines."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "old.txt").write_bytes(b"one\rtwo\rthree\r")
+
+        results = vexy_glob.search(None, "*.txt", root=tmpdir, content_literals=["two", "three"], line_terminator="\r", as_list=True)
+        assert [r["line_number"] for r in results] == [2, 3]
+
+
+def test_line_terminator_must_be_one_byte():
+    """Multi-byte terminators are rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.search("x", root=tmpdir, line_terminator="\r\n")
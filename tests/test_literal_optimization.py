@@ -115,4 +115,30 @@ def test_literal_pattern_with_filters():
         
         # Test literal pattern that doesn't match size filter
         results = list(vexy_glob.find("small.txt", root=tmpdir, min_size=100))
-        assert len(results) == 0
\ No newline at end of file
+        assert len(results) == 0
+
+def test_literal_unicode_case_folding():
+    """Case-insensitive literals fold non-ASCII names correctly."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "Straße.md").write_text("x")
+        Path(tmpdir, "CAFÉ.TXT").write_text("x")
+        Path(tmpdir, "cafe.txt").write_text("x")
+
+        def names(pattern):
+            return sorted(Path(p).name for p in vexy_glob.find(pattern, root=tmpdir, case_sensitive=False))
+
+        assert names("strasse.md") == ["Straße.md"]
+        assert names("café.txt") == ["CAFÉ.TXT"]
+        assert names("cafe.txt") == ["cafe.txt"]
+        # Smart case keeps an uppercase non-ASCII literal case-sensitive
+        assert sorted(Path(p).name for p in vexy_glob.find("CAFÉ.TXT", root=tmpdir)) == ["CAFÉ.TXT"]
+
+
+def test_literal_path_suffix_with_unicode():
+    """Path literals compare case-insensitively across non-ASCII directories."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "Ünïcode").mkdir()
+        Path(tmpdir, "Ünïcode", "README.md").write_text("x")
+
+        results = vexy_glob.find("ünïcode/readme.md", root=tmpdir, case_sensitive=False, as_list=True)
+        assert [Path(p).name for p in results] == ["README.md"]
@@ -0,0 +1,59 @@
+# this_file: tests/test_literal_separator.py
+"""Test whether glob wildcards may cross directory separators."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = ["src/main.rs", "src/a/b.rs", "lib/src/util.rs", "notes.txt"]
+
+
+def _relative(results, tmpdir):
+    return sorted(Path(r).relative_to(tmpdir).as_posix() for r in results)
+
+
+def test_star_stops_at_separator_by_default(make_tree):
+    """src/*.rs matches direct children of any src directory, not src/a/b.rs."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.find("src/*.rs", root=tmpdir, as_list=True)
+        assert _relative(results, tmpdir) == ["lib/src/util.rs", "src/main.rs"]
+
+
+def test_globstar_still_crosses_directories(make_tree):
+    """** keeps matching any number of directories."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.find("src/**/*.rs", root=tmpdir, as_list=True)
+        assert _relative(results, tmpdir) == ["lib/src/util.rs", "src/a/b.rs", "src/main.rs"]
+
+
+def test_star_crosses_separator_when_disabled(make_tree):
+    """With literal_separator=False, * spans directories of the whole path."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.find("**/src/*.rs", root=tmpdir, literal_separator=False, as_list=True)
+        assert _relative(results, tmpdir) == ["lib/src/util.rs", "src/a/b.rs", "src/main.rs"]
+
+
+def test_name_patterns_are_unaffected(make_tree):
+    """Patterns without a separator still match file names at any depth."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        for literal_separator in (True, False):
+            results = vexy_glob.find("*.rs", root=tmpdir, literal_separator=literal_separator, as_list=True)
+            assert len(results) == 3
+
+
+def test_compiled_pattern_honours_literal_separator(make_tree):
+    """compile() takes the same option."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        compiled = vexy_glob.compile("src/*.rs")
+        assert _relative(compiled.find([tmpdir]), tmpdir) == ["lib/src/util.rs", "src/main.rs"]
@@ -0,0 +1,78 @@
+# this_file: tests/test_match_extraction.py
+"""Test extraction of matched substrings in content search."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_matches_hold_matched_substrings():
+    """Each result lists the matched text, not the whole line."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.py").write_text("import os, sys  # import pathlib later\n")
+
+        results = vexy_glob.search(r"import \w+", "*.py", root=tmpdir, as_list=True)
+        assert len(results) == 1
+        assert results[0]["matches"] == ["import os", "import pathlib"]
+
+
+def test_unique_matches_collapses_repeats():
+    """unique_matches keeps one copy of each match in first-seen order."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("foo bar foo baz bar foo\n")
+
+        results = vexy_glob.search(r"foo|bar|baz", "*.txt", root=tmpdir, as_list=True)
+        assert results[0]["matches"] == ["foo", "bar", "foo", "baz", "bar", "foo"]
+
+        results = vexy_glob.search(r"foo|bar|baz", "*.txt", root=tmpdir, unique_matches=True, as_list=True)
+        assert results[0]["matches"] == ["foo", "bar", "baz"]
+
+
+def test_min_match_length_drops_short_matches():
+    """Short incidental matches are dropped, along with lines that only have those."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("x = 1\nERROR42 at a\n")
+
+        results = vexy_glob.search(r"[A-Za-z]+\d*", "*.txt", root=tmpdir, min_match_length=3, as_list=True)
+        assert [(r["line_number"], r["matches"], r["matches_on_line"]) for r in results] == [(2, ["ERROR42"], 1)]
+
+
+def test_matches_on_line_counts_hits():
+    """matches_on_line counts every hit, including ones unique_matches drops."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("foo foo foo\nfoo once\n")
+
+        results = vexy_glob.search("foo", "*.txt", root=tmpdir, unique_matches=True, as_list=True)
+        by_line = {r["line_number"]: r for r in results}
+        assert by_line[1]["matches_on_line"] == 3
+        assert by_line[1]["matches"] == ["foo"]
+        assert by_line[2]["matches_on_line"] == 1
+
+
+def test_case_insensitive_matches_keep_original_text():
+    """Matches report the text as it appears in the file."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("Error and ERROR\n")
+
+        results = vexy_glob.search("error", "*.txt", root=tmpdir, unique_matches=True, as_list=True)
+        assert results[0]["matches"] == ["Error", "ERROR"]
+
+
+def test_distinct_matches_across_files():
+    """distinct_matches returns one sorted list of values from every file."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.py").write_text("x = FOO_BAR\ny = BAZ\n")
+        Path(tmpdir, "b.py").write_text("z = BAZ + FOO_BAR + QUX\n")
+
+        results = vexy_glob.search(r"\b[A-Z_]{3,}\b", "*.py", root=tmpdir, distinct_matches=True)
+        assert results == ["BAZ", "FOO_BAR", "QUX"]
+
+
+def test_distinct_matches_with_stats():
+    """Stats are attached to the distinct list like any collected result."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("one two one\n")
+
+        results, stats = vexy_glob.search(r"\w+", "*.txt", root=tmpdir, distinct_matches=True, with_stats=True)
+        assert results == ["one", "two"]
+        assert stats["matched"] == 1
@@ -0,0 +1,34 @@
+# this_file: tests/test_max_open_files.py
+"""Test capping the files content search holds open at once."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_max_open_files_finds_every_match():
+    """A cap of one still searches every file, just not in parallel."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for i in range(50):
+            Path(tmpdir, f"f{i}.txt").write_text(f"needle {i}\n")
+
+        results = vexy_glob.search("needle", "*.txt", root=tmpdir, threads=8, max_open_files=1, as_list=True)
+        assert len(results) == 50
+
+
+def test_max_open_files_with_write():
+    """Rewrites happen while the slot is held and still complete."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for i in range(10):
+            Path(tmpdir, f"f{i}.txt").write_text("old\n")
+
+        list(vexy_glob.search("old", "*.txt", root=tmpdir, replace="new", write=True, max_open_files=2))
+        assert all(p.read_text() == "new\n" for p in Path(tmpdir).iterdir())
+
+
+def test_max_open_files_must_be_positive():
+    """Zero slots would block forever, so it is rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(Exception):
+            vexy_glob.search("x", root=tmpdir, max_open_files=0)
@@ -0,0 +1,75 @@
+# this_file: tests/test_max_symlink_hops.py
+"""Test limiting how many symlink hops are chased."""
+
+import tempfile
+import time
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+# link0 -> link1 -> ... -> link9 -> target.txt
+CHAIN = {f"link{i}": f"link{i + 1}" if i < 9 else "target.txt" for i in range(10)}
+
+
+def test_long_chain_reported(make_tree):
+    """Links needing more hops than the limit are dropped and reported."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, ["target.txt"], links=CHAIN)
+
+        it = vexy_glob.find("*", root=tmpdir, follow_symlinks=True, max_symlink_hops=3)
+        names = sorted(Path(p).name for p in it if p != tmpdir)
+        # Only the last three links reach the target within three hops
+        assert names == ["link7", "link8", "link9", "target.txt"]
+        errors = it.errors()
+        assert len(errors) == 7
+        assert all("Too many symlink hops" in e for e in errors)
+
+
+def test_loop_reported():
+    """A symlink loop is left out and reported."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a").symlink_to("b")
+        Path(tmpdir, "b").symlink_to("a")
+
+        it = vexy_glob.find("*", root=tmpdir, follow_symlinks=True, max_symlink_hops=5)
+        assert [p for p in it if p != tmpdir] == []
+        assert len(it.errors()) == 2
+
+
+def test_limit_ignored_without_following(make_tree):
+    """Unfollowed links are yielded as links whatever their chain."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, ["target.txt"], links=CHAIN)
+
+        it = vexy_glob.find("link*", root=tmpdir, max_symlink_hops=3)
+        assert len(list(it)) == 10
+        assert it.errors() == []
+
+
+def test_content_search_respects_limit(make_tree):
+    """Content search skips over-long chains too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, ["target.txt"], links=CHAIN)
+
+        results = vexy_glob.search("x", "*", root=tmpdir, follow_symlinks=True, max_symlink_hops=3, as_list=True)
+        assert sorted(Path(r["path"]).name for r in results) == ["link7", "link8", "link9", "target.txt"]
+
+
+def test_fail_fast_stops_the_walk(make_tree):
+    """With fail_fast an over-long chain ends the walk like any other error."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(Path(tmpdir, "a"), ["target.txt"], links=CHAIN)
+        Path(tmpdir, "b").mkdir()
+        for i in range(2000):
+            Path(tmpdir, "b", f"{i}.txt").touch()
+
+        it = vexy_glob.find(
+            "*", root=tmpdir, follow_symlinks=True, max_symlink_hops=3,
+            fail_fast=True, dir_order="pre", with_stats=True,
+        )
+        with pytest.raises(OSError, match="Too many symlink hops"):
+            list(it)
+        # Give a walker that kept going the time to show it
+        time.sleep(0.2)
+        assert it.stats["entries_visited"] < 20
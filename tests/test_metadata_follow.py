@@ -0,0 +1,57 @@
+# this_file: tests/test_metadata_follow.py
+"""Test choosing link or target metadata for size and time filters."""
+
+import os
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = {"big.bin": b"x" * 100_000}
+LINKS = {"link.bin": "big.bin"}
+
+
+def _names(results):
+    return sorted(Path(r).name for r in results)
+
+
+def test_metadata_follow_uses_target_size(make_tree):
+    """stat semantics measure an unfollowed link by the file it points to."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        results = vexy_glob.find("*.bin", root=tmpdir, min_size=50_000, metadata_follow=True, as_list=True)
+        assert _names(results) == ["big.bin", "link.bin"]
+
+
+def test_metadata_lstat_uses_link_size(make_tree):
+    """lstat semantics measure the link itself, even when it is followed."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        results = vexy_glob.find(
+            "*.bin", root=tmpdir, min_size=50_000, follow_symlinks=True, metadata_follow=False, as_list=True
+        )
+        assert _names(results) == ["big.bin"]
+        results = vexy_glob.find("*.bin", root=tmpdir, max_size=1000, follow_symlinks=True, metadata_follow=False, as_list=True)
+        assert _names(results) == ["link.bin"]
+
+
+def test_metadata_lstat_uses_link_times(make_tree):
+    """Time filters read the link's own mtime with lstat semantics."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+        os.utime(Path(tmpdir, "big.bin"), (1_000_000, 1_000_000))
+
+        old = dict(mtime_before=2_000_000, as_list=True)
+        assert _names(vexy_glob.find("*.bin", root=tmpdir, metadata_follow=True, **old)) == ["big.bin", "link.bin"]
+        assert _names(vexy_glob.find("*.bin", root=tmpdir, metadata_follow=False, **old)) == ["big.bin"]
+
+
+def test_metadata_follow_default_matches_file_symlinks(make_tree):
+    """Left unset, the filters follow follow_file_symlinks as before."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, links=LINKS)
+
+        results = vexy_glob.find("*.bin", root=tmpdir, min_size=50_000, follow_file_symlinks=True, as_list=True)
+        assert _names(results) == ["big.bin", "link.bin"]
@@ -0,0 +1,35 @@
+# this_file: tests/test_name_filter.py
+"""Test the file name filter applied alongside the glob in content search."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = ["tests/test_a.rs", "tests/helpers.rs", "tests/test_b.py", "src/test_c.rs"]
+
+
+def test_name_filter_combines_with_glob(make_tree):
+    """The glob picks the directory, name_filter the file names within it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "assert!(true)\n")
+
+        results = vexy_glob.search("assert", "tests/**", root=tmpdir, name_filter="test_*.rs", as_list=True)
+        assert [Path(r["path"]).relative_to(tmpdir).as_posix() for r in results] == ["tests/test_a.rs"]
+
+
+def test_name_filter_ignores_directories(make_tree):
+    """A name filter never matches against parent directory names."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "assert!(true)\n")
+
+        results = vexy_glob.search("assert", root=tmpdir, name_filter="tests", as_list=True)
+        assert results == []
+
+
+def test_name_filter_requires_content_search():
+    """Path search rejects name_filter instead of silently ignoring it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.find("*", root=tmpdir, name_filter="*.rs")
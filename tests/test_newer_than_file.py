@@ -0,0 +1,52 @@
+# this_file: tests/test_newer_than_file.py
+"""Test filtering by a reference file's modification time."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def make_files(tmpdir):
+    """An old file, a stamp and a new file with distinct mtimes."""
+    base = 1_700_000_000
+    for name, mtime in [("old.txt", base - 100), ("stamp", base), ("new.txt", base + 100), ("same.txt", base)]:
+        path = Path(tmpdir, name)
+        path.write_text("TODO\n")
+        os.utime(path, (mtime, mtime))
+    return Path(tmpdir, "stamp")
+
+
+def test_find_newer_than_file():
+    """Only entries strictly newer than the reference are kept."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        stamp = make_files(tmpdir)
+
+        results = vexy_glob.find("*.txt", root=tmpdir, newer_than_file=stamp)
+        assert [Path(p).name for p in results] == ["new.txt"]
+
+
+def test_search_newer_than_file():
+    """Content search honours the reference file too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        stamp = make_files(tmpdir)
+
+        results = vexy_glob.search("TODO", "*.txt", root=tmpdir, newer_than_file=str(stamp), as_list=True)
+        assert [Path(r["path"]).name for r in results] == ["new.txt"]
+
+
+def test_newer_than_file_combines_with_mtime_after():
+    """The stricter of mtime_after and the reference wins."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        stamp = make_files(tmpdir)
+
+        results = vexy_glob.find("*.txt", root=tmpdir, newer_than_file=stamp, mtime_after=1_700_000_200)
+        assert list(results) == []
+
+
+def test_missing_reference_file():
+    """A missing reference file is a ValueError."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError, match="newer_than_file"):
+            vexy_glob.find("*.txt", root=tmpdir, newer_than_file=Path(tmpdir, "missing"))
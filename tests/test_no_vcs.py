@@ -0,0 +1,50 @@
+# this_file: tests/test_no_vcs.py
+"""Test skipping git ignore handling while keeping .ignore files."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = {
+    ".git/info/exclude": "excluded.txt\n",
+    ".gitignore": "gitignored.txt\n",
+    ".ignore": "ignored.txt\n",
+    **dict.fromkeys(["kept.txt", "gitignored.txt", "excluded.txt", "ignored.txt"], "x"),
+}
+
+
+def _names(results):
+    return sorted(Path(r).name for r in results)
+
+
+def test_no_vcs_disables_git_rules_only(make_tree):
+    """.gitignore and .git/info/exclude stop applying; .ignore still does."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        assert _names(vexy_glob.find("*.txt", root=tmpdir)) == ["kept.txt"]
+        assert _names(vexy_glob.find("*.txt", root=tmpdir, no_vcs=True)) == [
+            "excluded.txt",
+            "gitignored.txt",
+            "kept.txt",
+        ]
+
+
+def test_no_vcs_keeps_fdignore():
+    """A root .fdignore is still honored."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, ".fdignore").write_text("*.tmp\n")
+        Path(tmpdir, "a.tmp").write_text("x")
+        Path(tmpdir, "b.txt").write_text("x")
+
+        assert _names(vexy_glob.find("*", root=tmpdir, file_type="f", no_vcs=True)) == ["b.txt"]
+
+
+def test_no_vcs_applies_to_content_search(make_tree):
+    """Content search sees gitignored files with no_vcs."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.search("x", "*.txt", root=tmpdir, no_vcs=True, as_list=True)
+        assert _names(r["path"] for r in results) == ["excluded.txt", "gitignored.txt", "kept.txt"]
@@ -0,0 +1,76 @@
+# this_file: tests/test_null_separated.py
+"""Test NUL-separated bytes output for xargs -0."""
+
+import os
+import subprocess
+import sys
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_find_null_separated_bytes():
+    """Every path is followed by a NUL byte."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for name in ["a.txt", "b.txt", "c.py"]:
+            Path(tmpdir, name).write_text("content")
+
+        output = vexy_glob.find_null_separated("*.txt", root=tmpdir, sort="name")
+        assert isinstance(output, bytes)
+        assert output.endswith(b"\0")
+        paths = output.split(b"\0")[:-1]
+        assert [Path(p.decode()).name for p in paths] == ["a.txt", "b.txt"]
+
+
+def test_null_separated_special_names():
+    """Names with spaces and newlines survive the round trip."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        names = ["with space.txt", "new\nline.txt"]
+        for name in names:
+            Path(tmpdir, name).write_text("content")
+
+        output = vexy_glob.find("*.txt", root=tmpdir, null_separated=True)
+        paths = [Path(p.decode()).name for p in output.split(b"\0") if p]
+        assert sorted(paths) == sorted(names)
+
+
+def test_null_separated_empty():
+    """No matches produce an empty bytes object."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        assert vexy_glob.find_null_separated("*.none", root=tmpdir) == b""
+
+
+def test_null_separated_with_xargs():
+    """The output can be piped to xargs -0."""
+    if sys.platform == "win32":
+        pytest.skip("xargs not available")
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "one file.txt").write_text("content")
+
+        output = vexy_glob.find_null_separated("*.txt", root=tmpdir)
+        listed = subprocess.run(["xargs", "-0", "ls"], input=output, capture_output=True, check=True)
+        assert b"one file.txt" in listed.stdout
+
+
+def test_null_separated_rejects_group_by_dir():
+    """Grouping and NUL joining are mutually exclusive."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, null_separated=True, group_by_dir=True)
+
+
+@pytest.mark.skipif(sys.platform != "linux", reason="needs a filesystem that accepts non-UTF-8 names")
+def test_null_separated_keeps_non_utf8_names():
+    """A name that is not valid UTF-8 comes back byte for byte."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        name = b"caf\xe9.txt"
+        with open(os.path.join(os.fsencode(tmpdir), name), "w") as f:
+            f.write("content")
+
+        output = vexy_glob.find_null_separated("*.txt", root=tmpdir)
+        paths = output.split(b"\0")[:-1]
+        assert [os.path.basename(p) for p in paths] == [name]
+
+        output = vexy_glob.find_null_separated("*.txt", root=tmpdir, separator="\\")
+        assert output.split(b"\0")[:-1] == [os.fsencode(tmpdir).replace(b"/", b"\\") + b"\\" + name]
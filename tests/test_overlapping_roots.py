@@ -0,0 +1,93 @@
+# this_file: tests/test_overlapping_roots.py
+"""Test that overlapping roots are walked once."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+from vexy_glob import _vexy_glob
+
+
+TREE = ["src/main.py", "src/lib/util.py", "other/x.py"]
+
+
+def test_nested_roots_find_each_file_once(make_tree):
+    """A root inside another root adds no duplicates."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle\n")
+        src = str(Path(tmpdir, "src"))
+        lib = str(Path(tmpdir, "src", "lib"))
+
+        compiled = vexy_glob.compile("*.py")
+        results = compiled.find([lib, src, src + "/", str(Path(tmpdir, "other"))], yield_results=False)
+        names = sorted(Path(p).name for p in results)
+        assert names == ["main.py", "util.py", "x.py"]
+
+
+def test_nested_roots_search_each_file_once(make_tree):
+    """Content search drops nested roots too, keeping the outer spelling."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle\n")
+        src = str(Path(tmpdir, "src"))
+        lib = str(Path(tmpdir, "src", "lib", ".."))
+
+        results = _vexy_glob.search("needle", [src, lib], glob="*.py", yield_results=False)
+        paths = sorted(r["path"] for r in results)
+        assert len(paths) == 2
+        assert all(p.startswith(src + "/") for p in paths)
+
+
+def test_nested_hidden_root_is_kept(make_tree):
+    """A root inside a hidden directory is walked although the outer walk skips it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle\n")
+        Path(tmpdir, ".cache").mkdir()
+        Path(tmpdir, ".cache", "c.py").write_text("needle\n")
+
+        results = _vexy_glob.find([tmpdir, str(Path(tmpdir, ".cache"))], glob="*.py", yield_results=False)
+        names = sorted(Path(p).name for p in results)
+        assert names == ["c.py", "main.py", "util.py", "x.py"]
+
+
+def test_nested_root_below_max_depth_is_kept(make_tree):
+    """max_depth counts from each root, so a deep root still sees its files."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle\n")
+
+        results = _vexy_glob.find(
+            [tmpdir, str(Path(tmpdir, "src", "lib"))], glob="*.py", max_depth=1, yield_results=False
+        )
+        names = sorted(Path(p).name for p in results)
+        assert names == ["util.py"]
+
+
+def test_nested_gitignored_root_is_kept(make_tree):
+    """A root inside a gitignored directory is walked although the outer walk skips it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle\n")
+        Path(tmpdir, ".gitignore").write_text("build/\n")
+        Path(tmpdir, "build").mkdir()
+        Path(tmpdir, "build", "gen.py").write_text("needle\n")
+
+        results = _vexy_glob.find(
+            [tmpdir, str(Path(tmpdir, "build"))], glob="*.py", require_git=False, yield_results=False
+        )
+        names = sorted(Path(p).name for p in results)
+        assert names == ["gen.py", "main.py", "util.py", "x.py"]
+
+        results = _vexy_glob.search(
+            "needle", [tmpdir, str(Path(tmpdir, "build"))], glob="*.py", require_git=False, yield_results=False
+        )
+        assert sorted(Path(r["path"]).name for r in results) == ["gen.py", "main.py", "util.py", "x.py"]
+
+
+def test_nested_symlinked_root_is_kept(make_tree):
+    """A symlinked root the outer walk does not follow is walked on its own."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle\n")
+        link = Path(tmpdir, "link")
+        link.symlink_to(Path(tmpdir, "src", "lib"), target_is_directory=True)
+
+        results = _vexy_glob.find([tmpdir, str(link)], glob="*.py", yield_results=False)
+        paths = sorted(results)
+        assert len(paths) == 4
+        assert str(link / "util.py") in paths
@@ -0,0 +1,48 @@
+# this_file: tests/test_parent_ignores.py
+"""Test ignore files above the search root and git repository detection."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = {".gitignore": "*.log\n", "sub/keep.txt": "content", "sub/debug.log": "content"}
+
+
+def test_parent_gitignore_applies_in_subdirectory(make_tree):
+    """The repository root's .gitignore applies when searching a subdirectory."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        sub = make_tree(tmpdir, {".git/": "", **TREE}) / "sub"
+
+        results = [Path(p).name for p in vexy_glob.find("*", root=sub, file_type="f")]
+        assert results == ["keep.txt"]
+
+
+def test_parents_false_ignores_ancestor_files(make_tree):
+    """parents=False only reads ignore files from the root downward."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        sub = make_tree(tmpdir, {".git/": "", **TREE}) / "sub"
+
+        results = sorted(Path(p).name for p in vexy_glob.find("*", root=sub, file_type="f", parents=False))
+        assert results == ["debug.log", "keep.txt"]
+
+
+def test_require_git(make_tree):
+    """.gitignore outside a git repository only applies with require_git=False."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        sub = make_tree(tmpdir, TREE) / "sub"
+
+        results = sorted(Path(p).name for p in vexy_glob.find("*", root=sub, file_type="f"))
+        assert results == ["debug.log", "keep.txt"]
+
+        results = [Path(p).name for p in vexy_glob.find("*", root=sub, file_type="f", require_git=False)]
+        assert results == ["keep.txt"]
+
+
+def test_parent_gitignore_in_content_search(make_tree):
+    """Content search honors the same ancestor ignore rules."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        sub = make_tree(tmpdir, {".git/": "", **TREE}) / "sub"
+
+        results = vexy_glob.search("content", root=sub, as_list=True)
+        assert [Path(r["path"]).name for r in results] == ["keep.txt"]
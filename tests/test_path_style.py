@@ -0,0 +1,48 @@
+# this_file: tests/test_path_style.py
+"""Test the path_style option for posix-style output."""
+
+import tempfile
+from pathlib import Path, PurePosixPath
+import pytest
+import vexy_glob
+
+
+def test_posix_style_builds_pure_posix_paths():
+    """as_path with path_style='posix' yields PurePosixPath objects."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sub").mkdir()
+        Path(tmpdir, "sub", "a.txt").write_text("hello\n")
+
+        results = vexy_glob.find("**/*.txt", root=tmpdir, as_path=True, path_style="posix", as_list=True)
+        assert len(results) == 1
+        assert type(results[0]) is PurePosixPath
+        assert results[0].name == "a.txt"
+        assert "\\" not in str(results[0])
+
+
+def test_posix_style_strings_use_forward_slashes():
+    """String output never contains backslash separators in posix style."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sub").mkdir()
+        Path(tmpdir, "sub", "a.txt").write_text("hello\n")
+
+        native = vexy_glob.find("**/*.txt", root=tmpdir, as_list=True)
+        posix = vexy_glob.find("**/*.txt", root=tmpdir, path_style="posix", as_list=True)
+        assert [p.replace("\\", "/") for p in native] == posix
+
+
+def test_posix_style_in_content_search():
+    """Content search results honour path_style as well."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("hello\n")
+
+        results = vexy_glob.search("hello", "*.txt", root=tmpdir, as_path=True, path_style="posix", as_list=True)
+        assert len(results) == 1
+        assert type(results[0]["path"]) is PurePosixPath
+
+
+def test_invalid_path_style_rejected():
+    """Unknown styles raise before walking."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises((ValueError, vexy_glob.VexyGlobError)):
+            vexy_glob.find("*", root=tmpdir, path_style="windows")
@@ -0,0 +1,50 @@
+# this_file: tests/test_per_dir_limit.py
+"""Test the per-directory result cap."""
+
+import tempfile
+from collections import Counter
+from pathlib import Path
+import vexy_glob
+
+
+TREE = [Path(d, f"file_{i}.txt").as_posix() for d in ["", "a", "b", "a/deep"] for i in range(10)]
+
+
+def test_per_dir_limit_caps_each_directory(make_tree):
+    """No directory contributes more than per_dir_limit entries."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, per_dir_limit=3, as_list=True)
+        counts = Counter(str(Path(p).parent) for p in results)
+        assert len(counts) == 4
+        assert all(count == 3 for count in counts.values())
+
+
+def test_per_dir_limit_larger_than_directory(make_tree):
+    """A cap above the directory size keeps every entry."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, per_dir_limit=100, as_list=True)
+        assert len(results) == 40
+
+
+def test_per_dir_limit_counts_only_matches(make_tree):
+    """Entries rejected by filters do not use up a directory's quota."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle")
+        for i in range(5):
+            Path(tmpdir, f"other_{i}.log").write_text("needle")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, file_type="f", per_dir_limit=2, as_list=True)
+        assert len(results) == 8
+
+
+def test_per_dir_limit_content_search(make_tree):
+    """Content search searches at most per_dir_limit files per directory."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "needle")
+
+        results = vexy_glob.search("needle", "*.txt", root=tmpdir, per_dir_limit=1, as_list=True)
+        assert len(results) == 4
@@ -0,0 +1,57 @@
+# this_file: tests/test_predicate.py
+"""Test filtering find() results with a Python predicate."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_predicate_keeps_truthy_entries():
+    """Only paths the predicate accepts are returned, as a list."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for name in ["a.txt", "bb.txt", "ccc.txt"]:
+            Path(tmpdir, name).write_text(name)
+
+        seen = []
+
+        def long_name(path):
+            seen.append(path)
+            return len(Path(path).stem) > 1
+
+        results = vexy_glob.find("*.txt", root=tmpdir, predicate=long_name)
+        assert isinstance(results, list)
+        assert sorted(Path(p).name for p in results) == ["bb.txt", "ccc.txt"]
+        assert all(isinstance(p, str) for p in seen) and len(seen) == 3
+
+
+def test_predicate_receives_entries():
+    """With as_entry the predicate gets Entry objects."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "empty.txt").write_text("")
+        Path(tmpdir, "full.txt").write_text("data")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, as_entry=True, predicate=lambda e: e.size > 0)
+        assert [e.name for e in results] == ["full.txt"]
+
+
+def test_predicate_runs_before_sort():
+    """Filtered results are still sorted as requested."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for name in ["c.txt", "a.txt", "b.txt", "skip.txt"]:
+            Path(tmpdir, name).write_text("x")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, sort="name", predicate=lambda p: "skip" not in p)
+        assert [Path(p).name for p in results] == ["a.txt", "b.txt", "c.txt"]
+
+
+def test_predicate_errors_propagate():
+    """Exceptions raised by the predicate reach the caller."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("x")
+
+        def broken(path):
+            raise RuntimeError("boom")
+
+        with pytest.raises(vexy_glob.VexyGlobError, match="boom"):
+            vexy_glob.find("*.txt", root=tmpdir, predicate=broken)
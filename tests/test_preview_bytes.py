@@ -0,0 +1,40 @@
+# this_file: tests/test_preview_bytes.py
+"""Test previews of leading file content in find results."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def _by_name(results):
+    return {Path(r["path"]).name: r["preview"] for r in results}
+
+
+def test_preview_bytes_truncates_text():
+    """Text files are previewed up to the byte limit."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "long.txt").write_text("abcdefghij" * 10)
+        Path(tmpdir, "short.txt").write_text("hi\n")
+
+        previews = _by_name(vexy_glob.find("*.txt", root=tmpdir, preview_bytes=5, as_list=True))
+        assert previews == {"long.txt": "abcde", "short.txt": "hi\n"}
+
+
+def test_preview_bytes_skips_binaries_and_dirs():
+    """Binary files and directories get no preview."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "text.txt").write_text("plain")
+        Path(tmpdir, "data.bin").write_bytes(b"ab" + b"\x00" * 10)
+        Path(tmpdir, "sub").mkdir()
+
+        previews = _by_name(r for r in vexy_glob.find("*", root=tmpdir, preview_bytes=1) if r["path"] != tmpdir)
+        assert previews == {"text.txt": "p", "data.bin": None, "sub": None}
+
+
+def test_preview_bytes_drops_cut_character():
+    """A multi-byte character split by the limit is left out."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("aé", encoding="utf-8")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, preview_bytes=2, as_list=True)
+        assert results[0]["preview"] == "a"
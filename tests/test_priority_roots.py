@@ -0,0 +1,65 @@
+# this_file: tests/test_priority_roots.py
+"""Test streaming results from priority directories first."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = [f"{d}/f{i}.txt" for d in ["aaa", "bbb", "zzz/deep/er", "mmm"] for i in range(20)]
+
+
+def test_priority_results_come_first(make_tree):
+    """Everything under a priority root precedes everything else."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        priority = str(Path(tmpdir, "zzz", "deep"))
+
+        results = list(vexy_glob.find("**/*.txt", root=tmpdir, priority_roots=priority))
+        inside = [r.startswith(priority) for r in results]
+        assert inside[:20] == [True] * 20
+        assert not any(inside[20:])
+
+
+def test_priority_roots_keep_the_same_set(make_tree):
+    """Directories on the way to a priority root are yielded exactly once."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        plain = vexy_glob.find("**/*", root=tmpdir, as_list=True)
+        prioritized = list(vexy_glob.find("**/*", root=tmpdir, priority_roots=[Path(tmpdir, "zzz", "deep"), Path(tmpdir, "mmm")]))
+        assert len(prioritized) == len(set(prioritized))
+        assert sorted(prioritized) == sorted(plain)
+
+
+def test_priority_roots_outside_root_ignored(make_tree):
+    """Priority paths outside every root change nothing."""
+    with tempfile.TemporaryDirectory() as tmpdir, tempfile.TemporaryDirectory() as other:
+        make_tree(tmpdir, TREE)
+        Path(other, "extra.txt").write_text("x")
+
+        results = list(vexy_glob.find("**/*.txt", root=tmpdir, priority_roots=other))
+        assert len(results) == 80
+
+
+def test_priority_roots_relative(make_tree):
+    """Relative priority roots resolve against the working directory like root."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        cwd = os.getcwd()
+        os.chdir(tmpdir)
+        try:
+            results = list(vexy_glob.find("**/*.txt", root=".", priority_roots="mmm"))
+        finally:
+            os.chdir(cwd)
+        assert all(Path(r).parts[-2] == "mmm" for r in results[:20])
+        assert len(results) == 80
+
+
+def test_priority_roots_reject_dir_order():
+    """A fixed directory order leaves no room for priorities."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(Exception):
+            vexy_glob.find("*", root=tmpdir, priority_roots=tmpdir, dir_order="pre")
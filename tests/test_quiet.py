@@ -0,0 +1,44 @@
+# this_file: tests/test_quiet.py
+"""Test the quiet (exists-only) content search mode."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_quiet_returns_bool():
+    """quiet returns True when anything matches and False otherwise."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("nothing here\n")
+        Path(tmpdir, "b.txt").write_text("needle\n")
+
+        assert vexy_glob.search("needle", root=tmpdir, quiet=True) is True
+        assert vexy_glob.search("missing", root=tmpdir, quiet=True) is False
+
+
+def test_quiet_stops_after_first_match():
+    """The walk stops at the first match instead of scanning everything."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for i in range(500):
+            Path(tmpdir, f"file_{i}.txt").write_text("needle\n" * 10)
+
+        found, stats = vexy_glob.search("needle", root=tmpdir, quiet=True, threads=1, with_stats=True)
+        assert found is True
+        assert stats["matched"] == 1
+        assert stats["entries_visited"] < 50
+
+        _, full_stats = vexy_glob.search("needle", root=tmpdir, threads=1, with_stats=True, as_list=True)
+        assert full_stats["entries_visited"] > 500
+
+
+def test_quiet_with_file_list():
+    """quiet also applies to an explicit file list."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        files = []
+        for i in range(3):
+            path = Path(tmpdir, f"file_{i}.txt")
+            path.write_text("needle\n" if i == 2 else "hay\n")
+            files.append(path)
+
+        assert vexy_glob.search("needle", file_list=files, quiet=True) is True
+        assert vexy_glob.search("needle", file_list=files[:2], quiet=True) is False
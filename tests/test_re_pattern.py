@@ -0,0 +1,62 @@
+# this_file: tests/test_re_pattern.py
+"""Test passing compiled Python regexes as the content pattern."""
+
+import re
+import tempfile
+import pytest
+import vexy_glob
+
+
+SAMPLE = {"a.txt": "Hello world\nhello there\nstart\nend\n"}
+
+
+def test_compiled_pattern_is_case_sensitive_by_default(make_tree):
+    """Without IGNORECASE a lowercase pattern does not get smart case."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, SAMPLE)
+
+        results = vexy_glob.search(re.compile("hello"), "*.txt", root=tmpdir, as_list=True)
+        assert [r["line_text"].strip() for r in results] == ["hello there"]
+
+
+def test_ignorecase_flag_carries_over(make_tree):
+    """re.IGNORECASE matches both spellings."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, SAMPLE)
+
+        results = vexy_glob.search(re.compile("HELLO", re.IGNORECASE), "*.txt", root=tmpdir, as_list=True)
+        assert len(results) == 2
+
+
+def test_dotall_flag_spans_lines(make_tree):
+    """re.DOTALL lets a match cross a line break."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, SAMPLE)
+
+        results = vexy_glob.search(re.compile("start.end", re.DOTALL), "*.txt", root=tmpdir, as_list=True)
+        assert len(results) == 1
+        assert vexy_glob.search(re.compile("start.end"), "*.txt", root=tmpdir, as_list=True) == []
+
+
+def test_verbose_flag_carries_over(make_tree):
+    """re.VERBOSE ignores whitespace in the pattern."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, SAMPLE)
+
+        results = vexy_glob.search(re.compile(r"hello \s there", re.VERBOSE), "*.txt", root=tmpdir, as_list=True)
+        assert len(results) == 1
+
+
+def test_python_only_constructs_rejected(make_tree):
+    """Backreferences and look-around raise PatternError before searching."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, SAMPLE)
+
+        with pytest.raises(vexy_glob.PatternError):
+            vexy_glob.search(re.compile(r"(l)\1"), root=tmpdir)
+        with pytest.raises(vexy_glob.PatternError):
+            vexy_glob.search(re.compile(r"(?P<c>l)(?P=c)"), root=tmpdir)
+        with pytest.raises(vexy_glob.PatternError):
+            vexy_glob.search(re.compile(r"hello(?= world)"), root=tmpdir)
+        # A look-around lookalike inside a class is an ordinary character set
+        assert len(vexy_glob.search(re.compile(r"hello[(?=]?"), "*.txt", root=tmpdir, as_list=True)) == 1
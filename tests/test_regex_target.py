@@ -0,0 +1,71 @@
+# this_file: tests/test_regex_target.py
+"""Test path regex matching against the full path or the file name."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+TREE = ["test_a.py", "pkg/test_b.py", "pkg/helper.py", "test_data/notes.txt"]
+
+
+def test_regex_name_target(make_tree):
+    """A name regex anchored with ^ matches file names."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = list(vexy_glob.find(root=tmpdir, regex=r"^test_.*\.py$", regex_target="name"))
+        names = sorted(Path(r).name for r in results)
+        assert names == ["test_a.py", "test_b.py"]
+
+
+def test_regex_path_target_is_default(make_tree):
+    """The default target is the whole path, which starts with the root."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = list(vexy_glob.find(root=tmpdir, regex=r"^test_.*\.py$"))
+        assert results == []
+
+        results = list(vexy_glob.find(root=tmpdir, regex=r"pkg/test_.*\.py$"))
+        assert [Path(r).name for r in results] == ["test_b.py"]
+
+
+def test_regex_target_with_content_search(make_tree):
+    """regex_target also applies to content search file filtering."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "content")
+
+        results = list(vexy_glob.search("content", root=tmpdir, regex=r"^helper", regex_target="name"))
+        assert len(results) == 1
+        assert Path(results[0]["path"]).name == "helper.py"
+
+
+def test_path_regex_and_content_must_both_match():
+    """Content search only looks inside files whose path matches the regex."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for rel, text in [
+            ("src/a.py", "# TODO: src\n"),
+            ("src/nested/b.py", "# TODO: nested\n"),
+            ("src/clean.py", "pass\n"),
+            ("docs/notes.md", "TODO: docs\n"),
+            ("srcfile.py", "# TODO: top\n"),
+        ]:
+            path = Path(tmpdir, rel)
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(text)
+
+        results = list(vexy_glob.search("TODO", root=tmpdir, regex=r"/src/"))
+        found = sorted(Path(r["path"]).relative_to(tmpdir).as_posix() for r in results)
+        assert found == ["src/a.py", "src/nested/b.py"]
+
+        # The glob narrows the same set further
+        results = list(vexy_glob.search("TODO", pattern="a.py", root=tmpdir, regex=r"/src/"))
+        assert [Path(r["path"]).name for r in results] == ["a.py"]
+
+
+def test_invalid_regex_target():
+    """Unknown targets are rejected."""
+    with pytest.raises(vexy_glob.VexyGlobError):
+        vexy_glob.find(regex="x", regex_target="stem")
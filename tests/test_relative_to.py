@@ -0,0 +1,52 @@
+# this_file: tests/test_relative_to.py
+"""Test relative_to path rewriting for find results."""
+
+import os
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_relative_to_root():
+    """Paths are reported relative to the given base."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sub").mkdir()
+        Path(tmpdir, "a.py").write_text("")
+        Path(tmpdir, "sub", "b.py").write_text("")
+
+        results = vexy_glob.find("**/*.py", root=tmpdir, relative_to=tmpdir, as_list=True)
+        assert sorted(results) == ["a.py", os.path.join("sub", "b.py")]
+
+
+def test_relative_to_multi_root_falls_back_to_absolute():
+    """Roots outside the base keep their absolute paths."""
+    with tempfile.TemporaryDirectory() as inside, tempfile.TemporaryDirectory() as outside:
+        Path(inside, "a.py").write_text("")
+        Path(outside, "b.py").write_text("")
+
+        results = list(vexy_glob.compile("*.py").find([inside, outside], relative_to=inside))
+        assert sorted(results) == sorted(["a.py", os.path.join(os.path.abspath(outside), "b.py")])
+
+
+def test_relative_to_with_relative_root():
+    """A relative search root is resolved before stripping the base."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "src").mkdir()
+        Path(tmpdir, "src", "main.py").write_text("")
+        cwd = os.getcwd()
+        os.chdir(tmpdir)
+        try:
+            results = vexy_glob.find("*.py", root="src", relative_to="src", as_list=True)
+        finally:
+            os.chdir(cwd)
+        assert results == ["main.py"]
+
+
+def test_relative_to_with_size_sort_and_path_objects():
+    """Sorting by size still stats the real files and Path objects stay relative."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "big.txt").write_text("x" * 100)
+        Path(tmpdir, "small.txt").write_text("x")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, relative_to=tmpdir, sort="size", as_path=True)
+        assert results == [Path("small.txt"), Path("big.txt")]
@@ -0,0 +1,69 @@
+# this_file: tests/test_replace.py
+"""Test replace preview and in-place rewriting for content search."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_replace_previews_without_writing():
+    """replace alone adds a replacement key but leaves files untouched."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        target = Path(tmpdir, "a.txt")
+        target.write_text("hello world\nbye world\n")
+
+        results = vexy_glob.search(r"(\w+) world", "*.txt", root=tmpdir, replace="$1 there", as_list=True)
+        assert sorted(r["replacement"].strip() for r in results) == ["bye there", "hello there"]
+        assert "replacements" not in results[0]
+        assert target.read_text() == "hello world\nbye world\n"
+
+
+def test_write_rewrites_file():
+    """write=True rewrites every match in the file and reports the count."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        target = Path(tmpdir, "a.txt")
+        target.write_text("foo foo\nbar\nfoo\n")
+        untouched = Path(tmpdir, "b.txt")
+        untouched.write_text("bar\n")
+        os.chmod(target, 0o640)
+
+        results = vexy_glob.search("foo", "*.txt", root=tmpdir, replace="baz", write=True, as_list=True)
+        assert len(results) == 2
+        assert all(r["replacements"] == 3 for r in results)
+        assert target.read_text() == "baz baz\nbar\nbaz\n"
+        assert untouched.read_text() == "bar\n"
+        assert target.stat().st_mode & 0o777 == 0o640
+        assert not Path(tmpdir, "a.txt.bak").exists()
+        assert sorted(p.name for p in Path(tmpdir).iterdir()) == ["a.txt", "b.txt"]
+
+
+def test_write_with_backup():
+    """backup=True keeps the original content next to the rewritten file."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        target = Path(tmpdir, "a.txt")
+        target.write_text("old value\n")
+
+        list(vexy_glob.search("old", "*.txt", root=tmpdir, replace="new", write=True, backup=True))
+        assert target.read_text() == "new value\n"
+        assert Path(tmpdir, "a.txt.bak").read_text() == "old value\n"
+
+
+def test_write_skips_binary_files():
+    """Files containing NUL bytes are never rewritten."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        target = Path(tmpdir, "data.bin")
+        target.write_bytes(b"foo\x00foo\n")
+
+        list(vexy_glob.search("foo", "*.bin", root=tmpdir, replace="bar", write=True))
+        assert target.read_bytes() == b"foo\x00foo\n"
+
+
+def test_write_requires_replace():
+    """write without replace is rejected before any file is touched."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.search("foo", root=tmpdir, write=True)
+        with pytest.raises(ValueError):
+            vexy_glob.find("*.txt", root=tmpdir, replace="bar")
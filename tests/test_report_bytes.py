@@ -0,0 +1,37 @@
+# this_file: tests/test_report_bytes.py
+"""Test reporting the bytes read per file during content search."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_bytes_read_covers_whole_file():
+    """A full search reads the entire file."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        path = Path(tmpdir, "a.txt")
+        path.write_text("TODO one\nnothing\nTODO two\n")
+
+        results = list(vexy_glob.search("TODO", root=tmpdir, report_bytes=True))
+        assert len(results) == 2
+        assert all(r["bytes_read"] == path.stat().st_size for r in results)
+
+
+def test_bytes_read_stops_early():
+    """Stopping at head_lines reads less than the file holds."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        path = Path(tmpdir, "big.txt")
+        path.write_text("TODO first\n" + "filler line\n" * 20000)
+
+        results = list(vexy_glob.search("TODO", root=tmpdir, head_lines=1, report_bytes=True))
+        assert len(results) == 1
+        assert 0 < results[0]["bytes_read"] < path.stat().st_size
+
+
+def test_bytes_read_absent_by_default():
+    """Results only carry bytes_read when asked."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("TODO\n")
+
+        results = list(vexy_glob.search("TODO", root=tmpdir))
+        assert "bytes_read" not in results[0]
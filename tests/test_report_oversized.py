@@ -0,0 +1,48 @@
+# this_file: tests/test_report_oversized.py
+"""Test reporting files above a size threshold separately from results."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+FILES = {"small.bin": b"x" * 10, "big.bin": b"x" * 500, "huge.bin": b"x" * 1000}
+
+
+def test_oversized_files_are_reported_not_returned(make_tree):
+    """Large files are held back and listed with their sizes."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        it = vexy_glob.find("*.bin", root=tmpdir, report_oversized=100)
+        assert [Path(p).name for p in it] == ["small.bin"]
+        assert sorted((Path(p).name, size) for p, size in it.oversized()) == [("big.bin", 500), ("huge.bin", 1000)]
+
+
+def test_oversized_counted_in_stats(make_tree):
+    """with_stats counts held-back files separately from matches."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        it = vexy_glob.find("*.bin", root=tmpdir, report_oversized=600, with_stats=True)
+        list(it)
+        assert it.stats["matched"] == 2
+        assert it.stats["oversized"] == 1
+
+
+def test_directories_are_never_oversized():
+    """Only regular files are compared against the threshold."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sub").mkdir()
+
+        it = vexy_glob.find("sub", root=tmpdir, report_oversized=0)
+        assert [Path(p).name for p in it] == ["sub"]
+        assert it.oversized() == []
+
+
+def test_report_oversized_requires_streaming():
+    """Collected results have nowhere to report oversized files."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, report_oversized=100, as_list=True)
@@ -0,0 +1,47 @@
+# this_file: tests/test_resolve_owner.py
+"""Test resolving owner and group names of found entries."""
+
+import os
+import sys
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="owner names are resolved on Unix only")
+def test_resolve_owner_matches_passwd_and_group():
+    """Names match what pwd and grp report for the file's ids."""
+    import grp
+    import pwd
+
+    with tempfile.TemporaryDirectory() as tmpdir:
+        target = Path(tmpdir, "a.txt")
+        target.write_text("x")
+        stat = target.stat()
+
+        results = vexy_glob.find("*.txt", root=tmpdir, resolve_owner=True, as_list=True)
+        assert len(results) == 1
+        assert results[0]["path"].endswith("a.txt")
+        assert results[0]["owner"] == pwd.getpwuid(stat.st_uid).pw_name
+        assert results[0]["group"] == grp.getgrgid(stat.st_gid).gr_name
+
+
+def test_resolve_owner_keys_with_other_fields():
+    """Owner keys are added alongside the other requested fields."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("one\ntwo\n")
+
+        results = list(vexy_glob.find("*.txt", root=tmpdir, resolve_owner=True, with_line_count=True))
+        assert len(results) == 1
+        assert set(results[0]) == {"path", "line_count", "owner", "group"}
+        assert results[0]["line_count"] == 2
+        if os.name != "posix":
+            assert results[0]["owner"] is None
+
+
+def test_resolve_owner_rejects_null_separated():
+    """Owner dicts cannot be printed as NUL-separated paths."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, resolve_owner=True, null_separated=True)
@@ -0,0 +1,97 @@
+# this_file: tests/test_scan_stats.py
+"""Test scan statistics reporting."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = ["a.py", "b.py", "c.txt", "sub/d.py"]
+
+
+def test_iterator_stats_after_exhaustion(make_tree):
+    """Streaming iterators expose stats once they are exhausted."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "hello\nworld\n")
+
+        it = vexy_glob.find("*.py", root=tmpdir, with_stats=True)
+        results = list(it)
+        assert len(results) == 3
+
+        stats = it.stats
+        assert stats["matched"] == 3
+        # root, sub/ and four files
+        assert stats["entries_visited"] == 6
+        assert stats["errors"] == 0
+        assert stats["bytes_searched"] == 0
+
+
+def test_stats_none_without_option(make_tree):
+    """Stats are only collected when requested."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "hello\nworld\n")
+
+        it = vexy_glob.find("*.py", root=tmpdir)
+        list(it)
+        assert it.stats is None
+
+
+def test_collected_results_with_stats(make_tree):
+    """Collected results are returned together with their stats."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "hello\nworld\n")
+
+        results, stats = vexy_glob.find("*.py", root=tmpdir, as_list=True, with_stats=True)
+        assert len(results) == 3
+        assert stats["matched"] == 3
+
+
+def test_content_search_counts_bytes(make_tree):
+    """Content search reports matched lines and bytes searched."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "hello\nworld\n")
+
+        results, stats = vexy_glob.search("world", "*.py", root=tmpdir, as_list=True, with_stats=True)
+        assert len(results) == 3
+        assert stats["matched"] == 3
+        assert stats["bytes_searched"] == 3 * len("hello\nworld\n")
+
+
+def test_bytes_searched_stops_with_head_lines():
+    """Files cut short by head_lines only count the bytes that were read."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "big.txt").write_text("header\n" + "filler line\n" * 100_000)
+
+        _, stats = vexy_glob.search("header", "*.txt", root=tmpdir, head_lines=1, as_list=True, with_stats=True)
+        assert 0 < stats["bytes_searched"] < Path(tmpdir, "big.txt").stat().st_size
+
+
+def test_iterator_progress_updates():
+    """Progress is reported every 1000 entries and settles on the final count."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        for d in range(5):
+            sub = Path(tmpdir, f"d{d}")
+            sub.mkdir()
+            for i in range(500):
+                Path(sub, f"f{i}.txt").touch()
+
+        it = vexy_glob.find("*.txt", root=tmpdir, with_stats=True)
+        assert it.progress is None
+        seen = []
+        for _ in it:
+            if it.progress is not None and it.progress not in seen:
+                seen.append(it.progress)
+        assert it.progress == (2506, 2506)
+        updates = seen[:-1]
+        assert updates and all(visited % 1000 == 0 for visited, _ in updates)
+        # The top two levels are the whole tree here, so the estimate is exact
+        assert all(total == 2506 for _, total in updates)
+
+
+def test_no_progress_without_stats(make_tree):
+    """Without with_stats the iterator never reports progress."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE, "hello\nworld\n")
+        it = vexy_glob.find("*.py", root=tmpdir)
+        list(it)
+        assert it.progress is None
@@ -0,0 +1,61 @@
+# this_file: tests/test_search_bytes.py
+"""Test content search over in-memory buffers."""
+
+import re
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+import vexy_glob
+
+
+def test_search_bytes_reports_label():
+    """Results carry the caller's label, line numbers and matches."""
+    data = b"alpha\nbeta gamma\nbeta\n"
+    results = vexy_glob.search_bytes(data, r"beta", label="download.txt")
+    assert [r["line_number"] for r in results] == [2, 3]
+    assert all(r["path"] == "download.txt" for r in results)
+    assert results[0]["line_text"] == "beta gamma\n"
+    assert results[0]["matches"] == ["beta"]
+
+
+def test_search_bytes_accepts_str_and_default_label():
+    """str data is searched as UTF-8 and labelled "<bytes>" by default."""
+    results = vexy_glob.search_bytes("héllo wörld\n", "wörld")
+    assert len(results) == 1
+    assert results[0]["path"] == "<bytes>"
+
+
+def test_search_bytes_options():
+    """Smart case, literals, compiled patterns and replace previews work as for files."""
+    data = b"Foo bar\nfoo baz\n"
+    assert len(vexy_glob.search_bytes(data, "foo")) == 2
+    assert len(vexy_glob.search_bytes(data, "Foo")) == 1
+    assert len(vexy_glob.search_bytes(data, content_literals=["bar", "baz"])) == 2
+    assert len(vexy_glob.search_bytes(data, re.compile("FOO", re.IGNORECASE))) == 2
+
+    results = vexy_glob.search_bytes(data, r"(\w+) bar", replace="$1 qux")
+    assert results[0]["replacement"] == "Foo qux\n"
+
+
+def test_search_bytes_no_match():
+    """A buffer without matches yields an empty list."""
+    assert vexy_glob.search_bytes(b"nothing here\n", "absent") == []
+
+
+def test_search_bytes_errors():
+    """Invalid patterns and missing patterns are rejected."""
+    with pytest.raises(vexy_glob.PatternError):
+        vexy_glob.search_bytes(b"data", "(unclosed")
+    with pytest.raises(ValueError):
+        vexy_glob.search_bytes(b"data")
+
+
+def test_search_bytes_from_threads():
+    """Buffers searched concurrently from several threads keep their own results."""
+    buffers = [(b"filler line\n" * 20000) + f"needle {i}\n".encode() for i in range(4)]
+    with ThreadPoolExecutor(max_workers=4) as pool:
+        results = list(pool.map(lambda data: vexy_glob.search_bytes(data, r"needle \d"), buffers))
+    assert [[r["line_text"] for r in found] for found in results] == [
+        [f"needle {i}\n"] for i in range(4)
+    ]
+    assert all(found[0]["line_number"] == 20001 for found in results)
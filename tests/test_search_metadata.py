@@ -0,0 +1,50 @@
+# this_file: tests/test_search_metadata.py
+"""Test file metadata attached to content search results."""
+
+import os
+import tarfile
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_search_metadata_adds_size_and_mtime():
+    """Each result carries its file's size and mtime."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        target = Path(tmpdir, "a.txt")
+        target.write_text("needle\nhay\nneedle\n")
+        os.utime(target, (1_600_000_000, 1_600_000_000))
+
+        results = vexy_glob.find("*.txt", root=tmpdir, content="needle", search_metadata=True, as_list=True)
+        assert len(results) == 2
+        for result in results:
+            assert result["size"] == target.stat().st_size
+            assert result["mtime"] == 1_600_000_000
+
+
+def test_search_metadata_off_by_default():
+    """Without the flag results have no metadata keys."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("needle\n")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, content="needle", as_list=True)
+        assert "size" not in results[0]
+        assert "mtime" not in results[0]
+
+
+def test_search_metadata_file_list_and_archive_members():
+    """Explicit file lists stat the file; archive members use their header."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        member = Path(tmpdir, "inner.txt")
+        member.write_text("needle in archive\n")
+        os.utime(member, (1_500_000_000, 1_500_000_000))
+        with tarfile.open(Path(tmpdir, "bundle.tar"), "w") as tar:
+            tar.add(member, arcname="inner.txt")
+        loose = Path(tmpdir, "loose.txt")
+        loose.write_text("needle\n")
+
+        results = vexy_glob.find(content="needle", file_list=[loose], search_metadata=True, as_list=True)
+        assert results[0]["size"] == loose.stat().st_size
+
+        results = vexy_glob.find("*.tar", root=tmpdir, content="needle", archives=True, search_metadata=True, as_list=True)
+        assert [(r["size"], r["mtime"]) for r in results] == [(member.stat().st_size, 1_500_000_000)]
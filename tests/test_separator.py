@@ -0,0 +1,68 @@
+# this_file: tests/test_separator.py
+"""Test rewriting the separator of emitted path strings."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_separator_rewrites_path_strings():
+    """Every platform separator in an emitted path becomes the chosen character."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "pkg", "sub").mkdir(parents=True)
+        Path(tmpdir, "pkg", "sub", "a.txt").write_text("x")
+
+        results = vexy_glob.find("**/*.txt", root=tmpdir, relative_to=tmpdir, separator="|", as_list=True)
+        assert results == ["pkg|sub|a.txt"]
+
+
+def test_separator_applies_to_search_results():
+    """Content search results carry the rewritten path too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "pkg").mkdir()
+        Path(tmpdir, "pkg", "a.txt").write_text("needle\n")
+
+        results = vexy_glob.find("**/*.txt", root=tmpdir, content="needle", separator=":", as_list=True)
+        assert len(results) == 1
+        assert results[0]["path"].endswith("pkg:a.txt")
+        assert os.sep not in results[0]["path"]
+
+
+def test_separator_matching_platform_is_unchanged():
+    """The platform's own separator leaves paths as they are."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "pkg").mkdir()
+        Path(tmpdir, "pkg", "a.txt").write_text("x")
+
+        plain = vexy_glob.find("**/*.txt", root=tmpdir, as_list=True)
+        assert vexy_glob.find("**/*.txt", root=tmpdir, separator=os.sep, as_list=True) == plain
+
+
+def test_separator_rejects_path_objects_and_path_style():
+    """The separator only makes sense for path strings in the native style."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(Exception):
+            vexy_glob.find("*", root=tmpdir, separator="/", as_path=True)
+        with pytest.raises(Exception):
+            vexy_glob.find("*", root=tmpdir, separator="/", path_style="posix")
+        with pytest.raises(Exception):
+            vexy_glob.find("*", root=tmpdir, separator="//")
+
+
+def test_separator_keeps_collected_results_statable():
+    """Sorting by size and grouping duplicates look at the files, not the rewritten strings."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "pkg", "sub").mkdir(parents=True)
+        Path(tmpdir, "pkg", "sub", "big.txt").write_text("x" * 300)
+        Path(tmpdir, "pkg", "small.txt").write_text("x")
+        Path(tmpdir, "pkg", "mid.txt").write_text("x" * 20)
+        Path(tmpdir, "pkg", "sub", "copy.txt").write_text("x" * 20)
+
+        by_size = vexy_glob.find("**/*.txt", root=tmpdir, relative_to=tmpdir, separator="|", sort="size")
+        assert by_size[0] == "pkg|small.txt"
+        assert by_size[-1] == "pkg|sub|big.txt"
+
+        duplicates = vexy_glob.find("**/*.txt", root=tmpdir, relative_to=tmpdir, separator="|", dedupe_content=True)
+        assert [sorted(group) for group in duplicates.values()] == [["pkg|mid.txt", "pkg|sub|copy.txt"]]
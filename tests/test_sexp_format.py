@@ -0,0 +1,72 @@
+# this_file: tests/test_sexp_format.py
+"""Test S-expression output for path and content search."""
+
+import os
+import tempfile
+import pytest
+import vexy_glob
+
+
+TREE = {"a.py": 'x = 1\nprint("TODO \\\\ later")\n', "sub/b.py": "TODO TODO\n"}
+
+
+def test_find_paths(make_tree):
+    """Each path becomes a (path "...") form."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        results = vexy_glob.find("**/*.py", root=tmpdir, format="sexp", sort="path")
+        expected = [os.path.join(tmpdir, "a.py"), os.path.join(tmpdir, "sub", "b.py")]
+        assert results == [f'(path "{path}")' for path in expected]
+
+
+def test_find_streams_strings(make_tree):
+    """Streamed results are strings too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        results = list(vexy_glob.find("**/b.py", root=tmpdir, format="sexp"))
+        assert results == [f'(path "{os.path.join(tmpdir, "sub", "b.py")}")']
+
+
+def test_find_fields_and_entries(make_tree):
+    """Requested fields and entry metadata follow as keyword pairs."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        path = os.path.join(tmpdir, "sub", "b.py")
+        [result] = vexy_glob.find("sub/*.py", root=tmpdir, format="sexp", with_depth=True, with_line_count=True, as_list=True)
+        assert result == f'(path "{path}" :depth 2 :line-count 1)'
+        [entry] = vexy_glob.find("sub/*.py", root=tmpdir, format="sexp", as_entry=True, as_list=True)
+        assert entry.startswith(f'(entry "{path}" :is-dir nil :is-file t :is-symlink nil :size 10 :mtime ')
+        mtime = entry.rsplit(" ", 1)[1].rstrip(")")
+        assert "." in mtime and float(mtime) > 0
+
+
+def test_search_results(make_tree):
+    """Content matches carry line, text and matches, with strings escaped."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        results = sorted(vexy_glob.search("TODO", "**/*.py", root=tmpdir, format="sexp", as_list=True))
+        a, b = os.path.join(tmpdir, "a.py"), os.path.join(tmpdir, "sub", "b.py")
+        assert results == sorted([
+            f'(match "{a}" :line-number 2 :line-text "print(\\"TODO \\\\\\\\ later\\")\n" :matches ("TODO") :matches-on-line 1)',
+            f'(match "{b}" :line-number 1 :line-text "TODO TODO\n" :matches ("TODO" "TODO") :matches-on-line 2)',
+        ])
+
+
+def test_search_optional_fields(make_tree):
+    """Optional result fields are appended when their options are set."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        [result] = vexy_glob.search("TODO", "sub/*.py", root=tmpdir, format="sexp", replace="DONE", as_list=True)
+        assert result.endswith(':matches-on-line 2 :replacement "DONE DONE\n")')
+
+
+def test_incompatible_options(make_tree):
+    """Options returning other shapes are rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*.py", root=tmpdir, format="sexp", null_separated=True)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.search("TODO", "*.py", root=tmpdir, format="sexp", quiet=True)
+        with pytest.raises(ValueError):
+            vexy_glob.find("*.py", root=tmpdir, format="edn")
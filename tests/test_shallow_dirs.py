@@ -0,0 +1,56 @@
+# this_file: tests/test_shallow_dirs.py
+"""Test listing matched directories one level deep."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = ["src/app.js", "node_modules/left-pad/index.js", "node_modules/left-pad/lib/util.js", "node_modules/README"]
+
+
+def _relative(results, tmpdir):
+    return sorted(Path(r).relative_to(tmpdir).as_posix() for r in results if r != tmpdir)
+
+
+def test_shallow_dirs_lists_children_only(make_tree):
+    """Children of a shallow directory are yielded, their contents are not."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.find("**/*", root=tmpdir, shallow_dirs="node_modules", as_list=True)
+        assert _relative(results, tmpdir) == [
+            "node_modules",
+            "node_modules/README",
+            "node_modules/left-pad",
+            "src",
+            "src/app.js",
+        ]
+
+
+def test_shallow_dirs_glob_patterns(make_tree):
+    """Patterns are globs matched against the directory name."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.find("*.js", root=tmpdir, shallow_dirs=["node_*", "sr?"], as_list=True)
+        assert _relative(results, tmpdir) == ["src/app.js"]
+
+
+def test_shallow_dirs_sequential_walk(make_tree):
+    """The sequential walk used for dir_order honours shallow_dirs too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = list(vexy_glob.find("**/*", root=tmpdir, shallow_dirs="node_modules", dir_order="pre"))
+        assert "node_modules/left-pad/index.js" not in _relative(results, tmpdir)
+        assert "node_modules/left-pad" in _relative(results, tmpdir)
+
+
+def test_shallow_dirs_content_search(make_tree):
+    """Content search skips files below a shallow directory's children."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = vexy_glob.search("x", "**/*", root=tmpdir, shallow_dirs="node_modules", as_list=True)
+        assert _relative([r["path"] for r in results], tmpdir) == ["node_modules/README", "src/app.js"]
@@ -0,0 +1,34 @@
+# this_file: tests/test_skip_empty_dirs.py
+"""Test leaving empty directories out of results."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+TREE = {"empty/": "", "full/file.txt": "content", "hidden_only/.keep": ""}
+
+
+def test_skip_empty_dirs(make_tree):
+    """Empty directories are dropped, non-empty ones kept."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        results = sorted(Path(p).name for p in vexy_glob.find("*", root=tmpdir, file_type="d"))
+        assert "empty" in results
+
+        results = sorted(Path(p).name for p in vexy_glob.find("*", root=tmpdir, file_type="d", skip_empty_dirs=True))
+        assert "empty" not in results
+        assert "full" in results
+        # A directory holding only hidden entries is not empty
+        assert "hidden_only" in results
+
+
+def test_skip_empty_dirs_keeps_files(make_tree):
+    """Files, including empty ones, are unaffected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        Path(tmpdir, "zero.txt").write_text("")
+
+        results = sorted(Path(p).name for p in vexy_glob.find("*.txt", root=tmpdir, skip_empty_dirs=True))
+        assert results == ["file.txt", "zero.txt"]
@@ -0,0 +1,53 @@
+# this_file: tests/test_skip_fs_types.py
+"""Test keeping the walk out of directories on named filesystem types."""
+
+import sys
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+# Every name the Linux lookup reports, so whatever holds the temp dir is listed
+KNOWN_TYPES = [
+    "nfs", "cifs", "smb2", "smb", "fuse", "afs", "ceph", "9p", "ext4", "xfs",
+    "btrfs", "zfs", "tmpfs", "overlay", "squashfs", "vfat", "exfat", "ntfs",
+    "iso9660", "proc", "sysfs", "apfs", "hfs", "ufs",
+]
+
+
+TREE = ["top.txt", "sub/inner.txt", "sub/deep/leaf.txt"]
+
+
+def _found(results, tmpdir):
+    return sorted(Path(p).relative_to(tmpdir).as_posix() for p in results)
+
+
+def test_unlisted_type_changes_nothing(make_tree):
+    """Naming a type the tree is not on leaves the results alone."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        plain = vexy_glob.find("**/*.txt", root=tmpdir, as_list=True)
+        skipping = vexy_glob.find("**/*.txt", root=tmpdir, skip_fs_types="no-such-fs", as_list=True)
+        assert _found(skipping, tmpdir) == _found(plain, tmpdir)
+        assert len(plain) == 3
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="filesystem types are not looked up on Windows")
+def test_listed_type_is_not_descended_into(make_tree):
+    """Directories on a listed type are pruned, while the root is still walked."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        found = _found(vexy_glob.find("**/*.txt", root=tmpdir, skip_fs_types=KNOWN_TYPES, as_list=True), tmpdir)
+        if "sub/inner.txt" in found:
+            pytest.skip("temp dir is on a filesystem type the lookup does not name")
+        assert found == ["top.txt"]
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="filesystem types are not looked up on Windows")
+def test_type_names_ignore_case(make_tree):
+    """Type names match whatever their case."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        lower = vexy_glob.find("**/*", root=tmpdir, skip_fs_types=KNOWN_TYPES, as_list=True)
+        upper = vexy_glob.find("**/*", root=tmpdir, skip_fs_types=[t.upper() for t in KNOWN_TYPES], as_list=True)
+        assert _found(upper, tmpdir) == _found(lower, tmpdir)
@@ -147,4 +147,54 @@ def test_smart_case_both_patterns():
         # Glob lowercase, content uppercase - case sensitive due to content
         results = list(vexy_glob.search("HELLO", "*test*.txt", root=tmpdir, case_sensitive=None))
         assert len(results) == 1
-        assert "TEST_upper.txt" in results[0]["path"]
\ No newline at end of file
+        assert "TEST_upper.txt" in results[0]["path"]
+
+def test_search_glob_case_matches_find():
+    """search() applies case_sensitive_glob to its file filter exactly like find()."""
+    from vexy_glob import _vexy_glob
+
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "main.py").write_text("hello")
+
+        # Glob, literal fast path, globstar and root-anchored patterns
+        for glob in ["*.PY", "MAIN.PY", "**/*.PY", "/MAIN.PY"]:
+            for case_sensitive in (False, True):
+                found = _vexy_glob.find([tmpdir], glob=glob, case_sensitive_glob=case_sensitive, yield_results=False)
+                searched = _vexy_glob.search(
+                    "hello", [tmpdir], glob=glob, case_sensitive_glob=case_sensitive, yield_results=False
+                )
+                assert len(searched) == len(found) == (0 if case_sensitive else 1), (glob, case_sensitive)
+
+
+def test_search_case_insensitive_glob_with_sensitive_content():
+    """Case-insensitive globs combine with case-sensitive content matching."""
+    from vexy_glob import _vexy_glob
+
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "main.py").write_text("Hello\nhello\n")
+
+        results = _vexy_glob.search(
+            "Hello", [tmpdir], glob="*.PY", case_sensitive_glob=False,
+            case_sensitive_content=True, yield_results=False,
+        )
+        assert [r["line_number"] for r in results] == [1]
+
+
+def test_case_sensitive_content_overrides_content_only():
+    """case_sensitive_content sets the content match apart from the glob."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "main.py").write_text("TODO one\ntodo two\n")
+
+        results = vexy_glob.search(
+            "TODO", "*.PY", root=tmpdir, case_sensitive=False, case_sensitive_content=True, as_list=True
+        )
+        assert [r["line_number"] for r in results] == [1]
+
+        results = vexy_glob.search(
+            "todo", "*.py", root=tmpdir, case_sensitive=True, case_sensitive_content=False, as_list=True
+        )
+        assert [r["line_number"] for r in results] == [1, 2]
+
+        # The glob stays case-sensitive when only the content is relaxed
+        assert vexy_glob.search("todo", "*.PY", root=tmpdir, case_sensitive=True,
+                                case_sensitive_content=False, as_list=True) == []
@@ -0,0 +1,48 @@
+# this_file: tests/test_sort_match_count.py
+"""Test ranking content search results by matches per file."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+FILES = {
+    "one.txt": "TODO\n",
+    "three.txt": "TODO\nskip\nTODO TODO\n",
+    "two_a.txt": "TODO\nTODO\n",
+    "two_b.txt": "TODO TODO\n",
+    "none.txt": "nothing\n",
+}
+
+
+def test_files_ranked_by_match_count(make_tree):
+    """Most matching file first, ties by path, lines kept in order."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        results = vexy_glob.search("TODO", root=tmpdir, sort="match_count")
+        assert isinstance(results, list)
+        order = [(Path(r["path"]).name, r["line_number"]) for r in results]
+        assert order == [
+            ("three.txt", 1),
+            ("three.txt", 3),
+            ("two_a.txt", 1),
+            ("two_a.txt", 2),
+            ("two_b.txt", 1),
+            ("one.txt", 1),
+        ]
+
+
+def test_match_count_needs_content_search():
+    """Path search has no matches to count."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(ValueError):
+            vexy_glob.find("*", root=tmpdir, sort="match_count")
+
+
+def test_match_count_with_quiet_rejected():
+    """quiet answers with a bool, so there is nothing to sort."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.search("TODO", root=tmpdir, sort="match_count", quiet=True)
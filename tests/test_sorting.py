@@ -152,4 +152,18 @@ def test_sort_mixed_types():
         basenames = [os.path.basename(r) for r in results]
         # Filter out the temp directory itself if it appears
         basenames = [b for b in basenames if b.startswith(("file", "dir"))]
-        assert basenames == ["dir1", "dir2", "file1.txt", "file2.txt"]
\ No newline at end of file
+        assert basenames == ["dir1", "dir2", "file1.txt", "file2.txt"]
+
+def test_sort_by_depth():
+    """Test sorting by directory depth, shallowest first, ties broken by path."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        deep = Path(tmpdir, "a", "b", "c", "d")
+        deep.mkdir(parents=True)
+        Path(deep, "aaa.txt").write_text("content")
+        Path(tmpdir, "z").mkdir()
+        Path(tmpdir, "z", "zzz.txt").write_text("content")
+        Path(tmpdir, "z", "yyy.txt").write_text("content")
+
+        results = vexy_glob.find("**/*.txt", root=tmpdir, sort="depth")
+        names = [os.path.basename(r) for r in results]
+        assert names == ["yyy.txt", "zzz.txt", "aaa.txt"]
@@ -0,0 +1,44 @@
+# this_file: tests/test_stream_sort_window.py
+"""Test approximately sorted streaming through a bounded reorder buffer."""
+
+import tempfile
+import pytest
+import vexy_glob
+
+
+TREE = [f"{d}/f{i}.txt" for d in ("a", "b", "c") for i in range(5)]
+
+
+def test_large_window_sorts_fully(make_tree):
+    """A window holding every result yields them in path order."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        results = list(vexy_glob.find("**/*.txt", root=tmpdir, stream_sort_window=100))
+        assert len(results) == 15
+        assert results == sorted(results)
+
+
+def test_small_window_yields_everything(make_tree):
+    """A window smaller than the result set still yields every result once."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        plain = sorted(vexy_glob.find("**/*.txt", root=tmpdir))
+        for window in (1, 4):
+            results = list(vexy_glob.find("**/*.txt", root=tmpdir, stream_sort_window=window))
+            assert sorted(results) == plain
+
+
+def test_window_with_entries(make_tree):
+    """Entry objects are ordered by their path as well."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+        results = list(vexy_glob.find("**/*.txt", root=tmpdir, as_entry=True, stream_sort_window=100))
+        paths = [r.path for r in results]
+        assert paths == sorted(paths)
+
+
+def test_zero_window_rejected():
+    """A window of zero is rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(Exception):
+            list(vexy_glob.find("*", root=tmpdir, stream_sort_window=0))
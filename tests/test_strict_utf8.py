@@ -0,0 +1,53 @@
+# this_file: tests/test_strict_utf8.py
+"""Test reporting non-UTF-8 paths instead of converting them lossily."""
+
+import os
+import sys
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def make_files(tmpdir):
+    """A valid file next to one whose name is not valid UTF-8."""
+    if sys.platform in ("win32", "darwin"):
+        pytest.skip("file names must be valid Unicode on this platform")
+    Path(tmpdir, "good.txt").write_text("needle\n")
+    bad = os.path.join(os.fsencode(tmpdir), b"bad\xff.txt")
+    try:
+        with open(bad, "w") as f:
+            f.write("needle\n")
+    except OSError:
+        pytest.skip("file system rejects non-UTF-8 names")
+
+
+def test_lossy_by_default():
+    """Without strict_utf8 the mangled path is yielded."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_files(tmpdir)
+
+        names = sorted(Path(p).name for p in vexy_glob.find("*.txt", root=tmpdir))
+        assert names == ["bad�.txt", "good.txt"]
+
+
+def test_strict_utf8_reports_error():
+    """strict_utf8 reports the path with its lossy form instead of yielding it."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_files(tmpdir)
+
+        it = vexy_glob.find("*.txt", root=tmpdir, strict_utf8=True)
+        assert [Path(p).name for p in it] == ["good.txt"]
+        assert len(it.errors()) == 1
+        assert "not valid UTF-8" in it.errors()[0]
+        assert "bad�.txt" in it.errors()[0]
+
+
+def test_strict_utf8_content_search():
+    """Content search skips and reports the file too."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_files(tmpdir)
+
+        it = vexy_glob.search("needle", "*.txt", root=tmpdir, strict_utf8=True)
+        assert [Path(r["path"]).name for r in it] == ["good.txt"]
+        assert len(it.errors()) == 1
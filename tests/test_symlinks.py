@@ -328,3 +328,107 @@ def test_symlink_parameter_validation():
 
         # Results should be the same when no symlinks are present
         assert len(results_false) == len(results_true)
+
+
+LINK_TREE = {
+    "external/external_file.txt": "needle in external",
+    "target.txt": "needle in target",
+    "search/regular.txt": "regular",
+}
+LINKS = {"search/file_link.txt": "../target.txt", "search/dir_link": "../external"}
+
+
+def test_follow_file_symlinks_only(make_tree):
+    """File links resolve to files while directory links are not descended."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        search = make_tree(tmpdir, LINK_TREE, links=LINKS) / "search"
+
+        files = sorted(Path(p).name for p in vexy_glob.find(
+            "*", root=search, file_type="f", follow_file_symlinks=True
+        ))
+        assert files == ["file_link.txt", "regular.txt"]
+
+        links = [Path(p).name for p in vexy_glob.find(
+            "*", root=search, file_type="l", follow_file_symlinks=True
+        )]
+        assert links == ["dir_link"]
+
+        # Size filters see the target's size
+        sized = [Path(p).name for p in vexy_glob.find(
+            "*.txt", root=search, min_size=10, follow_file_symlinks=True
+        )]
+        assert sized == ["file_link.txt"]
+
+        # Content search reads through the file link
+        matches = vexy_glob.search("needle", root=search, follow_file_symlinks=True, as_list=True)
+        assert [Path(m["path"]).name for m in matches] == ["file_link.txt"]
+
+
+def test_follow_dir_symlinks_only(make_tree):
+    """Directory links are descended while file links stay links."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        search = make_tree(tmpdir, LINK_TREE, links=LINKS) / "search"
+
+        files = sorted(Path(p).name for p in vexy_glob.find(
+            "*", root=search, file_type="f", follow_dir_symlinks=True
+        ))
+        assert files == ["external_file.txt", "regular.txt"]
+
+        links = [Path(p).name for p in vexy_glob.find(
+            "*", root=search, file_type="l", follow_dir_symlinks=True
+        )]
+        assert links == ["file_link.txt"]
+
+        matches = vexy_glob.search("needle", root=search, follow_dir_symlinks=True, as_list=True)
+        assert [Path(m["path"]).name for m in matches] == ["external_file.txt"]
+
+
+def test_follow_symlinks_split_overrides(make_tree):
+    """The per-kind options override follow_symlinks."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        search = make_tree(tmpdir, LINK_TREE, links=LINKS) / "search"
+
+        files = sorted(Path(p).name for p in vexy_glob.find(
+            "*", root=search, file_type="f", follow_symlinks=True, follow_dir_symlinks=False
+        ))
+        assert files == ["file_link.txt", "regular.txt"]
+
+        files = sorted(Path(p).name for p in vexy_glob.find(
+            "*", root=search, file_type="f", follow_symlinks=True
+        ))
+        assert files == ["external_file.txt", "file_link.txt", "regular.txt"]
+
+
+def test_symlink_target_type_filter(make_tree):
+    """Symlinks can be filtered by what they point to, yielding the link path."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        search = make_tree(tmpdir, LINK_TREE, links=LINKS) / "search"
+        (search / "broken_link").symlink_to(Path(tmpdir) / "missing")
+
+        dir_links = vexy_glob.find("*", root=search, file_type="l", symlink_target_type="d", as_list=True)
+        assert dir_links == [str(search / "dir_link")]
+
+        file_links = vexy_glob.find("*", root=search, file_type="l", symlink_target_type="f", as_list=True)
+        assert file_links == [str(search / "file_link.txt")]
+
+        # Regular entries are not affected by the target filter
+        names = sorted(Path(p).name for p in vexy_glob.find("*", root=search, symlink_target_type="f"))
+        assert "regular.txt" in names and "dir_link" not in names
+
+
+def test_symlink_broken_option(make_tree):
+    """Dangling symlinks can be kept or dropped explicitly."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        search = make_tree(tmpdir, LINK_TREE, links=LINKS) / "search"
+        (search / "broken_link").symlink_to(Path(tmpdir) / "missing")
+
+        def links(**kwargs):
+            return sorted(Path(p).name for p in vexy_glob.find("*", root=search, file_type="l", **kwargs))
+
+        assert "broken_link" in links()
+        assert links(symlink_broken=False) == ["dir_link", "file_link.txt"]
+        assert links(symlink_target_type="d") == ["dir_link"]
+        assert links(symlink_target_type="d", symlink_broken=True) == ["broken_link", "dir_link"]
+
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=search, symlink_target_type="l")
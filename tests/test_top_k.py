@@ -0,0 +1,58 @@
+# this_file: tests/test_top_k.py
+"""Test progressively refined largest-file snapshots."""
+
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+FILES = {
+    "a.bin": b"x" * 10,
+    "b.bin": b"x" * 500,
+    "c.bin": b"x" * 30,
+    "d.bin": b"x" * 200,
+    "e.bin": b"",
+    "sub/f.bin": b"x" * 300,
+}
+
+
+def test_final_update_holds_largest_files(make_tree):
+    """The last update is exact, largest first, after all the results."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, FILES)
+
+        items = list(vexy_glob.find("**/*.bin", root=tmpdir, top_k=3))
+        updates = [item for item in items if isinstance(item, vexy_glob.TopKUpdate)]
+        paths = [item for item in items if not isinstance(item, vexy_glob.TopKUpdate)]
+
+        assert len(paths) == 6
+        assert items[-1] is updates[-1]
+        assert updates[-1].final
+        assert not any(update.final for update in updates[:-1])
+        assert [(Path(p).name, size) for p, size in updates[-1].files] == [
+            ("b.bin", 500),
+            ("f.bin", 300),
+            ("d.bin", 200),
+        ]
+
+
+def test_fewer_files_than_k():
+    """With fewer files than k, every file is listed."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "only.txt").write_text("abc")
+        Path(tmpdir, "dir").mkdir()
+
+        items = list(vexy_glob.find("*", root=tmpdir, top_k=5))
+        final = items[-1]
+        assert isinstance(final, vexy_glob.TopKUpdate)
+        assert [(Path(p).name, size) for p, size in final.files] == [("only.txt", 3)]
+
+
+def test_top_k_needs_streaming():
+    """Collected results have nowhere to put the updates."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, top_k=3, as_list=True)
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, top_k=0)
@@ -0,0 +1,58 @@
+# this_file: tests/test_total_size.py
+"""Test summing the sizes of matching files without collecting paths."""
+
+import os
+import tempfile
+import pytest
+import vexy_glob
+
+
+TREE = {"a.txt": b"x" * 100, "sub/b.txt": b"x" * 250, "sub/c.log": b"x" * 1000}
+
+
+def test_total_size_sums_matching_files(make_tree):
+    """Only files matching the pattern and filters are counted."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        assert vexy_glob.total_size("*.txt", root=tmpdir) == 350
+        assert vexy_glob.total_size(root=tmpdir) == 1350
+        assert vexy_glob.total_size(root=tmpdir, max_size=500) == 350
+        assert vexy_glob.total_size("*.md", root=tmpdir) == 0
+
+
+def test_total_size_skips_directories(make_tree):
+    """Directories match the pattern but add nothing to the total."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        assert vexy_glob.total_size("sub", root=tmpdir) == 0
+        assert vexy_glob.total_size(root=tmpdir, file_type="d") == 0
+
+
+def test_total_size_with_stats(make_tree):
+    """with_stats returns the total alongside the scan statistics."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        total, stats = vexy_glob.total_size(root=tmpdir, with_stats=True)
+        assert total == 1350
+        assert stats["entries_visited"] >= 4
+
+
+def test_total_size_matches_python_sum(make_tree):
+    """The total equals summing os.path.getsize over find() results."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir, TREE)
+
+        files = vexy_glob.find("**/*", root=tmpdir, file_type="f", as_list=True)
+        assert vexy_glob.total_size("**/*", root=tmpdir) == sum(os.path.getsize(f) for f in files)
+
+
+def test_total_size_rejects_incompatible_options():
+    """Options that need paths cannot be combined."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.total_size(root=tmpdir, group_by_dir=True)
+        with pytest.raises(ValueError):
+            vexy_glob.find("*", root=tmpdir, content="x", total_size=True)
@@ -0,0 +1,26 @@
+# this_file: tests/test_validate.py
+"""Test validating patterns without running a search."""
+
+import vexy_glob
+
+
+def test_validate_glob():
+    """Valid globs return None, invalid ones the compile error."""
+    assert vexy_glob.validate_glob("**/*.py") is None
+    assert vexy_glob.validate_glob("src/[ab]*.RS", case_sensitive=False) is None
+    error = vexy_glob.validate_glob("*.{py")
+    assert isinstance(error, str) and error
+
+
+def test_validate_glob_reports_regex_syntax():
+    """Regex-looking globs get the same hint find() raises."""
+    error = vexy_glob.validate_glob("^main.py$")
+    assert error is not None
+    assert "regex=" in error
+
+
+def test_validate_regex():
+    """Valid regexes return None, invalid ones the compile error."""
+    assert vexy_glob.validate_regex(r"test_\w+\.py$") is None
+    error = vexy_glob.validate_regex("(unclosed")
+    assert isinstance(error, str) and error
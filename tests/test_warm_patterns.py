@@ -0,0 +1,40 @@
+# this_file: tests/test_warm_patterns.py
+"""Test pre-compiling patterns into the pattern cache."""
+
+import tempfile
+import uuid
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_warm_patterns_counts_new_and_cached():
+    """New patterns are compiled once; repeats and common patterns are cached."""
+    unique = f"**/*.{uuid.uuid4().hex}"
+
+    assert vexy_glob.warm_patterns([unique]) == {"compiled": 1, "cached": 0}
+    assert vexy_glob.warm_patterns([unique, "*.py"]) == {"compiled": 0, "cached": 2}
+
+
+def test_warm_patterns_respects_case_sensitivity():
+    """Each case sensitivity is its own cache entry."""
+    unique = f"*.{uuid.uuid4().hex}"
+
+    assert vexy_glob.warm_patterns([unique], case_sensitive=True)["compiled"] == 1
+    assert vexy_glob.warm_patterns([unique], case_sensitive=False)["compiled"] == 1
+    assert vexy_glob.warm_patterns([unique], case_sensitive=False)["cached"] == 1
+
+
+def test_warmed_pattern_still_matches():
+    """find() works as usual with a warmed pattern."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.warm").write_text("x")
+        vexy_glob.warm_patterns(["*.warm"])
+
+        assert [Path(p).name for p in vexy_glob.find("*.warm", root=tmpdir, as_list=True)] == ["a.warm"]
+
+
+def test_warm_patterns_rejects_invalid_glob():
+    """Invalid globs raise PatternError."""
+    with pytest.raises(vexy_glob.PatternError):
+        vexy_glob.warm_patterns(["src/[unclosed"])
@@ -0,0 +1,43 @@
+# this_file: tests/test_whole_line.py
+"""Test whole-line content matching (grep -x)."""
+
+import tempfile
+from pathlib import Path
+import vexy_glob
+
+
+def test_whole_line_requires_full_match():
+    """Lines with extra text around the match are skipped."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.cfg").write_text("debug=true\nverbose debug=true\ndebug=true # note\n")
+
+        results = vexy_glob.search(r"debug=\w+", "*.cfg", root=tmpdir, whole_line=True, as_list=True)
+        assert [r["line_number"] for r in results] == [1]
+        assert results[0]["matches"] == ["debug=true"]
+
+
+def test_whole_line_alternation_is_grouped():
+    """The pattern is wrapped in a group, so each branch must fill the line."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("foo\nfoobar\nbar\nxbar\n")
+
+        results = vexy_glob.search("foo|bar", "*.txt", root=tmpdir, whole_line=True, as_list=True)
+        assert [r["line_number"] for r in results] == [1, 3]
+
+
+def test_whole_line_with_literals():
+    """content_literals honour whole_line as well."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("a.b\na.b.c\nxyz\n")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, content_literals=["a.b", "xyz"], whole_line=True, as_list=True)
+        assert [r["line_number"] for r in results] == [1, 3]
+
+
+def test_whole_line_with_dotall():
+    """With dotall a match may cover several complete lines."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("begin\nmiddle\nend\nbegin\nend tail\n")
+
+        results = vexy_glob.search("begin.*?end", "*.txt", root=tmpdir, dotall=True, whole_line=True, as_list=True)
+        assert [r["line_number"] for r in results] == [1]
@@ -0,0 +1,60 @@
+# this_file: tests/test_with_depth.py
+"""Test reporting the walk depth of each result."""
+
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def make_tree(tmpdir):
+    for rel in ["top.txt", "a/one.txt", "a/b/two.txt", "a/b/c/three.txt"]:
+        path = Path(tmpdir, rel)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text("x")
+
+
+def test_depth_across_nested_structure():
+    """Each result carries its depth below the root."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir)
+
+        results = vexy_glob.find("*.txt", root=tmpdir, with_depth=True)
+        depths = {Path(r["path"]).name: r["depth"] for r in results}
+        assert depths == {"top.txt": 1, "one.txt": 2, "two.txt": 3, "three.txt": 4}
+
+
+def test_depth_independent_of_root_form():
+    """Relative and absolute roots report the same depths."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir)
+
+        absolute = vexy_glob.find("*.txt", root=Path(tmpdir, "a"), with_depth=True, sort="depth")
+        cwd = os.getcwd()
+        os.chdir(tmpdir)
+        try:
+            relative = vexy_glob.find("*.txt", root="a", with_depth=True, sort="depth")
+        finally:
+            os.chdir(cwd)
+        assert [r["depth"] for r in absolute] == [1, 2, 3]
+        assert [r["depth"] for r in relative] == [1, 2, 3]
+
+
+def test_depth_with_entries_and_path_objects():
+    """The path keeps the requested type inside the dict."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        make_tree(tmpdir)
+
+        entries = vexy_glob.find("two.txt", root=tmpdir, with_depth=True, as_entry=True, as_list=True)
+        assert entries[0]["depth"] == 3
+        assert entries[0]["path"].is_file
+        paths = vexy_glob.find("top.txt", root=tmpdir, with_depth=True, as_path=True, as_list=True)
+        assert paths == [{"path": Path(tmpdir, "top.txt"), "depth": 1}]
+
+
+def test_with_depth_rejects_null_separated():
+    """A NUL-joined byte string has nowhere to put depths."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(Exception):
+            vexy_glob.find("*.txt", root=tmpdir, with_depth=True, null_separated=True)
@@ -0,0 +1,54 @@
+# this_file: tests/test_with_hash.py
+"""Test content digests in find results."""
+
+import hashlib
+import os
+import tempfile
+from pathlib import Path
+import pytest
+import vexy_glob
+
+
+def test_sha256_matches_hashlib():
+    """The sha256 digest equals hashlib's."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        data = b"hello\n" * 50000
+        Path(tmpdir, "a.bin").write_bytes(data)
+
+        results = list(vexy_glob.find("*.bin", root=tmpdir, with_hash="sha256"))
+        assert len(results) == 1
+        assert results[0]["hash"] == hashlib.sha256(data).hexdigest()
+
+
+def test_xxh3_identifies_equal_contents():
+    """Equal files share an xxh3 digest; different files do not."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "a.txt").write_text("same")
+        Path(tmpdir, "b.txt").write_text("same")
+        Path(tmpdir, "c.txt").write_text("other")
+
+        results = vexy_glob.find("*.txt", root=tmpdir, with_hash="xxh3", as_list=True)
+        hashes = {Path(r["path"]).name: r["hash"] for r in results}
+        assert hashes["a.txt"] == hashes["b.txt"] != hashes["c.txt"]
+        assert len(hashes["a.txt"]) == 16
+
+
+def test_directories_and_symlinks_get_none():
+    """Only regular files are hashed."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        Path(tmpdir, "sub").mkdir()
+        Path(tmpdir, "file.txt").write_text("x")
+        os.symlink(Path(tmpdir, "file.txt"), Path(tmpdir, "link.txt"))
+
+        results = vexy_glob.find("*", root=tmpdir, with_hash="sha256", as_list=True)
+        hashes = {Path(r["path"]).name: r["hash"] for r in results}
+        assert hashes["sub"] is None
+        assert hashes["link.txt"] is None
+        assert hashes["file.txt"] is not None
+
+
+def test_unknown_algorithm():
+    """Unsupported algorithms are rejected."""
+    with tempfile.TemporaryDirectory() as tmpdir:
+        with pytest.raises(vexy_glob.VexyGlobError):
+            vexy_glob.find("*", root=tmpdir, with_hash="md5")
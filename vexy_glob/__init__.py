@@ -8,9 +8,11 @@ A high-performance Python extension for file system traversal and searching.
 from __future__ import annotations
 
 import os
+import re
 from pathlib import Path
-from typing import Union, List, Iterator, Optional, Literal, TYPE_CHECKING
+from typing import Union, List, Dict, Iterator, Optional, Literal, Callable, Any, Tuple, TYPE_CHECKING
 from datetime import datetime, timezone
+import itertools
 import time
 
 # Import the Rust extension module
@@ -20,6 +22,11 @@ except ImportError:
     # Development mode - module not built yet
     _vexy_glob = None
 
+CompiledPattern = _vexy_glob.CompiledPattern if _vexy_glob is not None else None
+Entry = _vexy_glob.Entry if _vexy_glob is not None else None
+DirEvent = _vexy_glob.DirEvent if _vexy_glob is not None else None
+TopKUpdate = _vexy_glob.TopKUpdate if _vexy_glob is not None else None
+
 if TYPE_CHECKING:
     from typing import TypedDict
 
@@ -30,14 +37,29 @@ if TYPE_CHECKING:
         line_number: int
         line_text: str
         matches: List[str]
+        matches_on_line: int
 
 
 __version__ = "0.1.0"
 __all__ = [
     "find",
+    "find_null_separated",
+    "find_roots",
+    "total_size",
+    "count_by_extension",
+    "compile",
+    "warm_patterns",
+    "validate_glob",
+    "validate_regex",
+    "analyze_content_pattern",
+    "CompiledPattern",
+    "Entry",
+    "DirEvent",
+    "TopKUpdate",
     "glob",
     "iglob",
     "search",
+    "search_bytes",
     "VexyGlobError",
     "PatternError",
     "SearchError",
@@ -130,6 +152,37 @@ def _parse_time_param(value: Union[float, int, str, datetime, None]) -> Optional
     raise TypeError(f"Unsupported time type: {type(value)}")
 
 
+def _parse_duration_param(value: Union[float, int, str, None]) -> Optional[float]:
+    """
+    Convert a duration to seconds.
+
+    Supports:
+    - float/int: Number of seconds (returned as-is)
+    - str: Number with unit suffix (30s, 5m, 2h, 1d, 1w)
+    - None: Returns None
+    """
+    if value is None:
+        return None
+
+    if isinstance(value, (int, float)):
+        return float(value)
+
+    if isinstance(value, str):
+        units = {"s": 1, "m": 60, "h": 3600, "d": 86400, "w": 604800}
+        text = value.strip().lower()
+        try:
+            if text and text[-1] in units:
+                return float(text[:-1]) * units[text[-1]]
+            return float(text)
+        except ValueError:
+            raise ValueError(
+                f"Invalid duration format: {value}. "
+                "Use seconds or a number with a unit (30s, 5m, 2h, 1d, 1w)"
+            )
+
+    raise TypeError(f"Unsupported duration type: {type(value)}")
+
+
 import functools
 
 @functools.lru_cache(maxsize=128)
@@ -163,55 +216,275 @@ def _is_case_sensitive_pattern(pattern: str) -> bool:
     return _has_uppercase(pattern)
 
 
+# Python regex flags that map onto inline flags of the Rust regex engine
+_RE_INLINE_FLAGS = ((re.IGNORECASE, "i"), (re.MULTILINE, "m"), (re.VERBOSE, "x"))
+_RE_SUPPORTED_FLAGS = re.IGNORECASE | re.MULTILINE | re.DOTALL | re.VERBOSE | re.UNICODE
+
+
+def _translate_re_pattern(compiled: "re.Pattern") -> tuple:
+    """Turn a compiled Python regex into a Rust regex string and a dotall flag.
+
+    IGNORECASE, MULTILINE and VERBOSE become inline flags so they apply to the
+    content match and the replacement alike; DOTALL switches on the multi-line
+    searcher. Constructs the Rust engine lacks are rejected up front.
+    """
+    source = compiled.pattern
+    if isinstance(source, bytes):
+        raise PatternError("Bytes regex patterns are not supported for content search", repr(source))
+    unsupported = compiled.flags & ~_RE_SUPPORTED_FLAGS
+    if unsupported:
+        raise PatternError(f"Unsupported regex flags {re.RegexFlag(unsupported)!r}", source)
+
+    in_class = False
+    i = 0
+    while i < len(source):
+        c = source[i]
+        if c == "\\" and i + 1 < len(source):
+            nxt = source[i + 1]
+            if nxt.isdigit():
+                raise PatternError(
+                    "Backreferences and octal escapes are not supported by the content regex engine", source
+                )
+            i += 2
+            continue
+        if c == "[":
+            in_class = True
+        elif c == "]":
+            in_class = False
+        elif not in_class and source.startswith("(?P=", i):
+            raise PatternError("Backreferences are not supported by the content regex engine", source)
+        elif not in_class and source.startswith(("(?=", "(?!", "(?<=", "(?<!"), i):
+            raise PatternError("Look-around is not supported by the content regex engine", source)
+        i += 1
+
+    inline = "".join(letter for flag, letter in _RE_INLINE_FLAGS if compiled.flags & flag)
+    if inline:
+        source = f"(?{inline}){source}"
+    return source, bool(compiled.flags & re.DOTALL)
+
+
+def _parse_line_terminator(value: Union[str, bytes, None]) -> Optional[int]:
+    """Convert a one-character line terminator to the byte value Rust expects."""
+    if value is None:
+        return None
+    if isinstance(value, str):
+        value = value.encode()
+    if len(value) != 1:
+        raise ValueError("line_terminator must be a single byte")
+    return value[0]
+
+
 def find(
     pattern: str = "*",
     root: Union[str, Path] = ".",
     *,
-    content: Optional[str] = None,
-    file_type: Optional[str] = None,
+    content: Optional[Union[str, "re.Pattern"]] = None,
+    content_literals: Optional[List[str]] = None,
+    file_list: Optional[List[Union[str, Path]]] = None,
+    name_filter: Optional[str] = None,
+    regex: Optional[str] = None,
+    regex_target: Literal["path", "name"] = "path",
+    file_type: Optional[Union[str, List[str]]] = None,
+    symlink_target_type: Optional[Literal["f", "d"]] = None,
+    symlink_broken: Optional[bool] = None,
+    content_type: Optional[Literal["text", "binary"]] = None,
+    skip_empty_dirs: bool = False,
+    dirs_with_matches: bool = False,
     extension: Optional[Union[str, List[str]]] = None,
     exclude: Optional[Union[str, List[str]]] = None,
+    exclude_name: Optional[Union[str, List[str]]] = None,
+    predicate: Optional[Callable[[Any], Any]] = None,
     max_depth: Optional[int] = None,
+    shallow_dirs: Optional[Union[str, List[str]]] = None,
     min_depth: int = 0,
+    per_dir_limit: Optional[int] = None,
     min_size: Optional[int] = None,
     max_size: Optional[int] = None,
+    min_lines: Optional[int] = None,
+    max_lines: Optional[int] = None,
+    report_oversized: Optional[int] = None,
     mtime_after: Optional[Union[float, int, str, datetime]] = None,
     mtime_before: Optional[Union[float, int, str, datetime]] = None,
+    changed_within: Optional[Union[float, int, str]] = None,
+    changed_before: Optional[Union[float, int, str]] = None,
+    newer_than_file: Optional[Union[str, Path]] = None,
     atime_after: Optional[Union[float, int, str, datetime]] = None,
     atime_before: Optional[Union[float, int, str, datetime]] = None,
     ctime_after: Optional[Union[float, int, str, datetime]] = None,
     ctime_before: Optional[Union[float, int, str, datetime]] = None,
+    include_inaccessible: bool = False,
     hidden: bool = False,
+    hidden_only: bool = False,
     ignore_git: bool = False,
+    no_vcs: bool = False,
+    parents: bool = True,
+    require_git: bool = True,
+    git_tracked_only: bool = False,
+    git_status: Optional[Union[str, List[str]]] = None,
     custom_ignore_files: Optional[Union[str, List[str]]] = None,
     case_sensitive: Optional[bool] = None,  # None = smart case
+    case_sensitive_content: Optional[bool] = None,
+    dotall: bool = False,
+    whole_line: bool = False,
+    line_terminator: Optional[Union[str, bytes]] = None,
+    encoding: Optional[str] = None,
+    unique_matches: bool = False,
+    distinct_matches: bool = False,
+    pattern_histogram: bool = False,
+    min_match_length: Optional[int] = None,
+    head_lines: Optional[int] = None,
+    format: Literal["lines", "hunks", "sexp"] = "lines",
+    context: int = 3,
+    highlight: Optional[Tuple[str, str]] = None,
+    archives: bool = False,
+    quiet: bool = False,
+    replace: Optional[str] = None,
+    write: bool = False,
+    backup: bool = False,
+    search_metadata: bool = False,
+    report_bytes: bool = False,
+    full_path: bool = False,
+    literal_separator: bool = True,
+    case_sensitive_exclude: Optional[bool] = None,
     follow_symlinks: bool = False,
+    follow_file_symlinks: Optional[bool] = None,
+    follow_dir_symlinks: Optional[bool] = None,
+    metadata_follow: Optional[bool] = None,
+    max_symlink_hops: Optional[int] = None,
     same_file_system: bool = False,
-    sort: Optional[Literal["name", "path", "size", "mtime"]] = None,
+    skip_fs_types: Optional[Union[str, List[str]]] = None,
+    canonicalize_dedup: bool = False,
+    sort: Optional[Literal["name", "path", "depth", "size", "mtime", "match_count"]] = None,
+    dir_order: Literal["default", "pre", "post"] = "default",
+    emit_dir_events: bool = False,
+    priority_roots: Optional[Union[str, Path, List[Union[str, Path]]]] = None,
+    stream_sort_window: Optional[int] = None,
+    top_k: Optional[int] = None,
+    group_by_dir: bool = False,
+    dedupe_content: bool = False,
+    null_separated: bool = False,
+    total_size: bool = False,
+    count_by_extension: bool = False,
+    with_stats: bool = False,
+    batch_size: Optional[int] = None,
     threads: Optional[int] = None,
+    deterministic: bool = False,
+    max_open_files: Optional[int] = None,
     as_path: bool = False,
+    path_style: Literal["native", "posix"] = "native",
+    separator: Optional[str] = None,
+    strict_utf8: bool = False,
+    fail_fast: bool = False,
+    as_entry: bool = False,
+    with_depth: bool = False,
+    with_line_count: bool = False,
+    resolve_owner: bool = False,
+    preview_bytes: Optional[int] = None,
+    with_hash: Optional[Literal["xxh3", "sha256"]] = None,
+    classify: Optional[Dict[str, str]] = None,
+    exclude_unclassified: bool = False,
+    relative_to: Optional[Union[str, Path]] = None,
+    debug_excludes: bool = False,
     as_list: bool = False,
-) -> Union[Iterator[Union[str, Path]], List[Union[str, Path]]]:
+) -> Union[
+    Iterator[Union[str, Path]],
+    List[Union[str, Path]],
+    Dict[str, List[Union[str, Path]]],
+    bytes,
+    bool,
+]:
     """
     Find files and directories with high performance.
 
     Args:
-        pattern: Glob pattern to match against file paths (default: "*")
+        pattern: Glob pattern to match against file paths (default: "*").
+                 A leading "/" anchors the pattern to root (e.g. "/src/*.rs")
         root: Starting directory for search (default: current directory)
-        content: Optional regex pattern to search within file contents
-        file_type: Filter by type: 'f' (files), 'd' (directories), 'l' (symlinks)
-        extension: Filter by file extension(s), e.g. "py" or ["py", "pyx"]
+        content: Optional regex pattern to search within file contents. A compiled
+                 re.Pattern is accepted too: its IGNORECASE, MULTILINE, DOTALL and
+                 VERBOSE flags carry over and it is matched case-sensitively
+                 unless compiled with IGNORECASE. Backreferences and look-around
+                 raise PatternError since the Rust engine lacks them
+        content_literals: Exact strings to search for instead of a regex, matched
+                          with an Aho-Corasick automaton; much faster than a
+                          large alternation (mutually exclusive with content)
+        file_list: Explicit files to search instead of walking root (content search
+                   only). Path filters are not applied; files that cannot be opened
+                   are reported as errors and skipped
+        name_filter: Glob matched against the file name only, in addition to
+                     the pattern, e.g. "test_*.py" with pattern "tests/**".
+                     Keeps directory structure in the pattern and file names
+                     here (content search only)
+        regex: Optional regex that paths must match (in addition to the glob)
+        regex_target: What the regex is matched against (default: "path").
+                      "path" matches the whole path string including the root, so
+                      anchors like "^test_" see the root prefix first. "name" matches
+                      the file name only, e.g. r"^test_.*\.py$".
+        file_type: Filter by type: 'f' (files), 'd' (directories), 'l' (symlinks).
+                   A list keeps entries of any listed type, e.g. ["f", "l"], and
+                   a '!' prefix leaves a type out, e.g. "!d" for everything
+                   but directories
+        symlink_target_type: Keep only symlinks pointing to 'f' (files) or 'd'
+                             (directories); the link path itself is returned and
+                             other entries are unaffected
+        symlink_broken: Whether dangling symlinks are kept. None (default) keeps
+                        them unless symlink_target_type is set, since they have
+                        no target type
+        content_type: Only include regular files whose first 8KB look like 'text'
+                      (no NUL byte) or 'binary'. Directories and symlinks are skipped.
+        skip_empty_dirs: Leave out directories that have no entries at all
+                         (hidden or ignored ones included); files are unaffected
+        dirs_with_matches: Yield each directory containing at least one matching
+                           entry, once, instead of the entries themselves
+        extension: Filter by file extension(s), e.g. "py" or ["py", "pyx"].
+                   Entries with glob syntax such as "c*" match any letter case
         exclude: Glob pattern(s) to exclude from results, e.g. "*.log" or ["*.tmp", "*.cache"]
+                 A "!" prefix re-includes paths excluded by an earlier pattern
+                 and the last matching pattern wins, as in .gitignore, e.g.
+                 ["**/build/**", "!**/build/keep.txt"]
+        exclude_name: Glob pattern(s) matched against the file or directory name
+                      only, wherever it is, e.g. "__init__.py" or "test_*".
+                      Not affected by "!" re-includes in exclude
+        predicate: Callable keeping only the entries it returns truthy for. It
+                   is called with each path string, or the Entry with
+                   as_entry, after the built-in filters. Each call goes back
+                   into Python, so it is far slower than the built-in filters
+                   and forces collection (ignored for content search)
         max_depth: Maximum depth to recurse into directories
+        shallow_dirs: Name pattern(s) of directories walked one level deep:
+                      their direct children are yielded, but nothing below
+                      those children, e.g. "node_modules" to list installed
+                      packages without their contents
         min_depth: Minimum depth before yielding results (default: 0)
+        per_dir_limit: Yield at most this many entries from each directory, e.g.
+                       to sample a huge tree (content search: search at most this
+                       many files per directory). Which entries are kept varies
+                       between runs because directories are walked in parallel
         min_size: Minimum file size in bytes (only applies to files)
         max_size: Maximum file size in bytes (only applies to files)
+        min_lines: Minimum number of lines. Only text files can match, and each
+                   is read in full to count its lines, so this is much slower
+                   than the size filters
+        max_lines: Maximum number of lines (only text files match; counting
+                   stops as soon as a file exceeds it)
+        report_oversized: Hold back files larger than this many bytes and report
+                          them as (path, size) pairs from the iterator's
+                          oversized() once drained; with_stats counts them as
+                          "oversized". Needs streamed results (no as_list, sort,
+                          group_by_dir, dedupe_content or null_separated;
+                          ignored for content search)
         mtime_after: Only include files modified after this time
                     Accepts: Unix timestamp, datetime, ISO date (YYYY-MM-DD),
                     or relative time (-1d, -2h, -30m, -45s)
         mtime_before: Only include files modified before this time
                      Accepts: Unix timestamp, datetime, ISO date (YYYY-MM-DD),
                      or relative time (-1d, -2h, -30m, -45s)
+        changed_within: Only include files modified within this duration before now
+                        Accepts: seconds, or a duration string (30s, 5m, 2h, 1d, 1w)
+        changed_before: Only include files modified longer ago than this duration
+                        Accepts: seconds, or a duration string (30s, 5m, 2h, 1d, 1w)
+        newer_than_file: Only include entries modified strictly after this
+                         reference file (like find -newer)
         atime_after: Only include files accessed after this time
                     Accepts: Unix timestamp, datetime, ISO date (YYYY-MM-DD),
                     or relative time (-1d, -2h, -30m, -45s)
@@ -224,21 +497,298 @@ def find(
         ctime_before: Only include files created before this time
                      Accepts: Unix timestamp, datetime, ISO date (YYYY-MM-DD),
                      or relative time (-1d, -2h, -30m, -45s)
+        include_inaccessible: Keep entries whose metadata cannot be read when
+                              size or time filters are set, instead of leaving
+                              them out and reporting them as errors (default: False)
         hidden: Include hidden files and directories (default: False)
+        hidden_only: Only yield entries whose name starts with '.', e.g. for
+                     auditing dotfiles (implies hidden=True)
         ignore_git: Ignore .gitignore rules (default: False)
+        no_vcs: Skip all git ignore handling (.gitignore, .git/info/exclude and
+                the global gitignore) while still honoring .ignore and
+                .fdignore files. Saves setting up git matchers in every
+                directory of trees that are not repositories (default: False)
+        parents: Also apply ignore files from directories above root (default: True),
+                 so searching a subdirectory of a repository still honors the
+                 repository root's .gitignore
+        require_git: Only apply .gitignore rules inside a git repository, i.e. below
+                     a directory containing .git (default: True). Set to False to
+                     honor .gitignore files anywhere
+        git_tracked_only: Only yield files in the git index (and directories
+                          containing them), as listed by `git ls-files`.
+                          Untracked files are skipped even when not ignored,
+                          and .gitignore rules no longer apply. Requires the
+                          git executable; raises VexyGlobError when root is
+                          not inside a git repository
+        git_status: Only yield files git reports in these categories, any of
+                    "modified" (work tree differs from the index), "staged"
+                    (index differs from HEAD) and "untracked" (not ignored),
+                    e.g. ["modified", "staged"] for a pre-commit hook. Like
+                    git_tracked_only, .gitignore rules no longer apply and
+                    VexyGlobError is raised when root is not inside a git
+                    repository; with both set, files must satisfy both
         custom_ignore_files: List of custom ignore files to process (e.g., [".myignore", "custom.ignore"])
                             Files will be processed if they exist. .fdignore files are automatically
                             detected and processed when ignore_git=False.
         case_sensitive: Case sensitivity for patterns (None = smart case)
+        case_sensitive_content: Case sensitivity for the content pattern alone,
+                    e.g. a case-insensitive glob with a case-sensitive
+                    content match. None (default) follows case_sensitive
+        dotall: Let "." in the content regex match newlines so a match can span
+                lines, e.g. "foo.*bar" across a line break (content search only)
+        whole_line: Only return lines the content pattern matches in full, like
+                    `grep -x`; the pattern is wrapped as ^(?:pattern)$. With
+                    dotall a match may cover several complete lines (content
+                    search only)
+        line_terminator: Single character ending each record instead of "\n",
+                         e.g. "\0" for NUL-delimited data or "\r" for CR-only
+                         files; line numbers count records (content search only)
+        encoding: Decode files from this encoding (e.g. "utf-16le", "latin1")
+                  before searching. Files with a UTF-8 or UTF-16 BOM are
+                  decoded automatically (content search only)
+        unique_matches: Drop repeated identical matches from each result's
+                        "matches" list, keeping first-seen order
+        distinct_matches: Return one sorted list of the distinct matched strings
+                          across all files instead of per-line results; paths
+                          and line numbers are not reported (forces collection)
+        pattern_histogram: Return a dict mapping each of content_literals (or the
+                           content regex) to its total match count across all
+                           files instead of per-line results; repeats on a
+                           line count even with unique_matches (forces collection)
+        min_match_length: Drop matches shorter than this many characters; lines
+                          whose matches are all too short are not returned
+        head_lines: Only search the first N lines of each file and stop reading
+                    there, e.g. for shebang or license header checks (content
+                    search only; cannot be combined with write)
+        format: "hunks" yields one {"path": ..., "hunks": [...]} dict per file
+                instead of one per matching line. Each hunk is a
+                {"start_line", "lines", "match_lines"} dict holding a run of
+                matching lines with their context; matches separated by at
+                most 2 * context other lines share a hunk, as in a unified
+                diff (content search only; cannot be combined with replace,
+                head_lines, distinct_matches, pattern_histogram or highlight).
+                "sexp" yields each result as an S-expression string built in
+                Rust: (path "<path>") for paths, (entry "<path>" :is-dir t ...)
+                with as_entry, (match "<path>" :line-number N :line-text "..."
+                :matches ("..." ...) :matches-on-line N) for content matches;
+                extra fields follow as :keyword value pairs. See the README
+                for the full schema (cannot be combined with null_separated,
+                quiet, distinct_matches or pattern_histogram)
+        context: Lines of context around each match in hunks (default: 3)
+        highlight: (prefix, suffix) strings inserted around each match, e.g.
+                   ("\x1b[31m", "\x1b[0m"), giving each result a
+                   "highlighted_line" key. Overlapping and adjacent matches
+                   are wrapped once (content search only)
+        archives: Search the files inside .tar, .tar.gz and .tgz archives instead
+                  of the archive bytes; results get paths like
+                  "logs.tar.gz::app/server.log". The glob and other filters apply
+                  to the archive file itself (content search only)
+        quiet: Return True as soon as any file matches the content pattern and
+               False otherwise, stopping the walk at the first match like
+               `grep -q` (content search only)
+        replace: Replacement for content matches; capture groups can be referenced
+                 as $1 or ${name}. Each result gains a "replacement" key holding
+                 the rewritten line (content search only)
+        write: Rewrite matching files on disk with the replacement applied.
+               Files are replaced atomically, binary files are skipped and each
+               result gains a "replacements" count for its file (requires replace)
+        backup: Keep the original of every rewritten file as <name>.bak
+        search_metadata: Add the matched file's "size" and "mtime" to each content
+                         search result, taken from the walk's metadata
+        report_bytes: Add "bytes_read" to each content search result: how much
+                      of its file the searcher read before finishing or
+                      stopping early, e.g. at head_lines or with quiet. The
+                      count is of decoded bytes for transcoded files, and files
+                      without matches produce no result to carry it
+        full_path: Match the glob against the path relative to root instead of the
+                   file name (default: False). When False, a pattern without a path
+                   separator matches the file name only and a pattern containing "/"
+                   matches the full path. When True, the pattern is matched verbatim
+                   against the relative path.
+        literal_separator: Whether "*", "?" and "[...]" stop at "/" so only "**"
+                           crosses directories (default: True). A pattern
+                           containing "/" then matches its trailing path
+                           components in any directory: "src/*.rs" matches
+                           "src/main.rs" and "lib/src/main.rs" but not
+                           "src/a/b.rs". With False, "*" may span directories
+                           and such patterns must match the whole path,
+                           root prefix included
+        case_sensitive_exclude: Case sensitivity for exclude patterns. None (default)
+                    uses the glob's case sensitivity, but always matches
+                    case-insensitively when the filesystem under root is
+                    case-insensitive (as on macOS and Windows by default).
+                    .gitignore rules follow the filesystem in the same way
         follow_symlinks: Follow symbolic links (default: False)
+        follow_file_symlinks: Treat symlinks to files as the files they point to
+                              (type, size, times, content). None uses follow_symlinks
+        follow_dir_symlinks: Descend into symlinked directories. None uses
+                             follow_symlinks
+        metadata_follow: Which metadata the size and time filters read for a
+                         symlink: True stats the target, False lstats the
+                         link itself, whether or not the walk follows it.
+                         None uses follow_file_symlinks
+        max_symlink_hops: When following symlinks, give up on links whose
+                          chain takes more than this many hops to resolve,
+                          including loops; they are left out and reported
+                          through errors() (default: no limit)
         same_file_system: Don't cross filesystem boundaries (default: False)
-        sort: Sort results by 'name', 'path', 'size', or 'mtime' (forces collection)
+        skip_fs_types: Filesystem type names, e.g. ["nfs", "cifs"], whose
+                       directories are not descended into. Looked up with
+                       statfs once per mount; the roots themselves are always
+                       walked. Has no effect outside Linux, macOS and the BSDs
+        canonicalize_dedup: Resolve each result with realpath and skip results
+                            whose resolved path was already yielded, e.g. the
+                            same tree seen through two bind mounts or a
+                            symlinked directory. Costs a realpath call per
+                            result (default: False; ignored for content search)
+        sort: Sort results by 'name', 'path', 'depth', 'size', or 'mtime' (forces collection).
+              Content search only takes 'match_count', which ranks files by
+              their total matches, most first, with ties broken by path; each
+              file's results stay together in line order
+        dir_order: "pre" emits every directory before its contents, "post" after
+                   them, e.g. to delete children before parents. Either forces a
+                   single-threaded walk in file name order (default: "default",
+                   the parallel walk's order; cannot be combined with sort;
+                   ignored for content search)
+        emit_dir_events: Also yield a DirEvent with kind "enter" when the walk
+                         descends into a directory and "leave" once it is done
+                         with it, for every directory walked whether it matches
+                         or not, so the tree can be rebuilt as results stream
+                         in. Forces the single-threaded walk in file name order
+                         used by dir_order (default: False; needs an iterator;
+                         ignored for content search)
+        priority_roots: Directories below root to walk to completion before
+                        anything else, so their results stream out first,
+                        e.g. the likely target of an interactive picker. Only
+                        the order changes, not the set of results; paths
+                        outside every root are ignored (cannot be combined
+                        with dir_order; ignored for content search)
+        stream_sort_window: Buffer up to this many results and yield them in
+                   path order, giving approximately sorted output while still
+                   streaming with bounded memory. Order is exact only when the
+                   window holds every result (default: None; ignored for
+                   content search)
+        top_k: Besides the results, yield a TopKUpdate holding the k largest
+               regular files found so far as (path, size) pairs, largest
+               first. Updates come at most every 100ms while the set changes,
+               and each supersedes the previous one; the last, with final
+               set, is sent once the walk is done and is exact (default:
+               None; needs an iterator; ignored for content search)
+        group_by_dir: Return a dict mapping each parent directory to the list of
+                      matching entries in it, ordered by `sort` (forces collection;
+                      ignored for content search)
+        dedupe_content: Return a dict mapping a content hash to the files sharing
+                        that content, for groups of two or more identical files.
+                        Only files of equal size are read and hashed, with
+                        64-bit XXH3, so a hash collision could merge two groups.
+                        Unfollowed symlinks are skipped and hard links to one
+                        file count once (forces collection; ignored for
+                        content search)
+        null_separated: Return a single bytes object with every path followed by
+                        a NUL byte, ready for `xargs -0` (forces collection;
+                        ignored for content search)
+        total_size: Return the summed size in bytes of every matching regular
+                    file as an int, without building any paths. Directories
+                    and other entries add nothing (path search only)
+        count_by_extension: Return a dict mapping each extension (without the
+                            dot, as the extension filter sees it) to the number
+                            of matching entries with it, most common first.
+                            Entries without an extension count under "".
+                            Directories are not counted (path search only)
+        with_stats: Collect scan statistics (entries_visited, matched, errors,
+                    bytes_searched, the content bytes actually read, so files
+                    cut short by head_lines or quiet count only their start).
+                    Iterators expose them as `.stats` once
+                    exhausted; collected results are returned as (results, stats).
+                    Iterators also expose `.progress`, an (entries_visited,
+                    estimated_total) pair updated every 1000 entries. The
+                    total is a rough guess from the top two levels of each
+                    root and is raised as the walk outgrows it
+        batch_size: Make the iterator yield lists of up to this many results
+                    instead of one result at a time; every batch is full except
+                    possibly the last (ignored when results are collected)
         threads: Number of parallel threads (None = auto-detect)
+        deterministic: Content search only: walk directories in sorted name
+                       order (or file_list in the order given) and search one
+                       file at a time, so the same tree always yields results
+                       in the same order on every platform. Gives up all
+                       parallelism, so large searches take much longer
+        max_open_files: Content search only: cap on files open for searching at
+                        once (None = unlimited). Each thread searches one file
+                        at a time, so this only matters below the thread count
         as_path: Return pathlib.Path objects instead of strings
+        path_style: "posix" reports paths with forward slashes on every platform
+                    (backslash separators are rewritten on Windows) and makes
+                    as_path return PurePosixPath objects (default: "native")
+        separator: Single character replacing the platform separator in
+                   emitted path strings, e.g. "/" for manifests that must be
+                   identical on every platform. It rewrites the string form
+                   only, so it cannot be combined with as_path or path_style
+        strict_utf8: Report paths that are not valid UTF-8 through the iterator's
+                     errors() instead of yielding them with undecodable bytes
+                     replaced by U+FFFD (default: False)
+        fail_fast: Stop the walk at the first traversal error and raise it as
+                   OSError (SearchError when results are collected) instead
+                   of collecting it for errors() and carrying on (ignored for
+                   content search)
+        as_entry: Return Entry objects caching is_dir, is_file, is_symlink, size
+                  and mtime from the walk; they work anywhere a path does via
+                  __fspath__ (takes precedence over as_path; ignored for
+                  content search)
+        with_depth: Yield {"path": ..., "depth": ...} dicts, where depth counts
+                    directories below the root the entry was found under
+                    (the root itself is 0; ignored for content search)
+        with_line_count: Yield {"path": ..., "line_count": ...} dicts counting
+                         the lines of each regular text file. Every file is
+                         read in full, so this costs far more than a plain
+                         walk; directories and binary files get None
+                         (ignored for content search)
+        resolve_owner: Yield {"path": ..., "owner": ..., "group": ...} dicts
+                       naming the user and group owning each entry. Names
+                       are looked up once per id and cached for the walk;
+                       ids without a name, and every entry on non-Unix
+                       platforms, get None (ignored for content search)
+        preview_bytes: Yield {"path": ..., "preview": ...} dicts with up to
+                       this many leading bytes of each regular text file,
+                       decoded as lossy UTF-8. Each file is opened and at
+                       least its first 8KB read to rule out binaries, which
+                       get None like directories (ignored for content search)
+        with_hash: Yield {"path": ..., "hash": ...} dicts with the hex digest of
+                   each regular file's contents, "xxh3" for a fast
+                   non-cryptographic hash or "sha256". Every matching file is
+                   read in full, which usually costs far more than the walk;
+                   directories and symlinks (unless followed) get None
+                   (ignored for content search)
+        classify: Named globs, e.g. {"tests": "**/test_*", "src": "src/**"};
+                  yield {"path": ..., "group": ...} dicts naming the first
+                  group, in dict order, whose glob matches the entry the way
+                  the find() pattern would, or None when none does (path
+                  search only; cannot be combined with resolve_owner, which
+                  also sets "group")
+        exclude_unclassified: Leave out entries matching no classify group
+                              instead of tagging them None
+        relative_to: Report paths relative to this directory; paths outside it
+                     (e.g. from another root) stay absolute (ignored for
+                     content search)
+        debug_excludes: Instead of the results, return a list of
+                        {"path", "excluded_by", "source", "pattern"} dicts for
+                        the entries matching the glob that were left out.
+                        excluded_by is "ignore_file" (source is the ignore
+                        file, pattern its line), "hidden" (pattern is the
+                        hidden path component), "exclude" or "exclude_name"
+                        (pattern is the matching pattern), or "filter" for any
+                        other filter. The walk is repeated without ignore rules,
+                        so this is slow on large ignored trees (path search only)
         as_list: Return a list instead of an iterator
 
     Returns:
-        Iterator or list of matching paths (strings or Path objects)
+        Iterator or list of matching paths (strings or Path objects), or a dict
+        of lists keyed by parent directory when group_by_dir is set (or by
+        content hash when dedupe_content is set), or NUL
+        separated bytes when null_separated is set, or an int when
+        total_size is set, or a dict of counts when count_by_extension is
+        set. With
+        with_stats, collected results come back as a (results, stats) tuple
 
     Raises:
         PatternError: If the pattern is invalid
@@ -253,15 +803,29 @@ def find(
     if isinstance(root, Path):
         root = str(root)
     
+    # A compiled Python regex brings its own flags instead of smart case
+    content_flags_explicit = isinstance(content, re.Pattern)
+    if content_flags_explicit:
+        content, pattern_dotall = _translate_re_pattern(content)
+        dotall = dotall or pattern_dotall
+
     # Implement smart-case matching with fast path optimization
     if case_sensitive is None:
         # Smart case: case-sensitive if pattern contains uppercase
         effective_glob_case_sensitive = _is_case_sensitive_pattern(pattern)
-        effective_content_case_sensitive = _has_uppercase(content) if content else True
+        if content_literals is not None:
+            effective_content_case_sensitive = any(_has_uppercase(l) for l in content_literals)
+        elif content_flags_explicit:
+            effective_content_case_sensitive = True
+        else:
+            effective_content_case_sensitive = _has_uppercase(content) if content else True
     else:
         # Explicit case sensitivity applies to both
         effective_glob_case_sensitive = case_sensitive
         effective_content_case_sensitive = case_sensitive
+    # ...unless the content match is set on its own
+    if case_sensitive_content is not None:
+        effective_content_case_sensitive = case_sensitive_content
 
     # Convert extension to list if string (optimized with early return)
     if extension is not None and isinstance(extension, str):
@@ -270,14 +834,73 @@ def find(
     # Convert exclude to list if string (optimized with early return)
     if exclude is not None and isinstance(exclude, str):
         exclude = [exclude]
+    if exclude_name is not None and isinstance(exclude_name, str):
+        exclude_name = [exclude_name]
+    if shallow_dirs is not None and isinstance(shallow_dirs, str):
+        shallow_dirs = [shallow_dirs]
+    if git_status is not None and isinstance(git_status, str):
+        git_status = [git_status]
+    if skip_fs_types is not None and isinstance(skip_fs_types, str):
+        skip_fs_types = [skip_fs_types]
+    if priority_roots is not None:
+        if isinstance(priority_roots, (str, Path)):
+            priority_roots = [priority_roots]
+        priority_roots = [str(p) for p in priority_roots]
+
+    # Normalize explicit file list entries to strings
+    if file_list is not None:
+        file_list = [str(f) for f in file_list]
 
     # Convert custom_ignore_files to list if string (optimized with early return)
     if custom_ignore_files is not None and isinstance(custom_ignore_files, str):
         custom_ignore_files = [custom_ignore_files]
 
+    if write and replace is None:
+        raise ValueError("write=True requires a replace string")
+    if sort == "match_count" and content is None and content_literals is None:
+        raise ValueError("sort='match_count' requires a content search")
+    if content is not None and content_literals is not None:
+        raise ValueError("content and content_literals are mutually exclusive")
+    if replace is not None and content is None and content_literals is None:
+        raise ValueError("replace requires a content pattern")
+    if dirs_with_matches and (content is not None or content_literals is not None):
+        raise ValueError("dirs_with_matches cannot be combined with content search")
+    if name_filter is not None and content is None and content_literals is None:
+        raise ValueError("name_filter requires a content pattern")
+    if total_size and (content is not None or content_literals is not None):
+        raise ValueError("total_size cannot be combined with content search")
+    if count_by_extension and (content is not None or content_literals is not None):
+        raise ValueError("count_by_extension cannot be combined with content search")
+    if classify is not None and (content is not None or content_literals is not None):
+        raise ValueError("classify cannot be combined with content search")
+    if format not in ("lines", "hunks", "sexp"):
+        raise ValueError(f"Invalid format: {format!r}. Use 'lines', 'hunks' or 'sexp'")
+    if context < 0:
+        raise ValueError("context must not be negative")
+    if highlight is not None:
+        if len(highlight) != 2 or not all(isinstance(marker, str) for marker in highlight):
+            raise ValueError("highlight must be a (prefix, suffix) pair of strings")
+        highlight = tuple(highlight)
+    if debug_excludes and (content is not None or content_literals is not None):
+        raise ValueError("debug_excludes cannot be combined with content search")
+    if debug_excludes and (
+        as_path or as_entry or with_depth or with_line_count or resolve_owner
+        or preview_bytes is not None or with_hash is not None or classify is not None or relative_to is not None or separator is not None
+        or path_style != "native" or group_by_dir or dedupe_content or null_separated or format == "sexp"
+        or with_stats or dirs_with_matches or report_oversized is not None
+    ):
+        raise ValueError("debug_excludes reports plain paths and cannot be combined with options that reshape results")
+    line_terminator = _parse_line_terminator(line_terminator)
+    if newer_than_file is not None:
+        newer_than_file = os.fspath(newer_than_file)
+        if not os.path.exists(newer_than_file):
+            raise ValueError(f"newer_than_file does not exist: {newer_than_file}")
+
     # Parse time parameters to Unix timestamps
     mtime_after = _parse_time_param(mtime_after)
     mtime_before = _parse_time_param(mtime_before)
+    changed_within = _parse_duration_param(changed_within)
+    changed_before = _parse_duration_param(changed_before)
     atime_after = _parse_time_param(atime_after)
     atime_before = _parse_time_param(atime_before)
     ctime_after = _parse_time_param(ctime_after)
@@ -288,64 +911,207 @@ def find(
 
     # Call Rust implementation
     try:
-        if content is not None:
+        if content is not None or content_literals is not None:
             # Content search mode
             results = _vexy_glob.search(
                 content_regex=content,
                 paths=[root],
+                file_list=file_list,
+                content_literals=content_literals,
                 glob=pattern,
+                name_filter=name_filter,
+                regex=regex,
+                regex_target=regex_target,
                 file_type=file_type,
+                symlink_target_type=symlink_target_type,
+                symlink_broken=symlink_broken,
+                content_type=content_type,
                 extension=extension,
                 exclude=exclude,
+                exclude_name=exclude_name,
                 max_depth=max_depth,
+                shallow_dirs=shallow_dirs,
+                per_dir_limit=per_dir_limit,
                 min_size=min_size,
                 max_size=max_size,
+                min_lines=min_lines,
+                max_lines=max_lines,
                 mtime_after=mtime_after,
                 mtime_before=mtime_before,
+                changed_within=changed_within,
+                changed_before=changed_before,
+                newer_than_file=newer_than_file,
                 atime_after=atime_after,
                 atime_before=atime_before,
                 ctime_after=ctime_after,
                 ctime_before=ctime_before,
+                include_inaccessible=include_inaccessible,
                 hidden=hidden,
+                hidden_only=hidden_only,
                 no_ignore=ignore_git,
+                no_vcs=no_vcs,
+                parents=parents,
+                require_git=require_git,
+                git_tracked_only=git_tracked_only,
+                git_status=git_status,
                 custom_ignore_files=custom_ignore_files,
                 follow_symlinks=follow_symlinks,
+                follow_file_symlinks=follow_file_symlinks,
+                follow_dir_symlinks=follow_dir_symlinks,
+                metadata_follow=metadata_follow,
+                max_symlink_hops=max_symlink_hops,
                 same_file_system=same_file_system,
+                skip_fs_types=skip_fs_types,
                 case_sensitive_glob=effective_glob_case_sensitive,
-                _case_sensitive_content=effective_content_case_sensitive,
+                full_path=full_path,
+                literal_separator=literal_separator,
+                case_sensitive_exclude=case_sensitive_exclude,
+                case_sensitive_content=effective_content_case_sensitive,
                 as_path_objects=as_path,
-                yield_results=not as_list,
+                path_style=path_style,
+                separator=separator,
+                strict_utf8=strict_utf8,
+                yield_results=not as_list and sort is None,
+                sort=sort,
                 _multiline=False,
+                dotall=dotall,
+                whole_line=whole_line,
+                line_terminator=line_terminator,
+                encoding=encoding,
+                unique_matches=unique_matches,
+                distinct_matches=distinct_matches,
+                pattern_histogram=pattern_histogram,
+                min_match_length=min_match_length,
+                head_lines=head_lines,
+                hunk_context=context if format == "hunks" else None,
+                sexp=format == "sexp",
+                highlight=highlight,
+                archives=archives,
+                quiet=quiet,
+                replace=replace,
+                write=write,
+                backup=backup,
+                search_metadata=search_metadata,
+                report_bytes=report_bytes,
+                with_stats=with_stats,
+                batch_size=batch_size,
                 threads=threads or 0,
+                deterministic=deterministic,
+                max_open_files=max_open_files,
             )
         else:
             # Path-only search mode
             results = _vexy_glob.find(
                 paths=[root],
                 glob=pattern,
+                regex=regex,
+                regex_target=regex_target,
                 file_type=file_type,
+                symlink_target_type=symlink_target_type,
+                symlink_broken=symlink_broken,
+                content_type=content_type,
+                skip_empty_dirs=skip_empty_dirs,
+                dirs_with_matches=dirs_with_matches,
                 extension=extension,
                 exclude=exclude,
+                exclude_name=exclude_name,
+                predicate=predicate,
                 max_depth=max_depth,
+                shallow_dirs=shallow_dirs,
+                per_dir_limit=per_dir_limit,
                 min_size=min_size,
                 max_size=max_size,
+                min_lines=min_lines,
+                max_lines=max_lines,
+                report_oversized=report_oversized,
                 mtime_after=mtime_after,
                 mtime_before=mtime_before,
+                changed_within=changed_within,
+                changed_before=changed_before,
+                newer_than_file=newer_than_file,
                 atime_after=atime_after,
                 atime_before=atime_before,
                 ctime_after=ctime_after,
                 ctime_before=ctime_before,
+                include_inaccessible=include_inaccessible,
                 hidden=hidden,
+                hidden_only=hidden_only,
                 no_ignore=ignore_git,
+                no_vcs=no_vcs,
+                parents=parents,
+                require_git=require_git,
+                git_tracked_only=git_tracked_only,
+                git_status=git_status,
                 custom_ignore_files=custom_ignore_files,
                 follow_symlinks=follow_symlinks,
+                follow_file_symlinks=follow_file_symlinks,
+                follow_dir_symlinks=follow_dir_symlinks,
+                metadata_follow=metadata_follow,
+                max_symlink_hops=max_symlink_hops,
                 same_file_system=same_file_system,
+                skip_fs_types=skip_fs_types,
+                canonicalize_dedup=canonicalize_dedup,
                 case_sensitive_glob=effective_glob_case_sensitive,
+                full_path=full_path,
+                literal_separator=literal_separator,
+                case_sensitive_exclude=case_sensitive_exclude,
                 as_path_objects=as_path,
-                yield_results=not as_list and sort is None,
+                path_style=path_style,
+                separator=separator,
+                strict_utf8=strict_utf8,
+                fail_fast=fail_fast,
+                as_entries=as_entry,
+                with_depth=with_depth,
+                with_line_count=with_line_count,
+                resolve_owner=resolve_owner,
+                preview_bytes=preview_bytes,
+                with_hash=with_hash,
+                classify=list(classify.items()) if classify is not None else None,
+                exclude_unclassified=exclude_unclassified,
+                relative_to=str(relative_to) if relative_to is not None else None,
+                yield_results=not as_list and not debug_excludes and sort is None and not group_by_dir and not dedupe_content,
                 sort=sort,
+                dir_order=dir_order,
+                emit_dir_events=emit_dir_events,
+                priority_roots=priority_roots,
+                stream_sort_window=stream_sort_window,
+                top_k=top_k,
+                group_by_dir=group_by_dir,
+                dedupe_content=dedupe_content,
+                null_separated=null_separated,
+                sexp=format == "sexp",
+                total_size=total_size,
+                count_by_extension=count_by_extension,
+                with_stats=with_stats,
+                batch_size=batch_size,
                 threads=threads or 0,
             )
+            if debug_excludes:
+                # Whatever matched the glob but is missing from the results was dropped
+                included = set(results)
+                candidates = _vexy_glob.explain_excludes(
+                    paths=[root],
+                    glob=pattern,
+                    case_sensitive_glob=effective_glob_case_sensitive,
+                    full_path=full_path,
+                    literal_separator=literal_separator,
+                    exclude=exclude,
+                    exclude_name=exclude_name,
+                    case_sensitive_exclude=case_sensitive_exclude,
+                    max_depth=max_depth,
+                    hidden=hidden or hidden_only,
+                    no_ignore=ignore_git,
+                    no_vcs=no_vcs,
+                    parents=parents,
+                    require_git=require_git,
+                    custom_ignore_files=custom_ignore_files,
+                    follow_symlinks=follow_symlinks if follow_dir_symlinks is None else follow_dir_symlinks,
+                )
+                results = [
+                    dict(candidate, excluded_by=candidate["excluded_by"] or "filter")
+                    for candidate in candidates
+                    if candidate["path"] not in included
+                ]
     except Exception as e:
         # Convert Rust errors to Python exceptions
         error_msg = str(e).lower()
@@ -353,6 +1119,8 @@ def find(
             raise PatternError(str(e), pattern)
         elif "permission" in error_msg or "i/o error" in error_msg:
             raise SearchError(str(e))
+        elif isinstance(e, OSError):
+            raise SearchError(str(e))
         else:
             raise VexyGlobError(str(e))
 
@@ -427,8 +1195,183 @@ def iglob(
     )
 
 
+def find_null_separated(
+    pattern: str = "*",
+    root: Union[str, Path] = ".",
+    **kwargs,
+) -> bytes:
+    """
+    Find paths and return them NUL-separated, as `find -print0` does.
+
+    The join happens in Rust, so no intermediate list of Python strings is
+    built. The result can be written straight to `xargs -0`.
+
+    Args:
+        pattern: Glob pattern to match files
+        root: Starting directory for search
+        **kwargs: Additional arguments passed to find()
+
+    Returns:
+        bytes with each path followed by a NUL byte
+    """
+    kwargs.pop("as_path", None)
+    return find(pattern=pattern, root=root, null_separated=True, **kwargs)
+
+
+# Options that change what find() returns, or report per walk in a way that
+# cannot be merged (each top_k update supersedes the previous one)
+_RESHAPING_OPTIONS = (
+    "as_list",
+    "group_by_dir",
+    "dedupe_content",
+    "null_separated",
+    "total_size",
+    "count_by_extension",
+    "debug_excludes",
+    "quiet",
+    "batch_size",
+    "top_k",
+    "report_oversized",
+    "emit_dir_events",
+)
+
+
+class RootsIterator:
+    """
+    Merged results of find_roots(), root by root.
+
+    Like the find() iterator, it keeps the reports of the walks behind it:
+    errors() gathers every root's traversal errors, and with with_stats,
+    `.stats` sums the counters of all roots once every walk is exhausted and
+    `.progress` sums their latest updates.
+    """
+
+    def __init__(self, walks: List[Any]):
+        self._walks = walks
+        self._results = itertools.chain.from_iterable(walks)
+
+    def __iter__(self) -> "RootsIterator":
+        return self
+
+    def __next__(self) -> Any:
+        return next(self._results)
+
+    def errors(self) -> List[str]:
+        """Errors encountered so far by any root's walk"""
+        return [error for walk in self._walks if hasattr(walk, "errors") for error in walk.errors()]
+
+    @property
+    def stats(self) -> Optional[Dict[str, int]]:
+        """Summed scan statistics, or None until every walk is exhausted"""
+        per_root = [getattr(walk, "stats", None) for walk in self._walks]
+        if not per_root or any(stats is None for stats in per_root):
+            return None
+        return {key: sum(stats[key] for stats in per_root) for key in per_root[0]}
+
+    @property
+    def progress(self) -> Optional[Tuple[int, int]]:
+        """Summed (entries_visited, estimated_total) of the walks that reported any"""
+        updates = [walk.progress for walk in self._walks if getattr(walk, "progress", None) is not None]
+        if not updates:
+            return None
+        return (sum(visited for visited, _ in updates), sum(total for _, total in updates))
+
+
+def find_roots(
+    roots: List[Tuple[Union[str, Path], Dict[str, Any]]],
+    as_list: bool = False,
+    **kwargs,
+) -> Union[RootsIterator, List[Union[str, Path]], Tuple[List[Union[str, Path]], Dict[str, int]]]:
+    """
+    Walk several roots in one call, each with its own pattern and filters.
+
+    Every root gets its own walker, and all of them start right away, so later
+    roots are walked in the background while earlier ones are consumed.
+    Results are merged root by root: everything from the first root, then
+    everything from the second, and so on, each in the order find() would
+    give it. The returned iterator reports the errors of every root through
+    errors(), and with with_stats, their summed `.stats` and `.progress`.
+
+    Args:
+        roots: (root, options) pairs; options are find() arguments such as
+               {"pattern": "*.py"} and override the shared kwargs
+        as_list: Return a list instead of an iterator (with with_stats, a
+                 (results, stats) tuple)
+        **kwargs: find() arguments shared by every root
+
+    Returns:
+        RootsIterator or list of the merged results
+
+    Raises:
+        ValueError: If an option would change the shape of the results
+    """
+    specs = [(root, {**kwargs, **options}) for root, options in roots]
+    for _, options in specs:
+        reshaping = [name for name in _RESHAPING_OPTIONS if options.get(name)]
+        if reshaping:
+            raise ValueError(f"find_roots cannot merge results reshaped by {', '.join(reshaping)}")
+        if options.get("with_stats") and options.get("sort") is not None:
+            raise ValueError("find_roots needs streamed results for with_stats; it cannot be combined with sort")
+    # Starting every walk before reading any of them lets them overlap
+    walks = RootsIterator([find(root=root, **options) for root, options in specs])
+    if not as_list:
+        return walks
+    results = list(walks)
+    return (results, walks.stats) if any(options.get("with_stats") for _, options in specs) else results
+
+
+def total_size(
+    pattern: str = "*",
+    root: Union[str, Path] = ".",
+    **kwargs,
+) -> int:
+    """
+    Sum the sizes of the regular files find() would return.
+
+    Sizes come from the metadata the walk already has and are added up in
+    Rust, so no paths are built or handed to Python. Directories, and
+    anything else that is not a regular file, add nothing to the total.
+
+    Args:
+        pattern: Glob pattern to match files
+        root: Starting directory for search
+        **kwargs: Additional arguments passed to find()
+
+    Returns:
+        Total size in bytes (with with_stats, a (total, stats) tuple)
+    """
+    kwargs.pop("as_path", None)
+    return find(pattern=pattern, root=root, total_size=True, **kwargs)
+
+
+def count_by_extension(
+    pattern: str = "*",
+    root: Union[str, Path] = ".",
+    **kwargs,
+) -> Dict[str, int]:
+    """
+    Count the entries find() would return, grouped by extension.
+
+    Counts are kept in a shared map in Rust as the walk goes, so no paths are
+    built or handed to Python. Extensions are taken as the extension filter
+    sees them, without the dot and with their case kept; files without one,
+    such as Makefile or .bashrc, count under "". Directories are not counted.
+
+    Args:
+        pattern: Glob pattern to match files
+        root: Starting directory for search
+        **kwargs: Additional arguments passed to find()
+
+    Returns:
+        Dict of extension to count, most common first (with with_stats, a
+        (counts, stats) tuple)
+    """
+    kwargs.pop("as_path", None)
+    return find(pattern=pattern, root=root, count_by_extension=True, **kwargs)
+
+
 def search(
-    content_regex: str,
+    content_regex: Union[str, "re.Pattern"],
     pattern: str = "*",
     root: Union[str, Path] = ".",
     **kwargs,
@@ -437,7 +1380,8 @@ def search(
     Search for content within files, similar to ripgrep.
 
     Args:
-        content_regex: Regular expression to search for in file contents
+        content_regex: Regular expression to search for in file contents, as a
+                       string or a compiled re.Pattern
         pattern: Glob pattern for files to search in
         root: Starting directory for search
         **kwargs: Additional arguments passed to find()
@@ -445,6 +1389,250 @@ def search(
     Returns:
         Iterator or list of SearchResult dictionaries
     """
-    # Remove sort parameter as content search doesn't support sorting
-    kwargs.pop('sort', None)
+    # Path sorts do not apply to content search; only match_count is kept
+    if kwargs.get('sort') != 'match_count':
+        kwargs.pop('sort', None)
     return find(pattern=pattern, root=root, content=content_regex, **kwargs)
+
+
+def search_bytes(
+    data: Union[bytes, str],
+    content_regex: Union[str, "re.Pattern", None] = None,
+    *,
+    content_literals: Optional[List[str]] = None,
+    label: str = "<bytes>",
+    case_sensitive: Optional[bool] = None,  # None = smart case
+    dotall: bool = False,
+    whole_line: bool = False,
+    line_terminator: Optional[Union[str, bytes]] = None,
+    encoding: Optional[str] = None,
+    unique_matches: bool = False,
+    min_match_length: Optional[int] = None,
+    head_lines: Optional[int] = None,
+    replace: Optional[str] = None,
+) -> List["SearchResult"]:
+    """
+    Search an in-memory buffer the way search() searches each file.
+
+    Useful for content that is not on disk, such as downloaded data. The
+    options mean the same as for find(); results are the same SearchResult
+    dictionaries, with "path" set to ``label``.
+
+    Args:
+        data: Buffer to search; str is encoded as UTF-8
+        content_regex: Regular expression to search for, as a string or a
+                       compiled re.Pattern
+        content_literals: Literal strings to search for instead of a regex
+        label: Value reported as the "path" of every result (default: "<bytes>")
+        case_sensitive: Case sensitivity of the match (None = smart case)
+        replace: Preview a substitution in each result's "replacement"
+
+    Returns:
+        List of SearchResult dictionaries in buffer order
+
+    Raises:
+        PatternError: If the pattern is invalid
+    """
+    if _vexy_glob is None:
+        raise ImportError(
+            "vexy_glob extension module not built. Run 'maturin develop' first."
+        )
+
+    if isinstance(data, str):
+        data = data.encode()
+    if content_regex is not None and content_literals is not None:
+        raise ValueError("content_regex and content_literals are mutually exclusive")
+    if content_regex is None and content_literals is None:
+        raise ValueError("search_bytes requires content_regex or content_literals")
+
+    content_flags_explicit = isinstance(content_regex, re.Pattern)
+    if content_flags_explicit:
+        content_regex, pattern_dotall = _translate_re_pattern(content_regex)
+        dotall = dotall or pattern_dotall
+    if case_sensitive is None:
+        if content_literals is not None:
+            case_sensitive = any(_has_uppercase(l) for l in content_literals)
+        else:
+            case_sensitive = content_flags_explicit or _has_uppercase(content_regex)
+
+    try:
+        return _vexy_glob.search_bytes(
+            data,
+            content_regex=content_regex,
+            content_literals=content_literals,
+            label=label,
+            case_sensitive=case_sensitive,
+            dotall=dotall,
+            whole_line=whole_line,
+            line_terminator=_parse_line_terminator(line_terminator),
+            encoding=encoding,
+            unique_matches=unique_matches,
+            min_match_length=min_match_length,
+            head_lines=head_lines,
+            replace=replace,
+        )
+    except ValueError as e:
+        if "invalid" in str(e).lower():
+            raise PatternError(str(e), content_regex or "")
+        raise VexyGlobError(str(e))
+
+
+def compile(
+    pattern: Optional[str] = "*",
+    *,
+    regex: Optional[str] = None,
+    regex_target: Literal["path", "name"] = "path",
+    case_sensitive: Optional[bool] = None,  # None = smart case
+    full_path: bool = False,
+    literal_separator: bool = True,
+) -> "CompiledPattern":
+    """
+    Compile a glob and/or path regex once for repeated searches.
+
+    The returned CompiledPattern keeps the compiled matchers, so calling its
+    ``find(paths, ...)`` method with different roots skips pattern parsing.
+    ``find`` takes the low-level filter arguments of the extension module:
+    a list of root paths, Unix timestamps for time filters and ``no_ignore``
+    instead of ``ignore_git``.
+
+    Args:
+        pattern: Glob pattern to match files (None to match everything)
+        regex: Regex that paths must match in addition to the glob
+        regex_target: Match the regex against the whole "path" or just the "name"
+        case_sensitive: Case sensitivity for the glob (None = smart case)
+        full_path: Match the glob against the path relative to each root
+        literal_separator: Whether "*" stops at "/" so only "**" crosses directories
+
+    Returns:
+        CompiledPattern instance
+
+    Raises:
+        PatternError: If the glob or regex is invalid
+    """
+    if _vexy_glob is None:
+        raise ImportError(
+            "vexy_glob extension module not built. Run 'maturin develop' first."
+        )
+
+    if case_sensitive is None:
+        case_sensitive = _is_case_sensitive_pattern(pattern) if pattern else True
+
+    try:
+        return _vexy_glob.CompiledPattern(
+            glob=pattern,
+            regex=regex,
+            regex_target=regex_target,
+            case_sensitive=case_sensitive,
+            full_path=full_path,
+            literal_separator=literal_separator,
+        )
+    except ValueError as e:
+        raise PatternError(str(e), pattern or regex or "")
+
+
+def warm_patterns(
+    patterns: List[str],
+    case_sensitive: Optional[bool] = None,  # None = smart case
+) -> Dict[str, int]:
+    """
+    Pre-compile glob patterns into the shared pattern cache.
+
+    Call this at startup so the first ``find()`` with each pattern skips glob
+    compilation. Common patterns such as "*.py" are cached already.
+
+    Args:
+        patterns: Glob patterns to compile
+        case_sensitive: Case sensitivity to compile them with. None (default)
+                        applies smart case to each pattern, as find() does
+
+    Returns:
+        Dict with the number of patterns newly "compiled" and already "cached"
+
+    Raises:
+        PatternError: If a pattern is invalid
+    """
+    if _vexy_glob is None:
+        raise ImportError(
+            "vexy_glob extension module not built. Run 'maturin develop' first."
+        )
+
+    counts = {"compiled": 0, "cached": 0}
+    for pattern in patterns:
+        sensitive = _is_case_sensitive_pattern(pattern) if case_sensitive is None else case_sensitive
+        try:
+            result = _vexy_glob.warm_patterns([pattern], case_sensitive=sensitive)
+        except ValueError as e:
+            raise PatternError(str(e), pattern)
+        counts["compiled"] += result["compiled"]
+        counts["cached"] += result["cached"]
+    return counts
+
+
+def validate_glob(pattern: str, case_sensitive: Optional[bool] = None) -> Optional[str]:
+    """
+    Check a glob pattern without running a search.
+
+    Args:
+        pattern: Glob pattern to check
+        case_sensitive: Case sensitivity to compile it with (None = smart case)
+
+    Returns:
+        None if the pattern is valid, otherwise the error message
+    """
+    if _vexy_glob is None:
+        raise ImportError(
+            "vexy_glob extension module not built. Run 'maturin develop' first."
+        )
+
+    if case_sensitive is None:
+        case_sensitive = _is_case_sensitive_pattern(pattern)
+    return _vexy_glob.validate_glob(pattern, case_sensitive=case_sensitive)
+
+
+def validate_regex(pattern: str) -> Optional[str]:
+    """
+    Check a regular expression without running a search.
+
+    Args:
+        pattern: Regular expression to check
+
+    Returns:
+        None if the pattern is valid, otherwise the error message
+    """
+    if _vexy_glob is None:
+        raise ImportError(
+            "vexy_glob extension module not built. Run 'maturin develop' first."
+        )
+
+    return _vexy_glob.validate_regex(pattern)
+
+
+def analyze_content_pattern(pattern: str) -> dict:
+    """
+    Report whether a content regex can use the engine's literal fast path.
+
+    A pattern whose matches all start with the same text lets the search skip
+    ahead with a substring scan before running the regex. Rewriting a slow
+    pattern so it gains a literal prefix (e.g. ``error: \\w+`` rather than
+    ``\\w+: failed``) is often the cheapest speedup.
+
+    Args:
+        pattern: Regular expression as passed to search()
+
+    Returns:
+        Dict with "has_literal_prefix" (bool), "literal" (the shared prefix,
+        or None when none can be extracted) and "is_anchored" (True when
+        every match must start at ^ or \\A)
+
+    Raises:
+        PatternError: If the pattern is not a valid regex
+    """
+    if _vexy_glob is None:
+        raise ImportError(
+            "vexy_glob extension module not built. Run 'maturin develop' first."
+        )
+
+    try:
+        return _vexy_glob.analyze_content_pattern(pattern)
+    except ValueError as e:
+        raise PatternError(str(e), pattern)
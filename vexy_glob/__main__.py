@@ -146,6 +146,7 @@ class Cli:
         extension: Optional[Union[str, List[str]]] = None,
         depth: Optional[int] = None,
         no_color: bool = False,
+        files_from: Optional[str] = None,
     ):
         """Search for content within files.
 
@@ -156,6 +157,8 @@ class Cli:
         Options:
             All options from 'find' command, plus:
             --no-color: Disable colored output
+            --files-from: Search only the files listed (one per line) in this file,
+                          or read the list from stdin with "-"
 
         Output Format:
             path/to/file.py:123:import this
@@ -163,12 +166,22 @@ class Cli:
         Examples:
             vexy_glob search "**/*.py" "import asyncio"
             vexy_glob search "src/**/*.rs" "fn\\s+my_function"
+            git diff --name-only | vexy_glob search "*" "TODO" --files-from -
         """
         try:
             # Parse size parameters
             min_size_bytes = self._parse_size(min_size) if min_size else None
             max_size_bytes = self._parse_size(max_size) if max_size else None
 
+            # Read an explicit file list, one path per line
+            file_list = None
+            if files_from is not None:
+                if files_from == "-":
+                    lines = sys.stdin.read().splitlines()
+                else:
+                    lines = Path(files_from).read_text().splitlines()
+                file_list = [line for line in lines if line.strip()]
+
             # Call vexy_glob.search with all parameters
             results = vexy_glob.search(
                 content_regex=content_pattern,
@@ -184,6 +197,7 @@ class Cli:
                 file_type=type,
                 extension=extension,
                 max_depth=depth,
+                file_list=file_list,
                 as_path=False,  # Return strings for CLI
                 as_list=False,  # Stream results
             )